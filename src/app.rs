@@ -1,5 +1,6 @@
 use gtk4 as gtk;
 use gtk::prelude::*;
+use gtk::gio;
 use libadwaita as adw;
 use adw::prelude::*;
 
@@ -13,21 +14,31 @@ pub struct TaskManagerApp {
 
 impl TaskManagerApp {
     pub fn new() -> Self {
+        // Unique on the session bus: launching again (e.g. from the Ctrl+Shift+Esc
+        // shortcut) forwards the command line to the running instance and exits
         let app = adw::Application::builder()
             .application_id(APP_ID)
+            .flags(gio::ApplicationFlags::HANDLES_COMMAND_LINE)
             .build();
 
         app.connect_startup(|_| {
             load_css();
         });
 
+        app.connect_command_line(|app, command_line| {
+            if command_line.is_remote() {
+                log::info!("Another launch requested the window, presenting the running instance");
+            }
+            app.activate();
+            0
+        });
+
         app.connect_activate(|app| {
             if let Some(window) = app.active_window() {
                 window.present();
                 return;
             }
-            let window = MainWindow::new(app);
-            window.present();
+            MainWindow::new(app);
         });
 
         Self { app }
@@ -11,6 +11,8 @@ pub struct BatteryInfo {
     pub ac_connected: bool,
     pub energy_now: u64,     // microWh
     pub energy_full: u64,    // microWh
+    pub health_percent: Option<f64>,
+    pub cycle_count: Option<u64>,
 }
 
 pub struct BatteryCollector {
@@ -62,6 +64,19 @@ impl BatteryCollector {
             })
             .unwrap_or(0);
 
+        // Health: full vs. design capacity, both in energy or both in charge
+        // units so no voltage is needed
+        info.health_percent = [("energy_full", "energy_full_design"), ("charge_full", "charge_full_design")]
+            .iter()
+            .find_map(|(full, design)| {
+                let full = read_sysfs_u64(&format!("{}/{}", bat_path, full))?;
+                let design = read_sysfs_u64(&format!("{}/{}", bat_path, design)).filter(|d| *d > 0)?;
+                Some(full as f64 / design as f64 * 100.0)
+            });
+
+        // Drivers that don't track cycles report 0
+        info.cycle_count = read_sysfs_u64(&format!("{}/cycle_count", bat_path)).filter(|c| *c > 0);
+
         // Percent
         // Try capacity first (direct percentage), else calculate
         info.percent = read_sysfs_u64(&format!("{}/capacity", bat_path))
@@ -0,0 +1,110 @@
+use std::fs;
+
+/// Capability names by bit number, as in linux/capability.h (lowercase, like capsh)
+const CAP_NAMES: &[&str] = &[
+    "cap_chown",
+    "cap_dac_override",
+    "cap_dac_read_search",
+    "cap_fowner",
+    "cap_fsetid",
+    "cap_kill",
+    "cap_setgid",
+    "cap_setuid",
+    "cap_setpcap",
+    "cap_linux_immutable",
+    "cap_net_bind_service",
+    "cap_net_broadcast",
+    "cap_net_admin",
+    "cap_net_raw",
+    "cap_ipc_lock",
+    "cap_ipc_owner",
+    "cap_sys_module",
+    "cap_sys_rawio",
+    "cap_sys_chroot",
+    "cap_sys_ptrace",
+    "cap_sys_pacct",
+    "cap_sys_admin",
+    "cap_sys_boot",
+    "cap_sys_nice",
+    "cap_sys_resource",
+    "cap_sys_time",
+    "cap_sys_tty_config",
+    "cap_mknod",
+    "cap_lease",
+    "cap_audit_write",
+    "cap_audit_control",
+    "cap_setfcap",
+    "cap_mac_override",
+    "cap_mac_admin",
+    "cap_syslog",
+    "cap_wake_alarm",
+    "cap_block_suspend",
+    "cap_audit_read",
+    "cap_perfmon",
+    "cap_bpf",
+    "cap_checkpoint_restore",
+];
+
+/// Capability sets and sandboxing flags from /proc/<pid>/status.
+pub struct SecurityStatus {
+    pub cap_effective: u64,
+    pub cap_permitted: u64,
+    /// 0 = disabled, 1 = strict, 2 = filter; None on kernels without seccomp
+    pub seccomp: Option<u8>,
+    /// Number of attached seccomp filters (kernel 5.9+)
+    pub seccomp_filters: Option<u32>,
+    pub no_new_privs: bool,
+}
+
+pub fn read_status(pid: i32) -> Option<SecurityStatus> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let mut result = SecurityStatus {
+        cap_effective: 0,
+        cap_permitted: 0,
+        seccomp: None,
+        seccomp_filters: None,
+        no_new_privs: false,
+    };
+    for line in status.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "CapEff" => result.cap_effective = u64::from_str_radix(value, 16).unwrap_or(0),
+            "CapPrm" => result.cap_permitted = u64::from_str_radix(value, 16).unwrap_or(0),
+            "Seccomp" => result.seccomp = value.parse().ok(),
+            "Seccomp_filters" => result.seccomp_filters = value.parse().ok(),
+            "NoNewPrivs" => result.no_new_privs = value == "1",
+            _ => {}
+        }
+    }
+    Some(result)
+}
+
+/// Names of the capabilities in `mask`; "all" for a full set, "none" for an empty one.
+pub fn describe(mask: u64) -> String {
+    let known = (1u64 << CAP_NAMES.len()) - 1;
+    if mask == 0 {
+        return "none".to_string();
+    }
+    if mask & known == known {
+        return "all".to_string();
+    }
+    (0..64)
+        .filter(|bit| mask & (1u64 << bit) != 0)
+        .map(|bit| CAP_NAMES.get(bit).map_or_else(|| format!("cap_{}", bit), |name| name.to_string()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn seccomp_mode(status: &SecurityStatus) -> String {
+    match (status.seccomp, status.seccomp_filters) {
+        (None, _) => "Not supported".to_string(),
+        (Some(0), _) => "Disabled".to_string(),
+        (Some(1), _) => "Strict".to_string(),
+        (Some(2), Some(filters)) => format!("Filter ({} filter(s))", filters),
+        (Some(2), None) => "Filter".to_string(),
+        (Some(mode), _) => format!("Unknown ({})", mode),
+    }
+}
@@ -0,0 +1,93 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+/// cpu.max period written when the cgroup doesn't have one yet (100ms, the kernel default)
+const DEFAULT_CPU_PERIOD_US: u64 = 100_000;
+
+/// Current memory and CPU limits of a process's cgroup v2 group.
+pub struct CgroupLimits {
+    /// Cgroup directory under /sys/fs/cgroup
+    pub dir: PathBuf,
+    /// Path as shown in /proc/<pid>/cgroup
+    pub path: String,
+    /// memory.max in bytes; None = unlimited
+    pub memory_max: Option<u64>,
+    /// cpu.max quota per period in microseconds; None = unlimited
+    pub cpu_quota_us: Option<u64>,
+    pub cpu_period_us: u64,
+    /// Whether we may write memory.max and cpu.max
+    pub writable: bool,
+}
+
+impl CgroupLimits {
+    /// CPU limit as a percentage of one core (200 = two cores).
+    pub fn cpu_percent(&self) -> Option<f64> {
+        self.cpu_quota_us.map(|quota| quota as f64 * 100.0 / self.cpu_period_us as f64)
+    }
+}
+
+/// Read the limits of the cgroup `pid` belongs to. Fails on a v1 or hybrid
+/// layout, or when the memory/cpu controllers aren't enabled for that group.
+pub fn read_limits(pid: i32) -> Result<CgroupLimits, String> {
+    // A pure v2 (unified) hierarchy has cgroup.controllers at its root
+    if !Path::new(CGROUP_ROOT).join("cgroup.controllers").exists() {
+        return Err("This system uses the cgroup v1 (or hybrid) layout. \
+                    Limits can only be set with cgroup v2."
+            .to_string());
+    }
+
+    let content = fs::read_to_string(format!("/proc/{}/cgroup", pid))
+        .map_err(|e| format!("Cannot read /proc/{}/cgroup: {}", pid, e))?;
+    let path = content
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(|p| p.trim().to_string())
+        .ok_or("The process is not in a cgroup v2 group")?;
+    if path == "/" {
+        return Err("The process is in the root cgroup, which cannot be limited".to_string());
+    }
+    let dir = Path::new(CGROUP_ROOT).join(path.trim_start_matches('/'));
+
+    let memory = fs::read_to_string(dir.join("memory.max"))
+        .map_err(|_| format!("The memory controller is not enabled for {}", path))?;
+    let cpu = fs::read_to_string(dir.join("cpu.max"))
+        .map_err(|_| format!("The cpu controller is not enabled for {}", path))?;
+
+    let memory_max = memory.trim().parse().ok();
+    let mut cpu_fields = cpu.split_whitespace();
+    let cpu_quota_us = cpu_fields.next().and_then(|q| q.parse().ok());
+    let cpu_period_us = cpu_fields.next().and_then(|p| p.parse().ok()).unwrap_or(DEFAULT_CPU_PERIOD_US);
+
+    let writable = is_writable(&dir.join("memory.max")) && is_writable(&dir.join("cpu.max"));
+
+    Ok(CgroupLimits { dir, path, memory_max, cpu_quota_us, cpu_period_us, writable })
+}
+
+/// Write memory.max (None removes the limit).
+pub fn set_memory_max(limits: &CgroupLimits, bytes: Option<u64>) -> Result<(), String> {
+    let value = bytes.map_or("max".to_string(), |b| b.to_string());
+    write_file(&limits.dir.join("memory.max"), &value)
+}
+
+/// Write cpu.max from a percentage of one core (None removes the limit).
+pub fn set_cpu_percent(limits: &CgroupLimits, percent: Option<f64>) -> Result<(), String> {
+    let period = limits.cpu_period_us;
+    let value = match percent {
+        // The kernel rejects quotas below 1ms
+        Some(p) => format!("{} {}", ((p / 100.0 * period as f64) as u64).max(1000), period),
+        None => format!("max {}", period),
+    };
+    write_file(&limits.dir.join("cpu.max"), &value)
+}
+
+fn write_file(path: &Path, value: &str) -> Result<(), String> {
+    fs::write(path, value).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn is_writable(path: &Path) -> bool {
+    let Ok(c_path) = std::ffi::CString::new(path.as_os_str().as_encoded_bytes()) else {
+        return false;
+    };
+    unsafe { libc::access(c_path.as_ptr(), libc::W_OK) == 0 }
+}
@@ -6,21 +6,58 @@ use crate::backend::network::NetworkCollector;
 use crate::backend::process::ProcessCollector;
 use crate::backend::battery::BatteryCollector;
 use crate::backend::history::AppHistoryTracker;
+use crate::backend::psi::PsiCollector;
 use crate::backend::DesktopResolver;
 use crate::backend::WindowResolver;
 use crate::model::{AppGroup, SystemSnapshot};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 pub struct Collector {
     tx: flume::Sender<SystemSnapshot>,
+    interval_ms: Arc<AtomicU64>,
+    paused: Arc<AtomicBool>,
+    priority_rules: PriorityRules,
+    measure_pss: Arc<AtomicBool>,
 }
 
+/// Exe path -> nice value, applied to each new process of that executable.
+pub type PriorityRules = Arc<Mutex<HashMap<String, i32>>>;
+
 impl Collector {
-    pub fn new() -> (Self, flume::Receiver<SystemSnapshot>) {
+    pub fn new(interval_ms: u64) -> (Self, flume::Receiver<SystemSnapshot>) {
         let (tx, rx) = flume::bounded(2);
-        (Self { tx }, rx)
+        let interval_ms = Arc::new(AtomicU64::new(interval_ms));
+        let priority_rules = Arc::new(Mutex::new(HashMap::new()));
+        let paused = Arc::new(AtomicBool::new(false));
+        let measure_pss = Arc::new(AtomicBool::new(false));
+        (Self { tx, interval_ms, paused, priority_rules, measure_pss }, rx)
+    }
+
+    /// Shared handle to the refresh interval; storing a new value takes effect
+    /// on the next collection cycle.
+    pub fn interval_handle(&self) -> Arc<AtomicU64> {
+        self.interval_ms.clone()
+    }
+
+    /// Shared handle to the pause flag; while set, no snapshots are taken.
+    pub fn pause_handle(&self) -> Arc<AtomicBool> {
+        self.paused.clone()
+    }
+
+    /// Shared handle to the PSS flag; while set, every process's smaps_rollup
+    /// is read for the PSS and shared/private memory figures.
+    pub fn pss_handle(&self) -> Arc<AtomicBool> {
+        self.measure_pss.clone()
+    }
+
+    /// Shared handle to the priority rules; edits apply to processes started
+    /// after the next collection cycle.
+    pub fn priority_rules_handle(&self) -> PriorityRules {
+        self.priority_rules.clone()
     }
 
     pub fn start(self) {
@@ -32,95 +69,217 @@ impl Collector {
             .expect("Failed to spawn collector thread");
     }
 
+    /// Take a single snapshot without starting the collector thread. Blocks
+    /// for half a second so CPU and I/O rates have a delta to work from.
+    pub fn collect_once() -> SystemSnapshot {
+        let mut sampler = Sampler::new(PriorityRules::default(), Arc::default());
+        let _ = sampler.sample();
+        thread::sleep(Duration::from_millis(500));
+        sampler.sample()
+    }
+
     fn run(self) {
-        let mut cpu_collector = CpuCollector::new();
-        let memory_collector = MemoryCollector::new();
-        let mut disk_collector = DiskCollector::new();
-        let mut network_collector = NetworkCollector::new();
-        let gpu_collector = GpuCollector::new();
-        let mut process_collector = ProcessCollector::new();
-        let battery_collector = BatteryCollector::new();
-        let mut history_tracker = AppHistoryTracker::new();
-        let desktop_resolver = DesktopResolver::new();
-        let window_resolver = WindowResolver::new();
+        let mut sampler = Sampler::new(self.priority_rules.clone(), self.measure_pss.clone());
 
         // Initial collection to prime deltas
-        let _ = cpu_collector.collect();
+        let _ = sampler.cpu_collector.collect();
         thread::sleep(Duration::from_millis(500));
 
         loop {
-            let (cpu_total, cpu_per_core, cpu_freq, cpu_temp, cpu_per_core_temps, cpu_per_core_freqs) = cpu_collector.collect();
-            let memory = memory_collector.collect();
-            let disk = disk_collector.collect();
-            let network = network_collector.collect();
-            let gpu_system = gpu_collector.collect_system();
-            let gpu_vram = gpu_collector.collect_per_process();
-            let battery = battery_collector.collect();
-            let window_titles = window_resolver.collect();
-
-            let processes = process_collector.collect(
-                &gpu_vram,
-                desktop_resolver.names(),
-                &window_titles,
-            );
-
-            let thread_count: u64 = processes.iter().map(|p| p.threads).sum();
-            let process_count = processes.len();
-
-            let app_groups = build_app_groups(&processes);
-
-            // Update history tracker
-            history_tracker.update(&app_groups);
-            let app_histories = history_tracker.snapshot();
-
-            let battery_model = crate::model::BatteryInfo {
-                available: battery.available,
-                percent: battery.percent,
-                status: battery.status,
-                power_watts: battery.power_watts,
-                time_remaining_secs: battery.time_remaining_secs,
-                ac_connected: battery.ac_connected,
-            };
-
-            let snapshot = SystemSnapshot {
-                processes,
-                app_groups,
-                cpu: crate::model::CpuInfo {
-                    total_percent: cpu_total,
-                    per_core_percent: cpu_per_core,
-                    core_count: cpu_collector.core_count,
-                    model_name: cpu_collector.model_name.clone(),
-                    frequency_mhz: cpu_freq,
-                    uptime_secs: cpu::uptime_secs(),
-                    temperature_celsius: cpu_temp,
-                    per_core_temperatures: cpu_per_core_temps,
-                    per_core_frequencies: cpu_per_core_freqs,
-                },
-                memory,
-                disk,
-                network,
-                gpu: gpu_system,
-                battery: battery_model,
-                process_count,
-                thread_count,
-                app_histories,
-            };
+            let snapshot = sampler.sample();
 
             if self.tx.send(snapshot).is_err() {
                 log::info!("Collector channel closed, shutting down");
                 break;
             }
 
-            thread::sleep(Duration::from_secs(1));
+            // Wait in short steps so a shorter interval or unpausing applies promptly
+            let waiting_since = Instant::now();
+            while self.paused.load(Ordering::Relaxed)
+                || waiting_since.elapsed() < Duration::from_millis(self.interval_ms.load(Ordering::Relaxed).max(100))
+            {
+                thread::sleep(Duration::from_millis(100));
+            }
+        }
+    }
+}
+
+/// All sub-collectors plus the state they carry between cycles.
+struct Sampler {
+    cpu_collector: CpuCollector,
+    memory_collector: MemoryCollector,
+    disk_collector: DiskCollector,
+    network_collector: NetworkCollector,
+    gpu_collector: GpuCollector,
+    process_collector: ProcessCollector,
+    battery_collector: BatteryCollector,
+    psi_collector: PsiCollector,
+    history_tracker: AppHistoryTracker,
+    desktop_resolver: DesktopResolver,
+    window_resolver: WindowResolver,
+    priority_rules: PriorityRules,
+    measure_pss: Arc<AtomicBool>,
+}
+
+impl Sampler {
+    fn new(priority_rules: PriorityRules, measure_pss: Arc<AtomicBool>) -> Self {
+        Self {
+            cpu_collector: CpuCollector::new(),
+            memory_collector: MemoryCollector::new(),
+            disk_collector: DiskCollector::new(),
+            network_collector: NetworkCollector::new(),
+            gpu_collector: GpuCollector::new(),
+            process_collector: ProcessCollector::new(),
+            battery_collector: BatteryCollector::new(),
+            psi_collector: PsiCollector::new(),
+            history_tracker: AppHistoryTracker::new(),
+            desktop_resolver: DesktopResolver::new(),
+            window_resolver: WindowResolver::new(),
+            priority_rules,
+            measure_pss,
+        }
+    }
+
+    /// Run one collection cycle.
+    fn sample(&mut self) -> SystemSnapshot {
+        let (cpu_total, cpu_per_core, cpu_freq, cpu_temp, cpu_per_core_temps, cpu_per_core_freqs) = self.cpu_collector.collect();
+        let memory = self.memory_collector.collect();
+        let disk = self.disk_collector.collect();
+        let network = self.network_collector.collect();
+        let gpu_system = self.gpu_collector.collect_system();
+        let gpu_vram = self.gpu_collector.collect_per_process();
+        let battery = self.battery_collector.collect();
+        let psi = self.psi_collector.collect();
+        let window_titles = self.window_resolver.collect();
+
+        let priority_rules = self.priority_rules.lock().map(|r| r.clone()).unwrap_or_default();
+        let processes = self.process_collector.collect(
+            &gpu_vram,
+            self.desktop_resolver.names(),
+            &window_titles,
+            &priority_rules,
+            self.measure_pss.load(Ordering::Relaxed),
+        );
+        let connections = self.process_collector.take_connections();
+
+        let thread_count: u64 = processes.iter().map(|p| p.threads).sum();
+        let process_count = processes.len();
+
+        let app_groups = build_app_groups(&processes);
+
+        // Update history tracker
+        self.history_tracker.update(&app_groups);
+        let app_histories = self.history_tracker.snapshot();
+
+        let battery_model = crate::model::BatteryInfo {
+            available: battery.available,
+            percent: battery.percent,
+            status: battery.status,
+            power_watts: battery.power_watts,
+            time_remaining_secs: battery.time_remaining_secs,
+            ac_connected: battery.ac_connected,
+            health_percent: battery.health_percent,
+            cycle_count: battery.cycle_count,
+        };
+
+        SystemSnapshot {
+            processes,
+            app_groups,
+            cpu: crate::model::CpuInfo {
+                total_percent: cpu_total,
+                per_core_percent: cpu_per_core,
+                core_count: self.cpu_collector.core_count,
+                model_name: self.cpu_collector.model_name.clone(),
+                frequency_mhz: cpu_freq,
+                max_frequency_mhz: self.cpu_collector.max_frequency_mhz,
+                uptime_secs: cpu::uptime_secs(),
+                temperature_celsius: cpu_temp,
+                per_core_temperatures: cpu_per_core_temps,
+                per_core_frequencies: cpu_per_core_freqs,
+            },
+            load: cpu::load_average(),
+            memory,
+            disk,
+            network,
+            gpu: gpu_system,
+            battery: battery_model,
+            psi,
+            process_count,
+            thread_count,
+            app_histories,
+            connections,
         }
     }
 }
 
-fn is_kernel_thread(proc: &crate::model::ProcessInfo) -> bool {
+pub fn is_kernel_thread(proc: &crate::model::ProcessInfo) -> bool {
     // kthreadd (PID 2) and all its children are kernel threads
     proc.pid == 2 || proc.ppid == 2 || (proc.ppid == 0 && proc.pid != 1)
 }
 
+/// Bundle all kernel threads under one "Kernel" group, the only group led
+/// by PID 0.
+fn kernel_group(kernel_procs: &[&crate::model::ProcessInfo]) -> Option<AppGroup> {
+    if kernel_procs.is_empty() {
+        return None;
+    }
+    let mut leader_info = crate::model::ProcessInfo {
+        pid: 0,
+        display_name: "Kernel".to_string(),
+        name: "kernel".to_string(),
+        ..Default::default()
+    };
+    // Sum up kernel thread stats for the leader
+    for kp in kernel_procs {
+        leader_info.cpu_percent += kp.cpu_percent;
+        leader_info.memory_bytes += kp.memory_bytes;
+        leader_info.threads += 1;
+    }
+    let mut group = AppGroup::new(leader_info);
+    for kp in kernel_procs {
+        group.add_child((*kp).clone());
+    }
+    Some(group)
+}
+
+/// Group processes by systemd unit (service, scope or slice) instead of by
+/// executable. Processes outside any unit are grouped by cgroup. Each group
+/// is led by its lowest PID, renamed after the unit.
+pub fn build_unit_groups(processes: &[crate::model::ProcessInfo]) -> Vec<AppGroup> {
+    let mut kernel_procs: Vec<&crate::model::ProcessInfo> = Vec::new();
+    let mut by_unit: HashMap<&str, Vec<&crate::model::ProcessInfo>> = HashMap::new();
+    let mut loose: Vec<&crate::model::ProcessInfo> = Vec::new();
+    for proc in processes {
+        if is_kernel_thread(proc) {
+            kernel_procs.push(proc);
+        } else if !proc.systemd_unit.is_empty() {
+            by_unit.entry(&proc.systemd_unit).or_default().push(proc);
+        } else if !proc.cgroup.is_empty() {
+            by_unit.entry(&proc.cgroup).or_default().push(proc);
+        } else {
+            loose.push(proc);
+        }
+    }
+
+    let mut result: Vec<AppGroup> = Vec::new();
+    result.extend(kernel_group(&kernel_procs));
+    for (unit, mut procs) in by_unit {
+        procs.sort_by_key(|p| p.pid);
+        let mut leader = procs[0].clone();
+        leader.display_name = unit.to_string();
+        let mut group = AppGroup::new(leader);
+        for proc in &procs[1..] {
+            group.add_child((*proc).clone());
+        }
+        result.push(group);
+    }
+    // Without cgroup info (e.g. other PID namespaces) a process stands alone
+    result.extend(loose.into_iter().map(|p| AppGroup::new(p.clone())));
+
+    result.sort_by(|a, b| b.total_cpu.partial_cmp(&a.total_cpu).unwrap_or(std::cmp::Ordering::Equal));
+    result
+}
+
 fn build_app_groups(processes: &[crate::model::ProcessInfo]) -> Vec<AppGroup> {
     let mut kernel_procs: Vec<&crate::model::ProcessInfo> = Vec::new();
     let mut by_name: HashMap<String, Vec<&crate::model::ProcessInfo>> = HashMap::new();
@@ -141,27 +300,7 @@ fn build_app_groups(processes: &[crate::model::ProcessInfo]) -> Vec<AppGroup> {
     }
 
     let mut result: Vec<AppGroup> = Vec::new();
-
-    // Bundle all kernel threads under one "Kernel" group
-    if !kernel_procs.is_empty() {
-        let mut leader_info = crate::model::ProcessInfo {
-            pid: 0,
-            display_name: "Kernel".to_string(),
-            name: "kernel".to_string(),
-            ..Default::default()
-        };
-        // Sum up kernel thread stats for the leader
-        for kp in &kernel_procs {
-            leader_info.cpu_percent += kp.cpu_percent;
-            leader_info.memory_bytes += kp.memory_bytes;
-            leader_info.threads += 1;
-        }
-        let mut group = AppGroup::new(leader_info);
-        for kp in &kernel_procs {
-            group.add_child((*kp).clone());
-        }
-        result.push(group);
-    }
+    result.extend(kernel_group(&kernel_procs));
 
     // Group userspace processes by exe path, then merge singletons by name prefix
     let mut groups_by_key: Vec<(String, Vec<&crate::model::ProcessInfo>)> = by_name.into_iter().collect();
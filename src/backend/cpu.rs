@@ -5,17 +5,20 @@ pub struct CpuCollector {
     prev_idle: Vec<u64>,
     pub core_count: usize,
     pub model_name: String,
+    pub max_frequency_mhz: f64,
 }
 
 impl CpuCollector {
     pub fn new() -> Self {
         let core_count = num_cores();
         let model_name = cpu_model_name();
+        let max_frequency_mhz = max_frequency_mhz();
         Self {
             prev_total: vec![0; core_count + 1],
             prev_idle: vec![0; core_count + 1],
             core_count,
             model_name,
+            max_frequency_mhz,
         }
     }
 
@@ -110,6 +113,14 @@ fn cpu_model_name() -> String {
         .unwrap_or_else(|| "Unknown CPU".to_string())
 }
 
+fn max_frequency_mhz() -> f64 {
+    fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq")
+        .ok()
+        .and_then(|f| f.trim().parse::<f64>().ok())
+        .map(|khz| khz / 1000.0)
+        .unwrap_or(0.0)
+}
+
 fn read_cpu_temperature() -> f64 {
     // Try hwmon: look for coretemp (Intel) or k10temp (AMD)
     if let Ok(entries) = fs::read_dir("/sys/class/hwmon") {
@@ -147,6 +158,19 @@ pub fn uptime_secs() -> u64 {
         .unwrap_or(0)
 }
 
+/// Load averages and runnable/total tasks, e.g. "0.52 0.58 0.59 2/1234 5678".
+pub fn load_average() -> crate::model::LoadAverage {
+    let loadavg = fs::read_to_string("/proc/loadavg").unwrap_or_default();
+    let fields: Vec<&str> = loadavg.split_whitespace().collect();
+    let load = |i: usize| fields.get(i).and_then(|s| s.parse().ok()).unwrap_or(0.0);
+    let (runnable, total) = fields
+        .get(3)
+        .and_then(|tasks| tasks.split_once('/'))
+        .map(|(r, t)| (r.parse().unwrap_or(0), t.parse().unwrap_or(0)))
+        .unwrap_or((0, 0));
+    crate::model::LoadAverage { one: load(0), five: load(1), fifteen: load(2), runnable, total }
+}
+
 fn read_per_core_temperatures() -> Vec<f64> {
     let mut temps = Vec::new();
 
@@ -14,14 +14,59 @@ pub struct DesktopEnv {
     pub name: String,
     pub session_type: String,
     pub commands: Vec<RestartCommand>,
+    /// Why there is nothing to restart, shown instead of the commands
+    pub note: Option<String>,
+}
+
+/// Wayland compositors configured through their own config file rather
+/// than desktop settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TilingCompositor {
+    Hyprland,
+    Sway,
+}
+
+/// The running tiling compositor, if any. Their socket variables are set
+/// even when XDG_CURRENT_DESKTOP is missing (e.g. started from a TTY).
+pub fn detect_tiling_compositor() -> Option<TilingCompositor> {
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default().to_uppercase();
+    if desktop.contains("HYPRLAND") || std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE").is_some() {
+        Some(TilingCompositor::Hyprland)
+    } else if desktop.contains("SWAY") || std::env::var_os("SWAYSOCK").is_some() {
+        Some(TilingCompositor::Sway)
+    } else {
+        None
+    }
 }
 
 /// Detect the running DE and return available restart commands.
 pub fn detect() -> Option<DesktopEnv> {
     let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default().to_uppercase();
     let session = std::env::var("XDG_SESSION_TYPE").unwrap_or_default().to_lowercase();
+    let tiling = detect_tiling_compositor();
 
-    let (name, commands) = if desktop.contains("KDE") || desktop.contains("PLASMA") {
+    let mut note = None;
+    // Tiling compositors can only reload their config; a real restart would
+    // end the session. Checked first since XDG_CURRENT_DESKTOP may be unset.
+    let (name, commands) = if tiling == Some(TilingCompositor::Hyprland) {
+        (
+            "Hyprland".to_string(),
+            vec![RestartCommand {
+                label: "Reload Hyprland Config".to_string(),
+                program: "hyprctl".to_string(),
+                args: vec!["reload".to_string()],
+            }],
+        )
+    } else if tiling == Some(TilingCompositor::Sway) {
+        (
+            "sway".to_string(),
+            vec![RestartCommand {
+                label: "Reload sway Config".to_string(),
+                program: "swaymsg".to_string(),
+                args: vec!["reload".to_string()],
+            }],
+        )
+    } else if desktop.contains("KDE") || desktop.contains("PLASMA") {
         let kwin = if session == "wayland" { "kwin_wayland" } else { "kwin_x11" };
         (
             "KDE Plasma".to_string(),
@@ -38,6 +83,11 @@ pub fn detect() -> Option<DesktopEnv> {
                 },
             ],
         )
+    } else if desktop.contains("GNOME") && session == "wayland" {
+        // Meta.restart() only works on X11; on Wayland the shell is the
+        // compositor, and replacing it ends the session
+        note = Some("Restarting GNOME Shell isn't supported on Wayland (it would log you out)".to_string());
+        ("GNOME".to_string(), Vec::new())
     } else if desktop.contains("GNOME") {
         (
             "GNOME".to_string(),
@@ -91,6 +141,7 @@ pub fn detect() -> Option<DesktopEnv> {
         name,
         session_type: session,
         commands,
+        note,
     })
 }
 
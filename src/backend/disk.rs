@@ -1,9 +1,48 @@
+use std::collections::HashSet;
 use std::fs;
-use crate::model::{DiskInfo, DiskDevice};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::model::{DiskInfo, DiskDevice, FilesystemUsage};
+
+/// How often mount capacities are re-queried.
+const FS_SCAN_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a tick waits for a fresh scan before reusing the previous one.
+const FS_SCAN_WAIT: Duration = Duration::from_millis(200);
+/// A scan stuck this long is abandoned and its current mount skipped from then on.
+const FS_SCAN_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct DiskCollector {
-    prev_stats: Vec<(String, u64, u64)>,
+    prev_stats: Vec<(String, DiskCounters)>,
     prev_time: std::time::Instant,
+    filesystems: FilesystemScanner,
+}
+
+/// Runs the statvfs scan of all mounts in a worker thread, so a hung network
+/// or FUSE mount can't stall the collector.
+struct FilesystemScanner {
+    latest: Vec<FilesystemUsage>,
+    last_scan: Option<Instant>,
+    pending: Option<PendingScan>,
+    hung_mounts: HashSet<String>,
+}
+
+struct PendingScan {
+    started: Instant,
+    result: Receiver<Vec<FilesystemUsage>>,
+    /// Mount point the worker is querying right now
+    current: Arc<Mutex<Option<String>>>,
+}
+
+/// Cumulative counters of one device from /proc/diskstats.
+#[derive(Clone, Copy)]
+struct DiskCounters {
+    read_bytes: u64,
+    write_bytes: u64,
+    reads: u64,
+    writes: u64,
+    read_ms: u64,
+    write_ms: u64,
 }
 
 impl DiskCollector {
@@ -11,6 +50,7 @@ impl DiskCollector {
         Self {
             prev_stats: Vec::new(),
             prev_time: std::time::Instant::now(),
+            filesystems: FilesystemScanner::new(),
         }
     }
 
@@ -34,34 +74,203 @@ impl DiskCollector {
                 continue;
             }
 
-            let read_sectors: u64 = parts[5].parse().unwrap_or(0);
-            let write_sectors: u64 = parts[9].parse().unwrap_or(0);
-            let read_bytes = read_sectors * 512;
-            let write_bytes = write_sectors * 512;
-
-            let prev = self.prev_stats.iter().find(|(n, _, _)| n == &name);
-            let (read_rate, write_rate) = if let Some((_, prev_r, prev_w)) = prev {
-                (
-                    (read_bytes.saturating_sub(*prev_r)) as f64 / elapsed,
-                    (write_bytes.saturating_sub(*prev_w)) as f64 / elapsed,
-                )
-            } else {
-                (0.0, 0.0)
+            // Fields: reads completed, merged, sectors read, ms reading,
+            // then the same four for writes
+            let field = |i: usize| parts[i].parse::<u64>().unwrap_or(0);
+            let counters = DiskCounters {
+                read_bytes: field(5) * 512,
+                write_bytes: field(9) * 512,
+                reads: field(3),
+                writes: field(7),
+                read_ms: field(6),
+                write_ms: field(10),
             };
 
-            current_stats.push((name.clone(), read_bytes, write_bytes));
-            devices.push(DiskDevice {
-                name,
-                read_bytes_sec: read_rate,
-                write_bytes_sec: write_rate,
-                total_read: read_bytes,
-                total_write: write_bytes,
-            });
+            let mut device = DiskDevice {
+                name: name.clone(),
+                total_read: counters.read_bytes,
+                total_write: counters.write_bytes,
+                ..Default::default()
+            };
+            if let Some((_, prev)) = self.prev_stats.iter().find(|(n, _)| n == &name) {
+                let reads = counters.reads.saturating_sub(prev.reads);
+                let writes = counters.writes.saturating_sub(prev.writes);
+                device.read_bytes_sec = counters.read_bytes.saturating_sub(prev.read_bytes) as f64 / elapsed;
+                device.write_bytes_sec = counters.write_bytes.saturating_sub(prev.write_bytes) as f64 / elapsed;
+                device.read_iops = reads as f64 / elapsed;
+                device.write_iops = writes as f64 / elapsed;
+                if reads > 0 {
+                    device.read_await_ms = counters.read_ms.saturating_sub(prev.read_ms) as f64 / reads as f64;
+                }
+                if writes > 0 {
+                    device.write_await_ms = counters.write_ms.saturating_sub(prev.write_ms) as f64 / writes as f64;
+                }
+            }
+
+            current_stats.push((name, counters));
+            devices.push(device);
         }
 
         self.prev_stats = current_stats;
         self.prev_time = std::time::Instant::now();
 
-        DiskInfo { devices }
+        DiskInfo {
+            devices,
+            filesystems: self.filesystems.poll(),
+        }
+    }
+}
+
+impl FilesystemScanner {
+    fn new() -> Self {
+        Self {
+            latest: Vec::new(),
+            last_scan: None,
+            pending: None,
+            hung_mounts: HashSet::new(),
+        }
+    }
+
+    /// Latest known filesystem usage, starting a new scan when due.
+    fn poll(&mut self) -> Vec<FilesystemUsage> {
+        let due = self.last_scan.is_none_or(|t| t.elapsed() >= FS_SCAN_INTERVAL);
+        if self.pending.is_none() && due {
+            self.start();
+        }
+
+        if let Some(pending) = &self.pending {
+            let wait = if self.last_scan.is_none() { FS_SCAN_WAIT } else { Duration::ZERO };
+            match pending.result.recv_timeout(wait) {
+                Ok(filesystems) => {
+                    self.latest = filesystems;
+                    self.pending = None;
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if pending.started.elapsed() >= FS_SCAN_TIMEOUT {
+                        if let Some(mount) = pending.current.lock().ok().and_then(|m| m.clone()) {
+                            log::warn!("Filesystem at {} is not responding, no longer querying its usage", mount);
+                            self.hung_mounts.insert(mount);
+                        }
+                        self.pending = None;
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => self.pending = None,
+            }
+        }
+
+        self.latest.clone()
+    }
+
+    fn start(&mut self) {
+        let (tx, rx) = mpsc::channel();
+        let current = Arc::new(Mutex::new(None));
+        let worker_current = current.clone();
+        let skip = self.hung_mounts.clone();
+        let spawned = std::thread::Builder::new()
+            .name("statvfs".into())
+            .spawn(move || {
+                let _ = tx.send(collect_filesystems(&skip, &worker_current));
+            });
+        self.last_scan = Some(Instant::now());
+        match spawned {
+            Ok(_) => {
+                self.pending = Some(PendingScan {
+                    started: Instant::now(),
+                    result: rx,
+                    current,
+                });
+            }
+            Err(e) => log::warn!("Failed to start filesystem scan: {}", e),
+        }
+    }
+}
+
+/// Filesystem types that don't represent real storage.
+const PSEUDO_FS_TYPES: &[&str] = &[
+    "proc", "sysfs", "tmpfs", "devtmpfs", "devpts", "cgroup", "cgroup2",
+    "securityfs", "pstore", "bpf", "debugfs", "tracefs", "configfs",
+    "fusectl", "mqueue", "hugetlbfs", "autofs", "binfmt_misc", "efivarfs",
+    "rpc_pipefs", "nsfs", "ramfs", "squashfs", "selinuxfs", "overlay",
+    "fuse.portal", "fuse.gvfsd-fuse", "fuse.snapfuse",
+];
+
+/// Read /proc/mounts and query capacity for each mounted filesystem (like `df`).
+/// Pseudo filesystems and mounts in `skip` are listed without querying them.
+fn collect_filesystems(skip: &HashSet<String>, current: &Mutex<Option<String>>) -> Vec<FilesystemUsage> {
+    let mounts = fs::read_to_string("/proc/mounts").unwrap_or_default();
+    let mut filesystems: Vec<FilesystemUsage> = Vec::new();
+
+    for line in mounts.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 3 {
+            continue;
+        }
+        let device = unescape_mount_field(parts[0]);
+        let mount_point = unescape_mount_field(parts[1]);
+        let fs_type = parts[2].to_string();
+        let is_pseudo = PSEUDO_FS_TYPES.contains(&fs_type.as_str());
+
+        // Pseudo filesystems have no capacity (and statvfs on an autofs
+        // mountpoint would trigger the automount)
+        let (total, used, available) = if is_pseudo || skip.contains(&mount_point) {
+            (0, 0, 0)
+        } else {
+            if let Ok(mut current) = current.lock() {
+                *current = Some(mount_point.clone());
+            }
+            statvfs_usage(&mount_point).unwrap_or((0, 0, 0))
+        };
+
+        // Later mounts on the same path shadow earlier ones
+        filesystems.retain(|f| f.mount_point != mount_point);
+        filesystems.push(FilesystemUsage {
+            device,
+            mount_point,
+            fs_type,
+            total,
+            used,
+            available,
+            is_pseudo,
+        });
+    }
+
+    filesystems
+}
+
+/// Returns (total, used, available) bytes for the filesystem at `path`.
+fn statvfs_usage(path: &str) -> Option<(u64, u64, u64)> {
+    let c_path = std::ffi::CString::new(path).ok()?;
+    unsafe {
+        let mut stat: libc::statvfs = std::mem::zeroed();
+        if libc::statvfs(c_path.as_ptr(), &mut stat) != 0 {
+            return None;
+        }
+        let frsize = stat.f_frsize as u64;
+        let total = stat.f_blocks as u64 * frsize;
+        let free = stat.f_bfree as u64 * frsize;
+        let available = stat.f_bavail as u64 * frsize;
+        Some((total, total.saturating_sub(free), available))
+    }
+}
+
+/// Decode the octal escapes (e.g. `\040` for space) used in /proc/mounts
+/// and /proc/swaps.
+pub(crate) fn unescape_mount_field(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\'
+            && i + 3 < bytes.len()
+            && bytes[i + 1..i + 4].iter().all(|b| (b'0'..=b'7').contains(b))
+        {
+            let val = bytes[i + 1..i + 4].iter().fold(0u32, |acc, b| acc * 8 + (b - b'0') as u32);
+            out.push(val as u8);
+            i += 4;
+            continue;
+        }
+        out.push(bytes[i]);
+        i += 1;
     }
+    String::from_utf8_lossy(&out).to_string()
 }
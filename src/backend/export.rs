@@ -19,12 +19,19 @@ pub fn export_processes_csv(
     processes: &[crate::model::ProcessInfo],
 ) -> Result<(), String> {
     let mut file = File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+    write_processes_csv(&mut file, processes)
+}
 
+/// Write the process list as CSV to any writer (e.g. stdout)
+pub fn write_processes_csv<W: Write>(
+    file: &mut W,
+    processes: &[crate::model::ProcessInfo],
+) -> Result<(), String> {
     // Write header
     writeln!(
         file,
-        "PID,PPID,Name,DisplayName,State,CPU%,Memory(bytes),Memory%,VRAM(bytes),\
-         DiskRead(B/s),DiskWrite(B/s),Threads,Nice,User,Container,SystemdUnit,\
+        "PID,PPID,Name,DisplayName,State,CPU%,Memory(bytes),Memory%,PSS(bytes),Swap(bytes),VRAM(bytes),\
+         DiskRead(B/s),DiskWrite(B/s),Threads,FDs,Nice,User,Container,SystemdUnit,\
          IOClass,IOPriority,SecurityLabel,Command,ExePath"
     )
     .map_err(|e| format!("Failed to write header: {}", e))?;
@@ -33,7 +40,7 @@ pub fn export_processes_csv(
     for p in processes {
         writeln!(
             file,
-            "{},{},{},{},{},{:.2},{},{:.2},{},{:.2},{:.2},{},{},{},{},{},{},{},{},{},{}",
+            "{},{},{},{},{},{:.2},{},{:.2},{},{},{},{:.2},{:.2},{},{},{},{},{},{},{},{},{},{},{}",
             p.pid,
             p.ppid,
             csv_escape(&p.name),
@@ -42,10 +49,13 @@ pub fn export_processes_csv(
             p.cpu_percent,
             p.memory_bytes,
             p.memory_percent,
+            p.pss_bytes,
+            p.swap_bytes,
             p.vram_bytes,
             p.disk_read_rate,
             p.disk_write_rate,
             p.threads,
+            p.fd_count,
             p.nice,
             csv_escape(&p.user),
             csv_escape(&p.container_type),
@@ -83,7 +93,9 @@ pub fn export_performance_csv(
              MemoryCached(bytes),SwapUsed(bytes),SwapTotal(bytes),GPU%,GPUName,\
              VRAMUsed(bytes),VRAMTotal(bytes),GPUTemp(C),GPUPower(W),DiskRead(B/s),\
              DiskWrite(B/s),NetRx(B/s),NetTx(B/s),ProcessCount,ThreadCount,\
-             BatteryPercent,BatteryStatus,BatteryPower(W),CPUTemp(C),CPUFreq(MHz),Uptime(s)"
+             BatteryPercent,BatteryStatus,BatteryPower(W),CPUTemp(C),CPUFreq(MHz),Uptime(s),\
+             CPUPressureSome10(%),MemoryPressureSome10(%),MemoryPressureFull10(%),\
+             IOPressureSome10(%),IOPressureFull10(%),Load1,Load5,Load15,RunnableTasks,TotalTasks"
         )
         .map_err(|e| format!("Failed to write header: {}", e))?;
         f
@@ -116,7 +128,7 @@ pub fn export_performance_csv(
 
     writeln!(
         file,
-        "{},{:.2},{},{},{},{},{},{},{:.2},{},{},{},{},{:.2},{:.2},{:.2},{:.2},{:.2},{},{},{:.2},{},{:.2},{:.2},{:.2},{}",
+        "{},{:.2},{},{},{},{},{},{},{:.2},{},{},{},{},{:.2},{:.2},{:.2},{:.2},{:.2},{},{},{:.2},{},{:.2},{:.2},{:.2},{},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{},{}",
         timestamp,
         snapshot.cpu.total_percent,
         snapshot.memory.used,
@@ -143,6 +155,16 @@ pub fn export_performance_csv(
         snapshot.cpu.temperature_celsius,
         snapshot.cpu.frequency_mhz,
         snapshot.cpu.uptime_secs,
+        snapshot.psi.cpu.some_avg10,
+        snapshot.psi.memory.some_avg10,
+        snapshot.psi.memory.full_avg10,
+        snapshot.psi.io.some_avg10,
+        snapshot.psi.io.full_avg10,
+        snapshot.load.one,
+        snapshot.load.five,
+        snapshot.load.fifteen,
+        snapshot.load.runnable,
+        snapshot.load.total,
     )
     .map_err(|e| format!("Failed to write performance row: {}", e))?;
 
@@ -194,7 +216,7 @@ pub fn export_disk_csv(
     // Write header
     writeln!(
         file,
-        "Device,ReadRate(B/s),WriteRate(B/s),TotalRead(bytes),TotalWrite(bytes)"
+        "Device,ReadRate(B/s),WriteRate(B/s),TotalRead(bytes),TotalWrite(bytes),ReadIOPS,WriteIOPS,ReadAwait(ms),WriteAwait(ms)"
     )
     .map_err(|e| format!("Failed to write header: {}", e))?;
 
@@ -202,12 +224,16 @@ pub fn export_disk_csv(
     for device in &disk_info.devices {
         writeln!(
             file,
-            "{},{:.2},{:.2},{},{}",
+            "{},{:.2},{:.2},{},{},{:.1},{:.1},{:.2},{:.2}",
             csv_escape(&device.name),
             device.read_bytes_sec,
             device.write_bytes_sec,
             device.total_read,
             device.total_write,
+            device.read_iops,
+            device.write_iops,
+            device.read_await_ms,
+            device.write_await_ms,
         )
         .map_err(|e| format!("Failed to write disk row: {}", e))?;
     }
@@ -245,3 +271,132 @@ pub fn export_network_csv(
 
     Ok(())
 }
+
+/// Serialize `value` as JSON into `path`, optionally pretty-printed.
+fn write_json<T: serde::Serialize + ?Sized>(path: &Path, value: &T, pretty: bool) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+    write_json_to(file, value, pretty)
+}
+
+fn write_json_to<W: Write, T: serde::Serialize + ?Sized>(file: W, value: &T, pretty: bool) -> Result<(), String> {
+    let result = if pretty {
+        serde_json::to_writer_pretty(file, value)
+    } else {
+        serde_json::to_writer(file, value)
+    };
+    result.map_err(|e| format!("Failed to write JSON: {}", e))
+}
+
+/// Export process list to JSON (an array of process objects)
+pub fn export_processes_json(
+    path: &Path,
+    processes: &[crate::model::ProcessInfo],
+    pretty: bool,
+) -> Result<(), String> {
+    write_json(path, processes, pretty)
+}
+
+/// Export a full system snapshot to JSON, tagged with a Unix timestamp
+pub fn export_snapshot_json(
+    path: &Path,
+    snapshot: &crate::model::SystemSnapshot,
+    pretty: bool,
+) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+    write_snapshot_json(file, snapshot, pretty)
+}
+
+/// Write a full system snapshot as timestamped JSON to any writer
+pub fn write_snapshot_json<W: Write>(
+    file: W,
+    snapshot: &crate::model::SystemSnapshot,
+    pretty: bool,
+) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct TimestampedSnapshot<'a> {
+        timestamp: u64,
+        #[serde(flatten)]
+        snapshot: &'a crate::model::SystemSnapshot,
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    write_json_to(file, &TimestampedSnapshot { timestamp, snapshot }, pretty)
+}
+
+/// Write a human-readable system summary followed by the process list,
+/// busiest first
+pub fn write_snapshot_text<W: Write>(
+    file: &mut W,
+    snapshot: &crate::model::SystemSnapshot,
+) -> Result<(), String> {
+    use crate::util::{format_bytes, format_bytes_rate, format_duration, format_percent};
+
+    let cpu = &snapshot.cpu;
+    let memory = &snapshot.memory;
+    let disk_read: f64 = snapshot.disk.devices.iter().map(|d| d.read_bytes_sec).sum();
+    let disk_write: f64 = snapshot.disk.devices.iter().map(|d| d.write_bytes_sec).sum();
+    let net_rx: f64 = snapshot.network.interfaces.iter().map(|i| i.rx_bytes_sec).sum();
+    let net_tx: f64 = snapshot.network.interfaces.iter().map(|i| i.tx_bytes_sec).sum();
+
+    let mut lines = vec![
+        format!("CPU:       {} of {} cores ({})", format_percent(cpu.total_percent), cpu.core_count, cpu.model_name),
+        format!(
+            "Memory:    {} / {} (swap {} / {})",
+            format_bytes(memory.used),
+            format_bytes(memory.total),
+            format_bytes(memory.swap_used),
+            format_bytes(memory.swap_total)
+        ),
+        format!("Disk:      read {}, write {}", format_bytes_rate(disk_read), format_bytes_rate(disk_write)),
+        format!("Network:   down {}, up {}", format_bytes_rate(net_rx), format_bytes_rate(net_tx)),
+    ];
+    for gpu in snapshot.gpu.iter().filter(|g| g.available) {
+        lines.push(format!(
+            "GPU:       {} {} (VRAM {} / {})",
+            gpu.name,
+            format_percent(gpu.utilization_percent),
+            format_bytes(gpu.vram_used),
+            format_bytes(gpu.vram_total)
+        ));
+    }
+    if snapshot.battery.available {
+        lines.push(format!("Battery:   {:.0}% {}", snapshot.battery.percent, snapshot.battery.status));
+    }
+    lines.push(format!(
+        "Processes: {} ({} threads), up {}",
+        snapshot.process_count,
+        snapshot.thread_count,
+        format_duration(cpu.uptime_secs)
+    ));
+    lines.push(format!(
+        "Load:      {:.2} {:.2} {:.2} ({} runnable of {} tasks)",
+        snapshot.load.one, snapshot.load.five, snapshot.load.fifteen, snapshot.load.runnable, snapshot.load.total
+    ));
+
+    let mut processes: Vec<&crate::model::ProcessInfo> = snapshot.processes.iter().collect();
+    processes.sort_by(|a, b| b.cpu_percent.partial_cmp(&a.cpu_percent).unwrap_or(std::cmp::Ordering::Equal));
+
+    lines.push(String::new());
+    lines.push(format!("{:>8} {:<12} {:>7} {:>10} {:<2} {}", "PID", "USER", "CPU", "MEMORY", "S", "NAME"));
+    for p in processes {
+        let name = if p.display_name.is_empty() { &p.name } else { &p.display_name };
+        lines.push(format!(
+            "{:>8} {:<12} {:>7} {:>10} {:<2} {}",
+            p.pid,
+            p.user,
+            format_percent(p.cpu_percent),
+            format_bytes(p.memory_bytes),
+            p.state,
+            name
+        ));
+    }
+
+    for line in lines {
+        writeln!(file, "{}", line).map_err(|e| format!("Failed to write output: {}", e))?;
+    }
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use crate::model::GpuInfo;
 use nvml_wrapper::Nvml;
+use nvml_wrapper::enum_wrappers::device::Clock;
 use nvml_wrapper::enums::device::UsedGpuMemory;
 use std::collections::HashMap;
+use std::time::Instant;
 
 // ---------------------------------------------------------------------------
 // Sysfs helpers
@@ -24,6 +26,295 @@ fn find_hwmon_path(device_path: &str) -> Option<String> {
     None
 }
 
+/// First non-zero value of `files` (tried in order) in any hwmon directory of
+/// the device, converted from microwatts to watts. Some cards split their
+/// sensors across several hwmon directories.
+fn read_hwmon_watts(device_path: &str, files: &[&str]) -> Option<f64> {
+    let mut hwmons: Vec<_> = std::fs::read_dir(format!("{}/hwmon", device_path))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect();
+    hwmons.sort();
+    files.iter().find_map(|file| {
+        hwmons
+            .iter()
+            .filter_map(|hwmon| read_sysfs_u64(&hwmon.join(file).to_string_lossy()))
+            .find(|&microwatts| microwatts > 0)
+            .map(|microwatts| microwatts as f64 / 1_000_000.0)
+    })
+}
+
+/// PCI slot name (e.g. "0000:03:00.0") of a DRM device, as reported in fdinfo `drm-pdev`.
+fn pci_slot_name(device_path: &str) -> String {
+    std::fs::canonicalize(device_path)
+        .ok()
+        .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_default()
+}
+
+/// Active level of an amdgpu DPM table such as `pp_dpm_sclk`, whose lines
+/// look like `1: 1800Mhz *` with the current level starred.
+fn read_amd_dpm_clock(device_path: &str, file: &str) -> Option<u32> {
+    let content = read_sysfs_string(&format!("{}/{}", device_path, file))?;
+    let line = content.lines().find(|l| l.trim_end().ends_with('*'))?;
+    let freq = line.split_whitespace().nth(1)?;
+    freq.to_ascii_lowercase().trim_end_matches("mhz").parse().ok()
+}
+
+// ---------------------------------------------------------------------------
+// DRM fdinfo (per-process VRAM for AMD/Intel)
+// ---------------------------------------------------------------------------
+
+/// Walk /proc/<pid>/fd for open DRM nodes on one of `pdevs` and parse the
+/// matching /proc/<pid>/fdinfo entries.
+///
+/// Each DRM client is returned once (keyed by `drm-pdev` + `drm-client-id`),
+/// since the same client can be reachable through dup'd or inherited fds.
+fn collect_drm_clients(pdevs: &[String]) -> Vec<(u32, DrmFdinfo)> {
+    let mut clients = Vec::new();
+    let mut seen_clients: std::collections::HashSet<(String, String)> = std::collections::HashSet::new();
+
+    let proc_entries = match std::fs::read_dir("/proc") {
+        Ok(e) => e,
+        Err(_) => return clients,
+    };
+
+    for entry in proc_entries.flatten() {
+        let pid: u32 = match entry.file_name().to_string_lossy().parse() {
+            Ok(p) => p,
+            Err(_) => continue,
+        };
+        let fds = match std::fs::read_dir(format!("/proc/{}/fd", pid)) {
+            Ok(f) => f,
+            Err(_) => continue,
+        };
+
+        for fd in fds.flatten() {
+            let is_drm = std::fs::read_link(fd.path())
+                .map(|target| target.starts_with("/dev/dri/"))
+                .unwrap_or(false);
+            if !is_drm {
+                continue;
+            }
+
+            let fdinfo_path = format!("/proc/{}/fdinfo/{}", pid, fd.file_name().to_string_lossy());
+            let Some(info) = parse_drm_fdinfo(&fdinfo_path) else {
+                continue;
+            };
+            if !info.pdev.is_empty() && !pdevs.contains(&info.pdev) {
+                continue;
+            }
+            if !info.client_id.is_empty() && !seen_clients.insert((info.pdev.clone(), info.client_id.clone())) {
+                continue;
+            }
+            clients.push((pid, info));
+        }
+    }
+
+    clients
+}
+
+/// Sum the device-local memory of every DRM client per process.
+fn per_process_vram(clients: &[(u32, DrmFdinfo)]) -> HashMap<u32, u64> {
+    let mut map = HashMap::new();
+    for (pid, info) in clients {
+        if info.vram_bytes > 0 {
+            *map.entry(*pid).or_insert(0) += info.vram_bytes;
+        }
+    }
+    map
+}
+
+struct DrmFdinfo {
+    pdev: String,
+    client_id: String,
+    vram_bytes: u64,
+    engines: HashMap<String, EngineCounter>,
+}
+
+/// Busy counter of one engine class for one DRM client.
+///
+/// i915 reports `drm-engine-<class>: N ns`; xe reports `drm-cycles-<class>`
+/// against a GPU timestamp in `drm-total-cycles-<class>`.
+#[derive(Clone, Copy, Default)]
+struct EngineCounter {
+    busy: u64,
+    total_cycles: Option<u64>,
+    capacity: u32,
+}
+
+fn parse_drm_fdinfo(path: &str) -> Option<DrmFdinfo> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let mut info = DrmFdinfo {
+        pdev: String::new(),
+        client_id: String::new(),
+        vram_bytes: 0,
+        engines: HashMap::new(),
+    };
+    let mut has_driver = false;
+    // amdgpu before 6.x only exposes drm-memory-vram; prefer resident over legacy keys
+    let mut legacy_vram = 0u64;
+    let mut resident_vram = 0u64;
+
+    for line in content.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "drm-driver" => has_driver = true,
+            "drm-pdev" => info.pdev = value.to_string(),
+            "drm-client-id" => info.client_id = value.to_string(),
+            "drm-memory-vram" => legacy_vram += parse_drm_size(value),
+            _ => {
+                // Device-local regions: "vram*" (amdgpu, xe) and "local*" (i915)
+                if let Some(region) = key.strip_prefix("drm-resident-") {
+                    if region.starts_with("vram") || region.starts_with("local") {
+                        resident_vram += parse_drm_size(value);
+                    }
+                } else if let Some(engine) = key.strip_prefix("drm-engine-capacity-") {
+                    info.engines.entry(engine.to_string()).or_default().capacity =
+                        value.parse().unwrap_or(1);
+                } else if let Some(engine) = key.strip_prefix("drm-engine-") {
+                    info.engines.entry(engine.to_string()).or_default().busy = parse_drm_number(value);
+                } else if let Some(engine) = key.strip_prefix("drm-total-cycles-") {
+                    info.engines.entry(engine.to_string()).or_default().total_cycles =
+                        Some(parse_drm_number(value));
+                } else if let Some(engine) = key.strip_prefix("drm-cycles-") {
+                    info.engines.entry(engine.to_string()).or_default().busy = parse_drm_number(value);
+                }
+            }
+        }
+    }
+
+    if !has_driver {
+        return None;
+    }
+    info.vram_bytes = if resident_vram > 0 { resident_vram } else { legacy_vram };
+    Some(info)
+}
+
+/// Parse a fdinfo counter like "123456 ns" or "98765".
+fn parse_drm_number(value: &str) -> u64 {
+    value.split_whitespace().next().and_then(|v| v.parse().ok()).unwrap_or(0)
+}
+
+/// Parse a fdinfo size like "1234 KiB" into bytes.
+fn parse_drm_size(value: &str) -> u64 {
+    let mut parts = value.split_whitespace();
+    let amount: u64 = parts.next().and_then(|v| v.parse().ok()).unwrap_or(0);
+    match parts.next() {
+        Some("KiB") => amount * 1024,
+        Some("MiB") => amount * 1024 * 1024,
+        Some("GiB") => amount * 1024 * 1024 * 1024,
+        _ => amount,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Intel engine busyness
+// ---------------------------------------------------------------------------
+
+/// Engine counters of an Intel card at one point in time. The counters are
+/// monotonic, so utilization is computed from the delta between two samples.
+struct IntelEngineSample {
+    /// Per DRM client id: busy counter per engine class (render, copy, video, ...)
+    clients: HashMap<String, HashMap<String, EngineCounter>>,
+    /// Per GT: total time spent in RC6 / idle, in ms
+    idle_residency_ms: Vec<u64>,
+    at: Instant,
+}
+
+impl IntelEngineSample {
+    fn read(card_path: &str, pdev: &str, drm_clients: &[(u32, DrmFdinfo)]) -> Self {
+        let clients = drm_clients
+            .iter()
+            .filter(|(_, info)| info.pdev.is_empty() || info.pdev == pdev)
+            .filter(|(_, info)| !info.client_id.is_empty() && !info.engines.is_empty())
+            .map(|(_, info)| (info.client_id.clone(), info.engines.clone()))
+            .collect();
+        Self {
+            clients,
+            idle_residency_ms: read_idle_residency_ms(card_path),
+            at: Instant::now(),
+        }
+    }
+
+    /// Busy percent of the busiest engine class since `prev`, summed over all
+    /// clients. Falls back to GT idle residency when fdinfo has no engine stats.
+    fn utilization_since(&self, prev: &IntelEngineSample) -> Option<f64> {
+        let elapsed = self.at.duration_since(prev.at);
+        if elapsed.is_zero() {
+            return None;
+        }
+        let elapsed_ns = elapsed.as_nanos() as f64;
+
+        let mut per_engine: HashMap<&str, f64> = HashMap::new();
+        let mut has_engine_stats = false;
+        for (client_id, engines) in &self.clients {
+            let Some(prev_engines) = prev.clients.get(client_id) else {
+                continue;
+            };
+            for (engine, counter) in engines {
+                let Some(prev_counter) = prev_engines.get(engine) else {
+                    continue;
+                };
+                has_engine_stats = true;
+                let busy = counter.busy.saturating_sub(prev_counter.busy) as f64;
+                let fraction = match (counter.total_cycles, prev_counter.total_cycles) {
+                    (Some(total), Some(prev_total)) if total > prev_total => {
+                        busy / (total - prev_total) as f64
+                    }
+                    (Some(_), Some(_)) => 0.0,
+                    _ => busy / elapsed_ns,
+                };
+                *per_engine.entry(engine.as_str()).or_insert(0.0) += fraction / counter.capacity.max(1) as f64;
+            }
+        }
+        if has_engine_stats {
+            let busiest = per_engine.values().cloned().fold(0.0, f64::max);
+            return Some((busiest * 100.0).min(100.0));
+        }
+
+        if !self.idle_residency_ms.is_empty() && self.idle_residency_ms.len() == prev.idle_residency_ms.len() {
+            let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+            let busiest = self.idle_residency_ms.iter()
+                .zip(&prev.idle_residency_ms)
+                .map(|(idle, prev_idle)| 1.0 - idle.saturating_sub(*prev_idle) as f64 / elapsed_ms)
+                .fold(0.0, f64::max);
+            return Some((busiest * 100.0).clamp(0.0, 100.0));
+        }
+
+        None
+    }
+}
+
+/// RC6 residency of every GT of an Intel card: i915 `gt/gt*/rc6_residency_ms`
+/// (or the legacy `power/rc6_residency_ms`), xe `device/tile*/gt*/gtidle/idle_residency_ms`.
+fn read_idle_residency_ms(card_path: &str) -> Vec<u64> {
+    let mut paths: Vec<String> = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(format!("{}/gt", card_path)) {
+        paths.extend(entries.flatten().map(|e| format!("{}/rc6_residency_ms", e.path().display())));
+    }
+    if paths.is_empty() {
+        paths.push(format!("{}/power/rc6_residency_ms", card_path));
+    }
+    if let Ok(tiles) = std::fs::read_dir(format!("{}/device", card_path)) {
+        for tile in tiles.flatten().filter(|t| t.file_name().to_string_lossy().starts_with("tile")) {
+            if let Ok(gts) = std::fs::read_dir(tile.path()) {
+                paths.extend(
+                    gts.flatten()
+                        .filter(|g| g.file_name().to_string_lossy().starts_with("gt"))
+                        .map(|g| format!("{}/gtidle/idle_residency_ms", g.path().display())),
+                );
+            }
+        }
+    }
+    paths.sort();
+    paths.iter().filter_map(|p| read_sysfs_u64(p)).collect()
+}
+
 // ---------------------------------------------------------------------------
 // GPU backend detection
 // ---------------------------------------------------------------------------
@@ -160,18 +451,37 @@ fn detect_backends() -> Vec<GpuBackend> {
 
 pub struct GpuCollector {
     backends: Vec<GpuBackend>,
+    /// Last engine sample per Intel card path
+    intel_samples: HashMap<String, IntelEngineSample>,
+    /// DRM clients of the AMD/Intel cards, read once per `collect_system`
+    drm_clients: Vec<(u32, DrmFdinfo)>,
 }
 
 impl GpuCollector {
     pub fn new() -> Self {
         Self {
             backends: detect_backends(),
+            intel_samples: HashMap::new(),
+            drm_clients: Vec::new(),
         }
     }
 
-    pub fn collect_system(&self) -> Vec<GpuInfo> {
+    /// Collect device stats. Also walks the DRM clients that
+    /// `collect_per_process` reports from, so call it first each cycle.
+    pub fn collect_system(&mut self) -> Vec<GpuInfo> {
         let mut gpu_infos = Vec::new();
 
+        // AMD/Intel: DRM fdinfo (kernel 5.19+ for amdgpu, 6.x for i915/xe)
+        let pdevs: Vec<String> = self.backends.iter()
+            .filter_map(|backend| match backend {
+                GpuBackend::Amd { device_path, .. } | GpuBackend::Intel { device_path, .. } => {
+                    Some(pci_slot_name(device_path))
+                }
+                _ => None,
+            })
+            .collect();
+        self.drm_clients = if pdevs.is_empty() { Vec::new() } else { collect_drm_clients(&pdevs) };
+
         for backend in &self.backends {
             match backend {
                 GpuBackend::Nvidia(nvml) => {
@@ -192,11 +502,18 @@ impl GpuCollector {
                 }
                 GpuBackend::Intel {
                     card_path,
-                    device_path: _,
+                    device_path,
                     hwmon_path,
                     name,
                 } => {
-                    gpu_infos.push(Self::collect_intel(card_path, hwmon_path, name));
+                    gpu_infos.push(Self::collect_intel(
+                        card_path,
+                        device_path,
+                        hwmon_path,
+                        name,
+                        &mut self.intel_samples,
+                        &self.drm_clients,
+                    ));
                 }
                 GpuBackend::None => {
                     // Skip None backends
@@ -219,7 +536,11 @@ impl GpuCollector {
                 }
             }
         }
-        // Per-process VRAM tracking not available via sysfs for AMD/Intel
+
+        // AMD/Intel: the DRM clients walked by the last collect_system()
+        for (pid, vram) in per_process_vram(&self.drm_clients) {
+            *map.entry(pid).or_insert(0) += vram;
+        }
 
         map
     }
@@ -243,6 +564,8 @@ impl GpuCollector {
         let power = device.power_usage().unwrap_or(0) as f64 / 1000.0; // mW to W
         let power_limit = device.enforced_power_limit().unwrap_or(0) as f64 / 1000.0;
         let fan = device.fan_speed(0).unwrap_or(0);
+        let core_clock = device.clock_info(Clock::Graphics).unwrap_or(0);
+        let mem_clock = device.clock_info(Clock::Memory).unwrap_or(0);
 
         GpuInfo {
             available: true,
@@ -254,6 +577,9 @@ impl GpuCollector {
             power_watts: power,
             power_limit_watts: power_limit,
             fan_speed_percent: fan,
+            core_clock_mhz: core_clock,
+            mem_clock_mhz: mem_clock,
+            is_discrete: true,
         }
     }
 
@@ -307,7 +633,6 @@ impl GpuCollector {
             read_sysfs_u64(&format!("{}/mem_info_vram_used", device_path)).unwrap_or(0);
 
         let mut temperature: u32 = 0;
-        let mut power_watts: f64 = 0.0;
         let mut fan_speed_percent: u32 = 0;
 
         if let Some(ref hp) = hwmon_path {
@@ -316,11 +641,6 @@ impl GpuCollector {
                 .map(|v| (v / 1000) as u32)
                 .unwrap_or(0);
 
-            // power1_average is in microwatts
-            power_watts = read_sysfs_u64(&format!("{}/power1_average", hp))
-                .map(|v| v as f64 / 1_000_000.0)
-                .unwrap_or(0.0);
-
             // Fan speed: pwm1 is 0-255, convert to percent
             // Or try fan1_input (RPM) — use pwm1 for percentage
             fan_speed_percent = read_sysfs_u64(&format!("{}/pwm1", hp))
@@ -328,13 +648,19 @@ impl GpuCollector {
                 .unwrap_or(0);
         }
 
-        // AMD sysfs doesn't expose a power limit file consistently;
-        // try power1_cap (microwatts)
-        let power_limit_watts = hwmon_path
-            .as_ref()
-            .and_then(|hp| read_sysfs_u64(&format!("{}/power1_cap", hp)))
-            .map(|v| v as f64 / 1_000_000.0)
-            .unwrap_or(0.0);
+        // Newer cards (RDNA 3 and some RDNA 2) report instantaneous draw in
+        // power1_input instead of power1_average
+        let power_watts = read_hwmon_watts(device_path, &["power1_average", "power1_input"]).unwrap_or(0.0);
+
+        // The enforced cap, else the board default, else the highest settable cap
+        let power_limit_watts =
+            read_hwmon_watts(device_path, &["power1_cap", "power1_cap_default", "power1_cap_max"]).unwrap_or(0.0);
+
+        let core_clock_mhz = read_amd_dpm_clock(device_path, "pp_dpm_sclk").unwrap_or(0);
+        let mem_clock_mhz = read_amd_dpm_clock(device_path, "pp_dpm_mclk").unwrap_or(0);
+
+        // Only dGPUs have dedicated VRAM chips, so APUs don't report a VRAM vendor
+        let is_discrete = std::path::Path::new(&format!("{}/mem_info_vram_vendor", device_path)).exists();
 
         GpuInfo {
             available: true,
@@ -346,6 +672,9 @@ impl GpuCollector {
             power_watts,
             power_limit_watts,
             fan_speed_percent,
+            core_clock_mhz,
+            mem_clock_mhz,
+            is_discrete,
         }
     }
 
@@ -353,7 +682,14 @@ impl GpuCollector {
     // Intel (sysfs)
     // ------------------------------------------------------------------
 
-    fn collect_intel(card_path: &str, hwmon_path: &Option<String>, name: &str) -> GpuInfo {
+    fn collect_intel(
+        card_path: &str,
+        device_path: &str,
+        hwmon_path: &Option<String>,
+        name: &str,
+        samples: &mut HashMap<String, IntelEngineSample>,
+        drm_clients: &[(u32, DrmFdinfo)],
+    ) -> GpuInfo {
         // Intel integrated GPUs expose much less info than discrete.
         // Intel Arc (discrete) may have hwmon entries.
 
@@ -382,23 +718,28 @@ impl GpuCollector {
             .unwrap_or(0.0);
 
         // Intel discrete (Arc) may have VRAM info under device/
-        let device_path = format!("{}/device", card_path);
         let vram_total =
             read_sysfs_u64(&format!("{}/mem_info_vram_total", device_path)).unwrap_or(0);
         let vram_used =
             read_sysfs_u64(&format!("{}/mem_info_vram_used", device_path)).unwrap_or(0);
 
-        // Try to read current frequency (informational — fits utilization_percent
-        // as a rough indicator when no busy_percent exists)
-        let _cur_freq = read_sysfs_u64(&format!("{}/gt_cur_freq_mhz", card_path));
-        let _max_freq = read_sysfs_u64(&format!("{}/gt_max_freq_mhz", card_path));
-
-        // Utilization: Intel doesn't expose gpu_busy_percent in sysfs for
-        // most cases, but some discrete cards may. Try it.
-        let utilization =
+        // Current GT frequency; memory clocks aren't exposed
+        let core_clock_mhz = read_sysfs_u64(&format!("{}/gt_cur_freq_mhz", card_path)).unwrap_or(0) as u32;
+
+        // Utilization: engine busy counters from fdinfo (or RC6 residency)
+        // need two samples; the first tick and kernels without either fall
+        // back to gpu_busy_percent, which only some discrete cards expose.
+        let pdev = pci_slot_name(device_path);
+        let sample = IntelEngineSample::read(card_path, &pdev, drm_clients);
+        let engine_utilization = samples
+            .get(card_path)
+            .and_then(|prev| sample.utilization_since(prev));
+        samples.insert(card_path.to_string(), sample);
+        let utilization = engine_utilization.unwrap_or_else(|| {
             read_sysfs_u64(&format!("{}/gpu_busy_percent", device_path))
                 .map(|v| v as f64)
-                .unwrap_or(0.0);
+                .unwrap_or(0.0)
+        });
 
         GpuInfo {
             available: true,
@@ -410,6 +751,11 @@ impl GpuCollector {
             power_watts,
             power_limit_watts,
             fan_speed_percent,
+            core_clock_mhz,
+            mem_clock_mhz: 0,
+            // Integrated Intel graphics sit on the root bus (0000:00:02.0);
+            // Arc cards hang off a PCIe port on a bus of their own
+            is_discrete: pdev.split(':').nth(1).is_some_and(|bus| bus != "00"),
         }
     }
 }
@@ -1,8 +1,10 @@
+use serde::Serialize;
 use std::collections::{HashMap, VecDeque};
 
-const MAX_SAMPLES: usize = 300; // 5 minutes at 1 sample/sec
+/// Samples kept per app, one per collection cycle (5 minutes at 1 sample/sec)
+pub const MAX_SAMPLES: usize = 300;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AppHistory {
     pub display_name: String,
     pub cpu_samples: VecDeque<f64>,
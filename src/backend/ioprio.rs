@@ -0,0 +1,46 @@
+// Thin wrappers around the ioprio_get/ioprio_set syscalls (no libc wrappers exist).
+//
+// An I/O priority packs the scheduling class into the top bits and the level
+// (0 = highest, 7 = lowest) into the low bits:
+//   ioprio = (class << 13) | level
+
+use std::io;
+
+pub const IOPRIO_CLASS_NONE: u32 = 0;
+pub const IOPRIO_CLASS_RT: u32 = 1;
+pub const IOPRIO_CLASS_BE: u32 = 2;
+pub const IOPRIO_CLASS_IDLE: u32 = 3;
+
+const IOPRIO_CLASS_SHIFT: u32 = 13;
+const IOPRIO_PRIO_MASK: u32 = (1 << IOPRIO_CLASS_SHIFT) - 1;
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+
+/// Read the I/O scheduling class and level of `pid`.
+pub fn get(pid: i32) -> io::Result<(u32, i32)> {
+    let result = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, pid) };
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let ioprio = result as u32;
+    Ok(((ioprio >> IOPRIO_CLASS_SHIFT) & 0x3, (ioprio & IOPRIO_PRIO_MASK) as i32))
+}
+
+/// Set the I/O scheduling class and level (0-7) of `pid`.
+/// The realtime class requires CAP_SYS_ADMIN.
+pub fn set(pid: i32, class: u32, level: i32) -> io::Result<()> {
+    let ioprio = (class << IOPRIO_CLASS_SHIFT) | (level.clamp(0, 7) as u32);
+    let result = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, pid, ioprio) };
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+pub fn class_name(class: u32) -> &'static str {
+    match class {
+        IOPRIO_CLASS_RT => "realtime",
+        IOPRIO_CLASS_BE => "best-effort",
+        IOPRIO_CLASS_IDLE => "idle",
+        _ => "none",
+    }
+}
@@ -0,0 +1,61 @@
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Start `argv` in its own session so it outlives the task manager. With
+/// `as_admin` it runs through pkexec, which asks for the password itself.
+pub fn launch(argv: &[OsString], cwd: Option<&Path>, as_admin: bool) -> Result<(), String> {
+    let Some((program, args)) = argv.split_first() else {
+        return Err("No command given".to_string());
+    };
+
+    let mut command = if as_admin {
+        let mut command = Command::new("pkexec");
+        // pkexec starts in root's home, so change directory in a shell
+        if let Some(dir) = cwd {
+            command.args(["/bin/sh", "-c", "cd -- \"$1\" && shift && exec \"$@\"", "sh"]);
+            command.arg(dir);
+        }
+        command.arg(program).args(args);
+        command
+    } else {
+        let mut command = Command::new(program);
+        command.args(args);
+        if let Some(dir) = cwd {
+            command.current_dir(dir);
+        }
+        command
+    };
+
+    spawn_detached(&mut command, &program.to_string_lossy())?;
+    Ok(())
+}
+
+/// Spawn `command` in its own session with no stdio and return its PID.
+/// A thread waits for it, so it doesn't linger as a zombie once it exits.
+pub fn spawn_detached(command: &mut Command, name: &str) -> Result<u32, String> {
+    use std::os::unix::process::CommandExt;
+
+    let mut child = unsafe {
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .pre_exec(|| {
+                libc::setsid();
+                Ok(())
+            })
+            .spawn()
+            .map_err(|e| format!("Failed to start {}: {}", name, e))?
+    };
+    let pid = child.id();
+
+    let name = name.to_string();
+    std::thread::spawn(move || match child.wait() {
+        Ok(status) if !status.success() => log::info!("{} exited with {}", name, status),
+        Ok(_) => {}
+        Err(e) => log::warn!("Failed to wait for {}: {}", name, e),
+    });
+
+    Ok(pid)
+}
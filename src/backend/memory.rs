@@ -1,5 +1,5 @@
 use std::fs;
-use crate::model::MemoryInfo;
+use crate::model::{MemoryInfo, SwapDevice};
 
 pub struct MemoryCollector;
 
@@ -38,7 +38,45 @@ impl MemoryCollector {
             }
         }
         info.swap_used = info.swap_total.saturating_sub(swap_free);
+        info.swap_devices = read_swap_devices();
 
         info
     }
 }
+
+/// Parse /proc/swaps (sizes are in KiB)
+fn read_swap_devices() -> Vec<SwapDevice> {
+    let swaps = fs::read_to_string("/proc/swaps").unwrap_or_default();
+    swaps
+        .lines()
+        .skip(1) // Header: Filename Type Size Used Priority
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 5 {
+                return None;
+            }
+            let name = super::disk::unescape_mount_field(parts[0]);
+            let zram = name.strip_prefix("/dev/").filter(|dev| dev.starts_with("zram"));
+            let compressed = zram.map(zram_compressed_size).unwrap_or(0);
+            Some(SwapDevice {
+                is_zram: zram.is_some(),
+                swap_type: parts[1].to_string(),
+                size: parts[2].parse::<u64>().unwrap_or(0) * 1024,
+                used: parts[3].parse::<u64>().unwrap_or(0) * 1024,
+                priority: parts[4].parse().unwrap_or(0),
+                compressed,
+                name,
+            })
+        })
+        .collect()
+}
+
+/// compr_data_size is the second field of /sys/block/zramN/mm_stat
+fn zram_compressed_size(dev: &str) -> u64 {
+    fs::read_to_string(format!("/sys/block/{}/mm_stat", dev))
+        .unwrap_or_default()
+        .split_whitespace()
+        .nth(1)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0)
+}
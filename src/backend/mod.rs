@@ -18,7 +18,17 @@ pub mod history;
 pub mod net_per_process;
 pub mod psi;
 pub mod export;
+pub mod ioprio;
+pub mod oom;
+pub mod process_restart;
+pub mod cgroup;
+pub mod process_report;
+pub mod capabilities;
+pub mod launcher;
+pub mod unit_file;
+pub mod system_info;
 
-pub use collector::Collector;
+pub use collector::{build_unit_groups, is_kernel_thread, Collector, PriorityRules};
 pub use desktop_resolver::DesktopResolver;
+pub use process::read_smaps_rollup;
 pub use window_resolver::WindowResolver;
@@ -1,8 +1,11 @@
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fs;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
-#[derive(Debug, Clone)]
+const PROTOCOLS: [&str; 4] = ["tcp", "tcp6", "udp", "udp6"];
+
+#[derive(Debug, Clone, Serialize)]
 pub struct NetConnection {
     pub protocol: String,     // "tcp", "tcp6", "udp", "udp6"
     pub local_addr: String,
@@ -10,6 +13,9 @@ pub struct NetConnection {
     pub remote_addr: String,
     pub remote_port: u16,
     pub state: String,
+    pub inode: u64,
+    /// Owning process; 0 when unknown (another user's process, or TIME_WAIT)
+    pub pid: i32,
 }
 
 /// Collect network connections for a specific process.
@@ -23,16 +29,14 @@ pub fn collect_process_connections(pid: i32) -> Vec<NetConnection> {
     }
 
     // Parse /proc/net/* for all connections, filter by inodes
-    for (proto, path) in &[
-        ("tcp", format!("/proc/{}/net/tcp", pid)),
-        ("tcp6", format!("/proc/{}/net/tcp6", pid)),
-        ("udp", format!("/proc/{}/net/udp", pid)),
-        ("udp6", format!("/proc/{}/net/udp6", pid)),
-    ] {
-        if let Ok(content) = fs::read_to_string(path) {
+    for proto in PROTOCOLS {
+        if let Ok(content) = fs::read_to_string(format!("/proc/{}/net/{}", pid, proto)) {
             for line in content.lines().skip(1) {
-                if let Some(conn) = parse_net_line(line, proto, &socket_inodes) {
-                    connections.push(conn);
+                if let Some(mut conn) = parse_net_line(line, proto) {
+                    if socket_inodes.contains_key(&conn.inode) {
+                        conn.pid = pid;
+                        connections.push(conn);
+                    }
                 }
             }
         }
@@ -41,12 +45,199 @@ pub fn collect_process_connections(pid: i32) -> Vec<NetConnection> {
     connections
 }
 
-fn get_socket_inodes(pid: i32) -> HashMap<u64, ()> {
+/// Every TCP/UDP socket in the collector's network namespace, with no owner
+/// filled in yet (`pid` 0).
+pub fn read_socket_table() -> Vec<NetConnection> {
+    let mut connections = Vec::new();
+    for proto in PROTOCOLS {
+        if let Ok(content) = fs::read_to_string(format!("/proc/net/{}", proto)) {
+            connections.extend(content.lines().skip(1).filter_map(|line| parse_net_line(line, proto)));
+        }
+    }
+    connections
+}
+
+/// Tracks per-socket TCP byte counters between collections so the process
+/// collector can turn them into per-process upload/download rates.
+///
+/// Counters come from the kernel's `sock_diag` netlink interface
+/// (`tcpi_bytes_received` / `tcpi_bytes_acked`), so only TCP sockets in the
+/// collector's own network namespace are counted. UDP traffic (including
+/// QUIC) is not attributed.
+pub struct SocketTrafficTracker {
+    prev: HashMap<u64, (u64, u64)>, // inode -> (bytes_received, bytes_acked)
+    primed: bool,
+}
+
+impl SocketTrafficTracker {
+    pub fn new() -> Self {
+        Self {
+            prev: HashMap::new(),
+            primed: false,
+        }
+    }
+
+    /// Poll the kernel and return per-socket (rx, tx) byte deltas since the
+    /// previous call, keyed by socket inode.
+    pub fn collect(&mut self) -> HashMap<u64, (u64, u64)> {
+        let mut current = HashMap::new();
+        for family in [libc::AF_INET as u8, libc::AF_INET6 as u8] {
+            if let Err(e) = dump_tcp_counters(family, &mut current) {
+                log::debug!("sock_diag dump failed for family {}: {}", family, e);
+            }
+        }
+
+        let mut deltas = HashMap::new();
+        if self.primed {
+            for (inode, &(rx, tx)) in &current {
+                // Sockets that appeared since the last poll count from zero
+                let (prev_rx, prev_tx) = self.prev.get(inode).copied().unwrap_or((0, 0));
+                let delta = (rx.saturating_sub(prev_rx), tx.saturating_sub(prev_tx));
+                if delta != (0, 0) {
+                    deltas.insert(*inode, delta);
+                }
+            }
+        }
+
+        self.prev = current;
+        self.primed = true;
+        deltas
+    }
+}
+
+/// Sum the socket deltas belonging to a process (given its socket inodes)
+/// into (rx, tx) bytes.
+pub fn process_traffic(socket_inodes: &HashMap<u64, ()>, deltas: &HashMap<u64, (u64, u64)>) -> (u64, u64) {
+    if deltas.is_empty() {
+        return (0, 0);
+    }
+    socket_inodes
+        .keys()
+        .filter_map(|inode| deltas.get(inode))
+        .fold((0, 0), |(rx, tx), (drx, dtx)| (rx + drx, tx + dtx))
+}
+
+const SOCK_DIAG_BY_FAMILY: u16 = 20;
+const INET_DIAG_INFO: u16 = 2;
+const INET_DIAG_MSG_LEN: usize = 72;
+// Offsets of tcpi_bytes_acked / tcpi_bytes_received within struct tcp_info
+const TCPI_BYTES_ACKED_OFFSET: usize = 120;
+const TCPI_BYTES_RECEIVED_OFFSET: usize = 128;
+
+/// Dump all TCP sockets of one address family over NETLINK_SOCK_DIAG and
+/// record (bytes_received, bytes_acked) per socket inode.
+fn dump_tcp_counters(family: u8, out: &mut HashMap<u64, (u64, u64)>) -> Result<(), std::io::Error> {
+    let fd = unsafe {
+        libc::socket(libc::AF_NETLINK, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, libc::NETLINK_SOCK_DIAG)
+    };
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let result = dump_on_socket(fd, family, out);
+    unsafe { libc::close(fd) };
+    result
+}
+
+fn dump_on_socket(fd: i32, family: u8, out: &mut HashMap<u64, (u64, u64)>) -> Result<(), std::io::Error> {
+    // nlmsghdr (16 bytes) + inet_diag_req_v2 (56 bytes)
+    const REQ_LEN: usize = 72;
+    let mut req = [0u8; REQ_LEN];
+    req[0..4].copy_from_slice(&(REQ_LEN as u32).to_ne_bytes());
+    req[4..6].copy_from_slice(&SOCK_DIAG_BY_FAMILY.to_ne_bytes());
+    req[6..8].copy_from_slice(&((libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16).to_ne_bytes());
+    req[8..12].copy_from_slice(&1u32.to_ne_bytes());
+    req[16] = family;
+    req[17] = libc::IPPROTO_TCP as u8;
+    req[18] = 1 << (INET_DIAG_INFO - 1);
+    req[20..24].copy_from_slice(&u32::MAX.to_ne_bytes()); // all TCP states
+
+    let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+    addr.nl_family = libc::AF_NETLINK as u16;
+    let sent = unsafe {
+        libc::sendto(
+            fd,
+            req.as_ptr() as *const libc::c_void,
+            req.len(),
+            0,
+            &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+            std::mem::size_of::<libc::sockaddr_nl>() as u32,
+        )
+    };
+    if sent < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    let mut buf = vec![0u8; 32 * 1024];
+    loop {
+        let len = unsafe { libc::recv(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0) };
+        if len < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let data = &buf[..len as usize];
+        let mut offset = 0;
+        while offset + 16 <= data.len() {
+            let msg_len = u32::from_ne_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+            let msg_type = u16::from_ne_bytes(data[offset + 4..offset + 6].try_into().unwrap());
+            if msg_len < 16 || offset + msg_len > data.len() {
+                return Ok(());
+            }
+            match msg_type as i32 {
+                libc::NLMSG_DONE => return Ok(()),
+                libc::NLMSG_ERROR => {
+                    let errno = i32::from_ne_bytes(data[offset + 16..offset + 20].try_into().unwrap_or([0; 4]));
+                    return Err(std::io::Error::from_raw_os_error(-errno));
+                }
+                _ => parse_diag_msg(&data[offset + 16..offset + msg_len], out),
+            }
+            offset += (msg_len + 3) & !3;
+        }
+    }
+}
+
+fn parse_diag_msg(payload: &[u8], out: &mut HashMap<u64, (u64, u64)>) {
+    if payload.len() < INET_DIAG_MSG_LEN {
+        return;
+    }
+    let inode = u32::from_ne_bytes(payload[68..72].try_into().unwrap()) as u64;
+    if inode == 0 {
+        return;
+    }
+
+    // Walk the rtattrs that follow inet_diag_msg looking for INET_DIAG_INFO
+    let mut offset = INET_DIAG_MSG_LEN;
+    while offset + 4 <= payload.len() {
+        let attr_len = u16::from_ne_bytes(payload[offset..offset + 2].try_into().unwrap()) as usize;
+        let attr_type = u16::from_ne_bytes(payload[offset + 2..offset + 4].try_into().unwrap());
+        if attr_len < 4 || offset + attr_len > payload.len() {
+            return;
+        }
+        if attr_type == INET_DIAG_INFO {
+            let info = &payload[offset + 4..offset + attr_len];
+            if info.len() >= TCPI_BYTES_RECEIVED_OFFSET + 8 {
+                let read_u64 = |at: usize| u64::from_ne_bytes(info[at..at + 8].try_into().unwrap());
+                out.insert(inode, (read_u64(TCPI_BYTES_RECEIVED_OFFSET), read_u64(TCPI_BYTES_ACKED_OFFSET)));
+            }
+            return;
+        }
+        offset += (attr_len + 3) & !3;
+    }
+}
+
+/// Inodes of the sockets `pid` has open, from the links in /proc/<pid>/fd.
+pub fn get_socket_inodes(pid: i32) -> HashMap<u64, ()> {
+    scan_fds(pid).0
+}
+
+/// Socket inodes and the total number of open descriptors of `pid`, from
+/// one walk of /proc/<pid>/fd.
+pub fn scan_fds(pid: i32) -> (HashMap<u64, ()>, u32) {
     let mut inodes = HashMap::new();
+    let mut fd_count = 0;
     let fd_dir = format!("/proc/{}/fd", pid);
 
     if let Ok(entries) = fs::read_dir(&fd_dir) {
         for entry in entries.flatten() {
+            fd_count += 1;
             if let Ok(link) = fs::read_link(entry.path()) {
                 let link_str = link.to_string_lossy();
                 // Socket links look like "socket:[12345]"
@@ -61,10 +252,10 @@ fn get_socket_inodes(pid: i32) -> HashMap<u64, ()> {
         }
     }
 
-    inodes
+    (inodes, fd_count)
 }
 
-fn parse_net_line(line: &str, protocol: &str, socket_inodes: &HashMap<u64, ()>) -> Option<NetConnection> {
+fn parse_net_line(line: &str, protocol: &str) -> Option<NetConnection> {
     let fields: Vec<&str> = line.split_whitespace().collect();
     if fields.len() < 10 {
         return None;
@@ -73,14 +264,15 @@ fn parse_net_line(line: &str, protocol: &str, socket_inodes: &HashMap<u64, ()>)
     // Field layout: sl local_address rem_address st ... inode
     let inode: u64 = fields[9].parse().ok()?;
 
-    if !socket_inodes.contains_key(&inode) {
-        return None;
-    }
-
     let (local_addr, local_port) = parse_addr_port(fields[1], protocol)?;
     let (remote_addr, remote_port) = parse_addr_port(fields[2], protocol)?;
     let state_num: u8 = u8::from_str_radix(fields[3], 16).ok()?;
-    let state = tcp_state_name(state_num).to_string();
+    // UDP reuses the TCP numbers; an unconnected socket is "CLOSE" (like ss's UNCONN)
+    let state = if protocol.starts_with("udp") && state_num == 0x07 {
+        "UNCONN".to_string()
+    } else {
+        tcp_state_name(state_num).to_string()
+    };
 
     Some(NetConnection {
         protocol: protocol.to_string(),
@@ -89,6 +281,8 @@ fn parse_net_line(line: &str, protocol: &str, socket_inodes: &HashMap<u64, ()>)
         remote_addr,
         remote_port,
         state,
+        inode,
+        pid: 0,
     })
 }
 
@@ -1,4 +1,5 @@
 use std::fs;
+use std::path::Path;
 use crate::model::{NetworkInfo, NetworkInterface};
 
 pub struct NetworkCollector {
@@ -44,6 +45,9 @@ impl NetworkCollector {
                 (0.0, 0.0)
             };
 
+            // Bridges, veths, tunnels etc. live under the virtual device tree
+            let is_virtual = Path::new("/sys/devices/virtual/net").join(&name).exists();
+
             current_stats.push((name.clone(), rx_bytes, tx_bytes));
             interfaces.push(NetworkInterface {
                 name,
@@ -51,6 +55,7 @@ impl NetworkCollector {
                 tx_bytes_sec: tx_rate,
                 total_rx: rx_bytes,
                 total_tx: tx_bytes,
+                is_virtual,
             });
         }
 
@@ -0,0 +1,25 @@
+// The kernel's OOM killer picks the process with the highest oom_score;
+// oom_score_adj (-1000..1000) biases it, with -1000 meaning "never kill".
+
+use std::fs;
+use std::io;
+
+pub const OOM_SCORE_ADJ_MIN: i32 = -1000;
+pub const OOM_SCORE_ADJ_MAX: i32 = 1000;
+
+/// Current badness score and adjustment of `pid`.
+pub fn read(pid: i32) -> Option<(i32, i32)> {
+    let read_value = |file: &str| {
+        fs::read_to_string(format!("/proc/{}/{}", pid, file))
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+    };
+    Some((read_value("oom_score")?, read_value("oom_score_adj")?))
+}
+
+/// Set the OOM score adjustment of `pid`. Lowering it below its current
+/// value requires CAP_SYS_RESOURCE.
+pub fn set_adj(pid: i32, adj: i32) -> io::Result<()> {
+    let adj = adj.clamp(OOM_SCORE_ADJ_MIN, OOM_SCORE_ADJ_MAX);
+    fs::write(format!("/proc/{}/oom_score_adj", pid), adj.to_string())
+}
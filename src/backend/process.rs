@@ -1,3 +1,5 @@
+use crate::backend::net_per_process::{self, NetConnection, SocketTrafficTracker};
+use crate::backend::window_resolver::WindowTitles;
 use crate::model::ProcessInfo;
 use std::collections::HashMap;
 use std::fs;
@@ -5,7 +7,14 @@ use std::fs;
 pub struct ProcessCollector {
     prev_processes: HashMap<i32, (u64, u64, u64)>, // pid -> (cpu_time, disk_read, disk_write)
     prev_total_cpu: u64,
+    prev_time: std::time::Instant,
     total_memory: u64,
+    socket_traffic: SocketTrafficTracker,
+    // System-wide sockets from the last collect(), with owners filled in
+    connections: Vec<NetConnection>,
+    // For turning stat start times (ticks since boot) into wall-clock time
+    boot_time: u64,
+    ticks_per_sec: u64,
 }
 
 impl ProcessCollector {
@@ -14,19 +23,41 @@ impl ProcessCollector {
         Self {
             prev_processes: HashMap::new(),
             prev_total_cpu: 0,
+            prev_time: std::time::Instant::now(),
             total_memory,
+            socket_traffic: SocketTrafficTracker::new(),
+            connections: Vec::new(),
+            boot_time: get_boot_time(),
+            ticks_per_sec: unsafe { libc::sysconf(libc::_SC_CLK_TCK) }.max(1) as u64,
         }
     }
 
+    /// Sockets seen by the last `collect()`; taking them leaves the list empty.
+    pub fn take_connections(&mut self) -> Vec<NetConnection> {
+        std::mem::take(&mut self.connections)
+    }
+
     pub fn collect(
         &mut self,
         gpu_vram: &HashMap<u32, u64>,
         desktop_names: &HashMap<String, String>,
-        window_titles: &HashMap<u32, String>,
+        window_titles: &WindowTitles,
+        priority_rules: &HashMap<String, i32>,
+        measure_pss: bool,
     ) -> Vec<ProcessInfo> {
         let total_cpu = read_total_cpu_time();
         let delta_total = total_cpu.saturating_sub(self.prev_total_cpu);
         let num_cores = num_cpus();
+        let elapsed = self.prev_time.elapsed().as_secs_f64().max(0.001);
+        self.prev_time = std::time::Instant::now();
+        let socket_deltas = self.socket_traffic.collect();
+        let mut connections = net_per_process::read_socket_table();
+        let socket_index: HashMap<u64, usize> = connections
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.inode != 0)
+            .map(|(i, c)| (c.inode, i))
+            .collect();
 
         let mut processes = Vec::new();
         let proc_entries = fs::read_dir("/proc").unwrap_or_else(|_| {
@@ -41,10 +72,17 @@ impl ProcessCollector {
                 Err(_) => continue,
             };
 
-            if let Some(mut info) = read_process(pid) {
-                // CPU percent
+            if let Some(mut info) = read_process(pid, measure_pss) {
                 let prev = self.prev_processes.get(&pid);
-                let prev_cpu = prev.map(|(c, _, _)| *c).unwrap_or(0);
+
+                // Remembered priority, applied once when the PID first shows up
+                if prev.is_none() {
+                    apply_priority_rule(&mut info, priority_rules);
+                }
+
+                // CPU percent. A newly seen PID starts from its current time, so
+                // its first sample reads 0 instead of its whole lifetime's usage.
+                let prev_cpu = prev.map(|(c, _, _)| *c).unwrap_or(info.total_cpu_time);
                 let cpu_delta = info.total_cpu_time.saturating_sub(prev_cpu);
                 info.cpu_percent = if delta_total > 0 {
                     (cpu_delta as f64 / delta_total as f64) * 100.0 * num_cores as f64
@@ -53,6 +91,8 @@ impl ProcessCollector {
                 };
                 info.prev_cpu_time = prev_cpu;
 
+                info.started_at = self.boot_time + info.start_time / self.ticks_per_sec;
+
                 // Memory percent
                 info.memory_percent = if self.total_memory > 0 {
                     (info.memory_bytes as f64 / self.total_memory as f64) * 100.0
@@ -63,19 +103,34 @@ impl ProcessCollector {
                 // Disk I/O rates
                 let prev_dr = prev.map(|(_, r, _)| *r).unwrap_or(info.disk_read_bytes);
                 let prev_dw = prev.map(|(_, _, w)| *w).unwrap_or(info.disk_write_bytes);
-                info.disk_read_rate = info.disk_read_bytes.saturating_sub(prev_dr) as f64;
-                info.disk_write_rate = info.disk_write_bytes.saturating_sub(prev_dw) as f64;
+                info.disk_read_rate = info.disk_read_bytes.saturating_sub(prev_dr) as f64 / elapsed;
+                info.disk_write_rate = info.disk_write_bytes.saturating_sub(prev_dw) as f64 / elapsed;
                 info.prev_disk_read = prev_dr;
                 info.prev_disk_write = prev_dw;
 
+                // Socket ownership, for the connection count and the system-wide list.
+                // A socket shared after fork() is credited to the first PID seen.
+                let (socket_inodes, fd_count) = net_per_process::scan_fds(pid);
+                info.fd_count = fd_count;
+                for inode in socket_inodes.keys() {
+                    if let Some(&i) = socket_index.get(inode) {
+                        info.connection_count += 1;
+                        if connections[i].pid == 0 {
+                            connections[i].pid = pid;
+                        }
+                    }
+                }
+
+                // Network rates (TCP socket byte counters)
+                let (rx, tx) = net_per_process::process_traffic(&socket_inodes, &socket_deltas);
+                info.rx_bytes_sec = rx as f64 / elapsed;
+                info.tx_bytes_sec = tx as f64 / elapsed;
+
                 // GPU VRAM
                 if let Some(&vram) = gpu_vram.get(&(pid as u32)) {
                     info.vram_bytes = vram;
                 }
 
-                // Display name resolution
-                resolve_display_name(&mut info, window_titles, desktop_names);
-
                 self.prev_processes.insert(pid, (
                     info.total_cpu_time,
                     info.disk_read_bytes,
@@ -87,6 +142,13 @@ impl ProcessCollector {
         }
 
         self.prev_total_cpu = total_cpu;
+        self.connections = connections;
+
+        // Display name resolution; Wayland app ids need the whole list to match against
+        let titles = window_titles.by_process(&processes);
+        for info in &mut processes {
+            resolve_display_name(info, &titles, desktop_names);
+        }
 
         // Prune dead processes
         let live_pids: std::collections::HashSet<i32> = processes.iter().map(|p| p.pid).collect();
@@ -96,7 +158,29 @@ impl ProcessCollector {
     }
 }
 
-fn read_process(pid: i32) -> Option<ProcessInfo> {
+/// PSS, shared and private bytes from /proc/<pid>/smaps_rollup, which is
+/// only readable for our own processes (or as root). Costly: the kernel walks
+/// the process's page tables to produce it.
+pub fn read_smaps_rollup(pid: i32) -> Option<(u64, u64, u64)> {
+    let rollup = fs::read_to_string(format!("/proc/{}/smaps_rollup", pid)).ok()?;
+    let (mut pss, mut shared, mut private) = (0, 0, 0);
+    for line in rollup.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let bytes = value.split_whitespace().next()
+            .and_then(|s| s.parse::<u64>().ok()).unwrap_or(0) * 1024;
+        match key {
+            "Pss" => pss = bytes,
+            "Shared_Clean" | "Shared_Dirty" => shared += bytes,
+            "Private_Clean" | "Private_Dirty" => private += bytes,
+            _ => {}
+        }
+    }
+    Some((pss, shared, private))
+}
+
+fn read_process(pid: i32, measure_pss: bool) -> Option<ProcessInfo> {
     let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
     let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
 
@@ -132,6 +216,9 @@ fn read_process(pid: i32) -> Option<ProcessInfo> {
         } else if let Some(val) = line.strip_prefix("VmRSS:") {
             info.memory_bytes = val.trim().split_whitespace().next()
                 .and_then(|s| s.parse::<u64>().ok()).unwrap_or(0) * 1024;
+        } else if let Some(val) = line.strip_prefix("VmSwap:") {
+            info.swap_bytes = val.trim().split_whitespace().next()
+                .and_then(|s| s.parse::<u64>().ok()).unwrap_or(0) * 1024;
         }
     }
 
@@ -150,6 +237,15 @@ fn read_process(pid: i32) -> Option<ProcessInfo> {
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_default();
 
+    // PSS and the shared/private split, only when enabled
+    if measure_pss {
+        if let Some((pss, shared, private)) = read_smaps_rollup(pid) {
+            info.pss_bytes = pss;
+            info.shared_bytes = shared;
+            info.private_bytes = private;
+        }
+    }
+
     // Disk I/O
     if let Ok(io) = fs::read_to_string(format!("/proc/{}/io", pid)) {
         for line in io.lines() {
@@ -285,11 +381,40 @@ fn script_display_name(cmdline: &str) -> Option<String> {
     None
 }
 
+fn apply_priority_rule(info: &mut ProcessInfo, priority_rules: &HashMap<String, i32>) {
+    let Some(&nice) = priority_rules.get(&info.exe_path) else {
+        return;
+    };
+    if info.nice == nice {
+        return;
+    }
+    let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, info.pid as u32, nice) };
+    if result == 0 {
+        log::info!("Applied remembered priority {} to PID {} ({})", nice, info.pid, info.exe_path);
+        info.nice = nice;
+    } else {
+        log::warn!(
+            "Failed to apply remembered priority {} to PID {} ({}): {}",
+            nice,
+            info.pid,
+            info.exe_path,
+            std::io::Error::last_os_error()
+        );
+    }
+}
+
 fn detect_container_type(pid: i32, exe_path: &str) -> String {
-    // Check exe_path first (fast)
-    if exe_path.starts_with("/snap/") {
-        return "Snap".to_string();
+    let cgroup = fs::read_to_string(format!("/proc/{}/cgroup", pid)).unwrap_or_default();
+
+    // Flatpak and Snap apps are labeled with their app id when it can be found
+    if let Some(app_id) = flatpak_app_id(pid, &cgroup) {
+        return format!("Flatpak: {}", app_id);
     }
+    if let Some(name) = snap_name(exe_path, &cgroup) {
+        return format!("Snap: {}", name);
+    }
+
+    // Check exe_path (fast)
     if exe_path.contains("/.var/app/") || exe_path.contains("/app/com.") || exe_path.contains("/app/org.") {
         return "Flatpak".to_string();
     }
@@ -300,26 +425,21 @@ fn detect_container_type(pid: i32, exe_path: &str) -> String {
     }
 
     // Check cgroup for container hints
-    if let Ok(cgroup) = fs::read_to_string(format!("/proc/{}/cgroup", pid)) {
-        let cgroup_lower = cgroup.to_lowercase();
-        if cgroup_lower.contains("docker") {
-            return "Docker".to_string();
-        }
-        if cgroup_lower.contains("podman") {
-            return "Podman".to_string();
-        }
-        if cgroup_lower.contains("lxc") {
-            return "LXC".to_string();
-        }
-        if cgroup_lower.contains("flatpak") {
-            return "Flatpak".to_string();
-        }
-        if cgroup_lower.contains("snap.") {
-            return "Snap".to_string();
-        }
+    let cgroup_lower = cgroup.to_lowercase();
+    if cgroup_lower.contains("docker") {
+        return "Docker".to_string();
+    }
+    if cgroup_lower.contains("podman") {
+        return "Podman".to_string();
+    }
+    if cgroup_lower.contains("lxc") {
+        return "LXC".to_string();
+    }
+    if cgroup_lower.contains("flatpak") {
+        return "Flatpak".to_string();
     }
 
-    // Enhanced Flatpak detection: check if /.flatpak-info exists in process root
+    // Sandboxed, but /.flatpak-info had no app id
     if fs::metadata(format!("/proc/{}/root/.flatpak-info", pid)).is_ok() {
         return "Flatpak".to_string();
     }
@@ -335,6 +455,54 @@ fn detect_container_type(pid: i32, exe_path: &str) -> String {
     String::new()
 }
 
+/// Flatpak app id from the systemd scope in the cgroup path
+/// (".../app-flatpak-org.mozilla.firefox-12345.scope"), or from
+/// /.flatpak-info inside the sandbox ("[Application]" section, "name=" key).
+fn flatpak_app_id(pid: i32, cgroup: &str) -> Option<String> {
+    for segment in cgroup.lines().flat_map(|l| l.split('/')) {
+        if let Some(rest) = segment.strip_prefix("app-flatpak-").and_then(|s| s.strip_suffix(".scope")) {
+            let app_id = match rest.rsplit_once('-') {
+                Some((id, suffix)) if suffix.chars().all(|c| c.is_ascii_digit()) => id,
+                _ => rest,
+            };
+            if !app_id.is_empty() {
+                return Some(app_id.to_string());
+            }
+        }
+    }
+
+    let info = fs::read_to_string(format!("/proc/{}/root/.flatpak-info", pid)).ok()?;
+    let mut in_application = false;
+    for line in info.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_application = line == "[Application]";
+        } else if in_application {
+            if let Some(name) = line.strip_prefix("name=") {
+                return Some(name.trim().to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Snap name from the cgroup ("snap.<name>.<app>-<uuid>.scope" or
+/// "snap.<name>.<app>.service") or from an exe path under /snap/<name>/.
+fn snap_name(exe_path: &str, cgroup: &str) -> Option<String> {
+    for segment in cgroup.lines().flat_map(|l| l.split('/')) {
+        if let Some(rest) = segment.strip_prefix("snap.") {
+            if let Some(name) = rest.split('.').next().filter(|n| !n.is_empty()) {
+                return Some(name.to_string());
+            }
+        }
+    }
+    exe_path
+        .strip_prefix("/snap/")
+        .and_then(|rest| rest.split('/').next())
+        .filter(|n| !n.is_empty())
+        .map(|n| n.to_string())
+}
+
 fn read_total_cpu_time() -> u64 {
     fs::read_to_string("/proc/stat")
         .unwrap_or_default()
@@ -349,6 +517,22 @@ fn read_total_cpu_time() -> u64 {
         .unwrap_or(0)
 }
 
+/// Boot time as Unix seconds: `btime` from /proc/stat, else now minus uptime.
+pub fn get_boot_time() -> u64 {
+    let btime = fs::read_to_string("/proc/stat")
+        .unwrap_or_default()
+        .lines()
+        .find_map(|l| l.strip_prefix("btime "))
+        .and_then(|s| s.trim().parse::<u64>().ok());
+    btime.unwrap_or_else(|| {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        now.saturating_sub(crate::backend::cpu::uptime_secs())
+    })
+}
+
 fn get_total_memory() -> u64 {
     fs::read_to_string("/proc/meminfo")
         .unwrap_or_default()
@@ -384,37 +568,9 @@ fn get_username(uid: u32) -> String {
 }
 
 fn read_io_priority(pid: i32) -> (String, i32) {
-    // Use the ioprio_get syscall via libc
-    // syscall number: SYS_ioprio_get (platform-specific)
-    // ioprio_get(IOPRIO_WHO_PROCESS, pid) returns a u32
-    // class = (ioprio >> 13) & 0x3
-    // data = ioprio & 0x1fff (but typically 0-7)
-
-    unsafe {
-        #[cfg(target_arch = "x86_64")]
-        const SYS_IOPRIO_GET: i64 = 252;
-        #[cfg(target_arch = "aarch64")]
-        const SYS_IOPRIO_GET: i64 = 31;
-        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
-        const SYS_IOPRIO_GET: i64 = 252; // Fallback to x86_64
-
-        const IOPRIO_WHO_PROCESS: i32 = 1;
-
-        let result = libc::syscall(SYS_IOPRIO_GET, IOPRIO_WHO_PROCESS, pid);
-        if result < 0 {
-            return (String::new(), -1);
-        }
-        let ioprio = result as u32;
-        let class = (ioprio >> 13) & 0x3;
-        let data = (ioprio & 0x1fff) as i32;
-
-        let class_name = match class {
-            1 => "realtime",
-            2 => "best-effort",
-            3 => "idle",
-            _ => "none",
-        };
-        (class_name.to_string(), data)
+    match super::ioprio::get(pid) {
+        Ok((class, level)) => (super::ioprio::class_name(class).to_string(), level),
+        Err(_) => (String::new(), -1),
     }
 }
 
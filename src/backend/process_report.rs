@@ -0,0 +1,204 @@
+use crate::backend::net_per_process::{self, NetConnection};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Everything the details dialog shows about one process, in one artifact
+/// for bug reports.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessReport {
+    pub timestamp: u64,
+    pub pid: i32,
+    /// (label, value) rows of the General tab, in display order
+    pub general: Vec<(String, String)>,
+    /// NAME=value, with the values of likely secrets redacted
+    pub environment: Result<Vec<String>, String>,
+    pub open_files: Result<Vec<OpenFile>, String>,
+    pub maps: Result<Vec<MappingSummary>, String>,
+    pub connections: Vec<NetConnection>,
+    pub cgroup: Result<Vec<String>, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenFile {
+    pub fd: u32,
+    pub target: String,
+}
+
+/// Mappings of one file (or anonymous/special region) added together.
+#[derive(Debug, Clone, Serialize)]
+pub struct MappingSummary {
+    pub path: String,
+    pub regions: usize,
+    pub size_bytes: u64,
+}
+
+impl ProcessReport {
+    /// Read the process's /proc entries now. `general` comes from the caller,
+    /// which already has the collector's view of the process.
+    pub fn collect(pid: i32, general: Vec<(String, String)>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self {
+            timestamp,
+            pid,
+            general,
+            environment: read_environment(pid).map(redact_secrets),
+            open_files: read_open_files(pid),
+            maps: read_maps_summary(pid),
+            connections: net_per_process::collect_process_connections(pid),
+            cgroup: read_cgroup(pid),
+        }
+    }
+
+    pub fn save_json(&self, path: &Path) -> Result<(), String> {
+        let file = fs::File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+        serde_json::to_writer_pretty(file, self).map_err(|e| format!("Failed to write JSON: {}", e))
+    }
+
+    pub fn save_text(&self, path: &Path) -> Result<(), String> {
+        let mut file = fs::File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+        file.write_all(self.to_text().as_bytes())
+            .map_err(|e| format!("Failed to write report: {}", e))
+    }
+
+    fn to_text(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("Process report for PID {} (Unix time {})\n", self.pid, self.timestamp));
+
+        section(&mut out, "General");
+        let width = self.general.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+        for (label, value) in &self.general {
+            // Continuation lines (the kernel stack) line up under the value
+            let value = value.replace('\n', &format!("\n{:width$}  ", "", width = width));
+            out.push_str(&format!("{:<width$}  {}\n", label, value, width = width));
+        }
+
+        section(&mut out, "Environment");
+        push_lines(&mut out, &self.environment, |var| var.clone());
+
+        section(&mut out, "Open Files");
+        push_lines(&mut out, &self.open_files, |f| format!("fd {} → {}", f.fd, f.target));
+
+        section(&mut out, "Memory Maps (by file)");
+        push_lines(&mut out, &self.maps, |m| {
+            format!("{:>10}  {:>4} regions  {}", crate::util::format_bytes(m.size_bytes), m.regions, m.path)
+        });
+
+        section(&mut out, "Network");
+        if self.connections.is_empty() {
+            out.push_str("No network connections\n");
+        }
+        for conn in &self.connections {
+            out.push_str(&format!(
+                "{:<8} {}:{:<6} → {}:{:<6} {}\n",
+                conn.protocol, conn.local_addr, conn.local_port, conn.remote_addr, conn.remote_port, conn.state
+            ));
+        }
+
+        section(&mut out, "Cgroup");
+        push_lines(&mut out, &self.cgroup, |line| line.clone());
+        out
+    }
+}
+
+fn section(out: &mut String, title: &str) {
+    out.push_str(&format!("\n== {} ==\n", title));
+}
+
+fn push_lines<T>(out: &mut String, items: &Result<Vec<T>, String>, line: impl Fn(&T) -> String) {
+    match items {
+        Ok(items) => {
+            for item in items {
+                out.push_str(&line(item));
+                out.push('\n');
+            }
+        }
+        Err(e) => {
+            out.push_str(e);
+            out.push('\n');
+        }
+    }
+}
+
+/// Environment variables (NAME=value), sorted.
+pub fn read_environment(pid: i32) -> Result<Vec<String>, String> {
+    let environ = fs::read_to_string(format!("/proc/{}/environ", pid))
+        .map_err(|_| "Unable to read environment (permission denied?)".to_string())?;
+    let mut vars: Vec<String> = environ.split('\0').filter(|s| !s.is_empty()).map(String::from).collect();
+    vars.sort();
+    Ok(vars)
+}
+
+/// Parts of variable names whose values a shared report leaves out
+const SECRET_NAME_PARTS: [&str; 4] = ["TOKEN", "SECRET", "PASS", "KEY"];
+
+/// Replace the values of variables that look like credentials (API keys,
+/// tokens, passwords), so a report can be attached to a bug as is.
+fn redact_secrets(vars: Vec<String>) -> Vec<String> {
+    vars.into_iter()
+        .map(|var| match var.split_once('=') {
+            Some((name, _)) if SECRET_NAME_PARTS.iter().any(|part| name.to_uppercase().contains(part)) => {
+                format!("{}=<redacted>", name)
+            }
+            _ => var,
+        })
+        .collect()
+}
+
+/// Open file descriptors and what they point at, by fd number.
+pub fn read_open_files(pid: i32) -> Result<Vec<OpenFile>, String> {
+    let entries = fs::read_dir(format!("/proc/{}/fd", pid))
+        .map_err(|_| "Unable to read file descriptors (permission denied?)".to_string())?;
+    let mut files: Vec<OpenFile> = entries
+        .flatten()
+        .map(|entry| OpenFile {
+            fd: entry.file_name().to_string_lossy().parse().unwrap_or(0),
+            target: fs::read_link(entry.path())
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|_| "?".to_string()),
+        })
+        .collect();
+    files.sort_by_key(|f| f.fd);
+    Ok(files)
+}
+
+/// Lines of /proc/<pid>/cgroup.
+pub fn read_cgroup(pid: i32) -> Result<Vec<String>, String> {
+    let cgroup = fs::read_to_string(format!("/proc/{}/cgroup", pid))
+        .map_err(|_| "Unable to read cgroup info".to_string())?;
+    Ok(cgroup.lines().map(String::from).collect())
+}
+
+/// /proc/<pid>/maps grouped by backing file, largest first.
+pub fn read_maps_summary(pid: i32) -> Result<Vec<MappingSummary>, String> {
+    let maps = fs::read_to_string(format!("/proc/{}/maps", pid))
+        .map_err(|_| "Unable to read memory maps (permission denied?)".to_string())?;
+    let mut by_path: HashMap<String, MappingSummary> = HashMap::new();
+    for line in maps.lines() {
+        // "start-end perms offset dev inode [path]"
+        let mut fields = line.splitn(6, char::is_whitespace);
+        let Some((start, end)) = fields.next().and_then(|range| range.split_once('-')) else {
+            continue;
+        };
+        let (Ok(start), Ok(end)) = (u64::from_str_radix(start, 16), u64::from_str_radix(end, 16)) else {
+            continue;
+        };
+        let path = fields.nth(4).map(str::trim).filter(|p| !p.is_empty()).unwrap_or("[anonymous]");
+        let entry = by_path.entry(path.to_string()).or_insert_with(|| MappingSummary {
+            path: path.to_string(),
+            regions: 0,
+            size_bytes: 0,
+        });
+        entry.regions += 1;
+        entry.size_bytes += end.saturating_sub(start);
+    }
+    let mut summary: Vec<MappingSummary> = by_path.into_values().collect();
+    summary.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then_with(|| a.path.cmp(&b.path)));
+    Ok(summary)
+}
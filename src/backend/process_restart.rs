@@ -0,0 +1,95 @@
+use std::ffi::OsString;
+use std::fs;
+use std::os::unix::ffi::OsStringExt;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::backend::launcher;
+
+/// Everything needed to start a process again the way it was launched.
+pub struct LaunchSpec {
+    pub pid: i32,
+    pub exe: PathBuf,
+    /// Full argv, including argv[0]
+    pub argv: Vec<OsString>,
+    pub cwd: PathBuf,
+    pub env: Vec<(OsString, OsString)>,
+    // Start time from /proc/<pid>/stat, to tell the process from a reused PID
+    start_time: u64,
+}
+
+/// Capture the executable, command line, working directory and environment
+/// of `pid`. Fails if any of them can't be read (usually another user's process).
+pub fn capture(pid: i32) -> Result<LaunchSpec, String> {
+    let exe = fs::read_link(format!("/proc/{}/exe", pid))
+        .map_err(|e| format!("Cannot read the executable path: {}", e))?;
+    // A binary replaced by an update shows up as "<path> (deleted)"
+    let exe = match exe.to_str().and_then(|s| s.strip_suffix(" (deleted)")) {
+        Some(path) => PathBuf::from(path),
+        None => exe,
+    };
+    if !exe.exists() {
+        return Err(format!("The executable {} no longer exists", exe.display()));
+    }
+
+    let cmdline = fs::read(format!("/proc/{}/cmdline", pid))
+        .map_err(|e| format!("Cannot read the command line: {}", e))?;
+    let argv: Vec<OsString> = split_nul(&cmdline).map(|arg| OsString::from_vec(arg.to_vec())).collect();
+    if argv.is_empty() {
+        return Err("The process has no command line (kernel thread or zombie)".to_string());
+    }
+
+    let cwd = fs::read_link(format!("/proc/{}/cwd", pid))
+        .map_err(|e| format!("Cannot read the working directory: {}", e))?;
+
+    let environ = fs::read(format!("/proc/{}/environ", pid))
+        .map_err(|e| format!("Cannot read the environment: {}", e))?;
+    let env = split_nul(&environ)
+        .filter_map(|var| {
+            let eq = var.iter().position(|&b| b == b'=')?;
+            Some((
+                OsString::from_vec(var[..eq].to_vec()),
+                OsString::from_vec(var[eq + 1..].to_vec()),
+            ))
+        })
+        .collect();
+
+    let (_, start_time) = read_stat(pid).ok_or("Cannot read the process start time")?;
+
+    Ok(LaunchSpec { pid, exe, argv, cwd, env, start_time })
+}
+
+/// Whether the captured process is still alive (and not a new one with its PID).
+/// A zombie waiting to be reaped counts as exited.
+pub fn is_running(spec: &LaunchSpec) -> bool {
+    matches!(read_stat(spec.pid), Some((state, start)) if state != 'Z' && start == spec.start_time)
+}
+
+/// Start the captured command again in its own session and return the new PID.
+pub fn spawn(spec: &LaunchSpec) -> Result<u32, String> {
+    use std::os::unix::process::CommandExt;
+
+    let mut command = Command::new(&spec.exe);
+    command
+        .arg0(&spec.argv[0])
+        .args(&spec.argv[1..])
+        .current_dir(&spec.cwd)
+        .env_clear()
+        .envs(spec.env.iter().map(|(k, v)| (k, v)));
+
+    launcher::spawn_detached(&mut command, &spec.exe.display().to_string())
+}
+
+fn split_nul(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    data.split(|&b| b == 0).filter(|part| !part.is_empty())
+}
+
+/// State character and start time from /proc/<pid>/stat.
+pub fn read_stat(pid: i32) -> Option<(char, u64)> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // Fields after the parenthesised comm: state is field 3, starttime field 22
+    let mut fields = stat[stat.rfind(')')? + 1..].split_whitespace();
+    let state = fields.next()?.chars().next()?;
+    let start_time = fields.nth(18)?.parse().ok()?;
+    Some((state, start_time))
+}
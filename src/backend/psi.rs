@@ -1,13 +1,16 @@
+use serde::Serialize;
 use std::fs;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PsiMetrics {
+    /// False when the kernel has no PSI support (CONFIG_PSI off or booted with psi=0)
+    pub available: bool,
     pub cpu: PsiResource,
     pub memory: PsiResource,
     pub io: PsiResource,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PsiResource {
     pub some_avg10: f64,
     pub some_avg60: f64,
@@ -25,19 +28,18 @@ impl PsiCollector {
     }
 
     pub fn collect(&self) -> PsiMetrics {
+        let memory = read_psi_resource("/proc/pressure/memory");
         PsiMetrics {
-            cpu: read_psi_resource("/proc/pressure/cpu"),
-            memory: read_psi_resource("/proc/pressure/memory"),
-            io: read_psi_resource("/proc/pressure/io"),
+            available: memory.is_some(),
+            cpu: read_psi_resource("/proc/pressure/cpu").unwrap_or_default(),
+            memory: memory.unwrap_or_default(),
+            io: read_psi_resource("/proc/pressure/io").unwrap_or_default(),
         }
     }
 }
 
-fn read_psi_resource(path: &str) -> PsiResource {
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(_) => return PsiResource::default(),
-    };
+fn read_psi_resource(path: &str) -> Option<PsiResource> {
+    let content = fs::read_to_string(path).ok()?;
 
     let mut resource = PsiResource::default();
 
@@ -57,7 +59,7 @@ fn read_psi_resource(path: &str) -> PsiResource {
         }
     }
 
-    resource
+    Some(resource)
 }
 
 fn parse_psi_line(line: &str) -> Option<(f64, f64, f64)> {
@@ -1,4 +1,5 @@
 use crate::model::service_entry::ServiceEntry;
+use crate::model::timer_entry::TimerEntry;
 use std::process::Command;
 
 /// Check if systemd is the init system
@@ -11,30 +12,32 @@ pub struct ServicesCollector;
 
 impl ServicesCollector {
     pub fn collect() -> Vec<ServiceEntry> {
+        Self::try_collect().unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Vec::new()
+        })
+    }
+
+    /// Like `collect`, but tells a failed `systemctl` run apart from an
+    /// empty list.
+    pub fn try_collect() -> Result<Vec<ServiceEntry>, String> {
         // Check if systemd is available first
         if !is_systemd_available() {
             log::info!("systemd not detected, returning empty service list");
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
-        let output = match Command::new("systemctl")
+        let output = Command::new("systemctl")
             .args(["list-units", "--type=service", "--all", "--no-legend", "--no-pager"])
             .output()
-        {
-            Ok(o) => o,
-            Err(e) => {
-                log::error!("Failed to run systemctl list-units: {}", e);
-                return Vec::new();
-            }
-        };
+            .map_err(|e| format!("Failed to run systemctl list-units: {}", e))?;
 
         if !output.status.success() {
-            log::error!(
+            return Err(format!(
                 "systemctl list-units exited with {}: {}",
                 output.status,
                 String::from_utf8_lossy(&output.stderr)
-            );
-            return Vec::new();
+            ));
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
@@ -81,7 +84,7 @@ impl ServicesCollector {
             });
         }
 
-        entries
+        Ok(entries)
     }
 
     pub fn service_action(name: &str, action: &str) -> Result<(), String> {
@@ -90,21 +93,31 @@ impl ServicesCollector {
             return Err("systemd not available on this system".to_string());
         }
 
-        let valid_actions = ["start", "stop", "restart", "enable", "disable"];
+        let valid_actions = [
+            "start", "stop", "restart", "enable", "disable", "mask", "unmask", "daemon-reload",
+        ];
         if !valid_actions.contains(&action) {
             return Err(format!("Invalid action: {}", action));
         }
 
-        let service_name = if name.ends_with(".service") {
+        // daemon-reload applies to the whole manager; `name` is ignored
+        let service_name = if action == "daemon-reload" {
+            String::new()
+        } else if name.ends_with(".service") {
             name.to_string()
         } else {
             format!("{}.service", name)
         };
 
-        let output = Command::new("pkexec")
-            .args(["systemctl", action, &service_name])
+        let command_line = format!("systemctl {} {}", action, service_name).trim_end().to_string();
+        let mut command = Command::new("pkexec");
+        command.args(["systemctl", action]);
+        if !service_name.is_empty() {
+            command.arg(&service_name);
+        }
+        let output = command
             .output()
-            .map_err(|e| format!("Failed to execute pkexec systemctl {} {}: {}", action, service_name, e))?;
+            .map_err(|e| format!("Failed to execute pkexec {}: {}", command_line, e))?;
 
         if output.status.success() {
             Ok(())
@@ -118,14 +131,55 @@ impl ServicesCollector {
             }
 
             Err(format!(
-                "systemctl {} {} failed (exit {}): {}",
-                action,
-                service_name,
+                "{} failed (exit {}): {}",
+                command_line,
                 output.status,
                 stderr_str
             ))
         }
     }
+
+    /// List system and user timers (`systemctl list-timers --all`), each in
+    /// systemd's order of next elapse.
+    pub fn collect_timers() -> Vec<TimerEntry> {
+        if !is_systemd_available() {
+            return Vec::new();
+        }
+        let mut timers = list_timers(false);
+        timers.extend(list_timers(true));
+        timers
+    }
+
+    /// Fetch the last `lines` journal entries for a unit. `user` selects the
+    /// per-user journal (`journalctl --user`) instead of the system one.
+    pub fn journal(name: &str, user: bool, lines: u32) -> Result<String, String> {
+        let unit = if name.ends_with(".service") {
+            name.to_string()
+        } else {
+            format!("{}.service", name)
+        };
+
+        let lines_arg = lines.to_string();
+        let mut args = vec!["-u", unit.as_str(), "-n", lines_arg.as_str(), "--no-pager"];
+        if user {
+            args.insert(0, "--user");
+        }
+
+        let output = Command::new("journalctl")
+            .args(&args)
+            .output()
+            .map_err(|e| format!("Failed to run journalctl: {}", e))?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err(format!(
+                "journalctl exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
+        }
+    }
 }
 
 /// Look up the UnitFileState for a given unit via systemctl show.
@@ -150,3 +204,80 @@ fn get_unit_file_state(unit: &str) -> String {
         _ => String::new(),
     }
 }
+
+fn list_timers(user: bool) -> Vec<TimerEntry> {
+    let mut args = vec!["list-timers", "--all", "--no-pager"];
+    if user {
+        args.insert(0, "--user");
+    }
+    let output = match Command::new("systemctl").args(&args).output() {
+        Ok(o) if o.status.success() => o,
+        Ok(o) => {
+            log::warn!(
+                "systemctl {} exited with {}: {}",
+                args.join(" "),
+                o.status,
+                String::from_utf8_lossy(&o.stderr).trim()
+            );
+            return Vec::new();
+        }
+        Err(e) => {
+            log::error!("Failed to run systemctl list-timers: {}", e);
+            return Vec::new();
+        }
+    };
+    parse_list_timers(&String::from_utf8_lossy(&output.stdout), user)
+}
+
+/// Parse the `systemctl list-timers` table. Times contain spaces, so fields
+/// are cut at the column offsets of the header line:
+///
+/// NEXT                        LEFT         LAST                        PASSED  UNIT            ACTIVATES
+/// Thu 2024-01-11 00:00:00 CET 5h 3min left Wed 2024-01-10 00:00:00 CET 18h ago logrotate.timer logrotate.service
+fn parse_list_timers(output: &str, user: bool) -> Vec<TimerEntry> {
+    let mut lines = output.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let header: Vec<char> = header.chars().collect();
+    let column = |name: &str| -> Option<usize> {
+        let name: Vec<char> = name.chars().collect();
+        header.windows(name.len()).position(|w| w == name.as_slice())
+    };
+    let (Some(next), Some(left), Some(last), Some(passed), Some(unit), Some(activates)) = (
+        column("NEXT"),
+        column("LEFT"),
+        column("LAST"),
+        column("PASSED"),
+        column("UNIT"),
+        column("ACTIVATES"),
+    ) else {
+        log::warn!("Unexpected systemctl list-timers header");
+        return Vec::new();
+    };
+
+    let mut timers = Vec::new();
+    // The table ends at a blank line followed by the "N timers listed." footer
+    for line in lines.take_while(|l| !l.trim().is_empty()) {
+        let chars: Vec<char> = line.chars().collect();
+        let field = |start: usize, end: usize| -> String {
+            let end = end.min(chars.len());
+            if start >= end {
+                return String::new();
+            }
+            let value: String = chars[start..end].iter().collect();
+            let value = value.trim();
+            if value == "-" { String::new() } else { value.to_string() }
+        };
+        timers.push(TimerEntry {
+            next: field(next, left),
+            left: field(left, last).trim_end_matches(" left").to_string(),
+            last: field(last, passed),
+            passed: field(passed, unit).trim_end_matches(" ago").to_string(),
+            unit: field(unit, activates),
+            activates: field(activates, chars.len()),
+            user,
+        });
+    }
+    timers
+}
@@ -96,6 +96,15 @@ fn listen_loop() -> Result<(), String> {
 }
 
 fn launch_task_manager() {
+    // Raise the running instance if there is one
+    match crate::ui::dbus_service::show_running_instance() {
+        Ok(()) => {
+            eprintln!("shortcut-daemon: raised running instance");
+            return;
+        }
+        Err(e) => eprintln!("shortcut-daemon: no running instance ({})", e),
+    }
+
     let bin = dirs::home_dir()
         .expect("no home dir")
         .join(".local/bin/task-manager-linux");
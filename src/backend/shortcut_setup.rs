@@ -1,7 +1,9 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::backend::de_restart::{self, TilingCompositor};
+
 const DESKTOP_ENTRY: &str = "\
 [Desktop Entry]
 Type=Application
@@ -83,8 +85,19 @@ pub fn install() -> Result<String, String> {
 
 /// Detect DE and register the shortcut using the appropriate method.
 fn register_shortcut(bin_path: &std::path::Path) -> Result<String, String> {
-    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default().to_uppercase();
+    match de_restart::detect_tiling_compositor() {
+        Some(TilingCompositor::Hyprland) => {
+            register_hyprland_shortcut(bin_path)?;
+            return Ok("Ctrl+Shift+Esc is configured for Hyprland.\nThe shortcut is active immediately.".into());
+        }
+        Some(TilingCompositor::Sway) => {
+            register_sway_shortcut(bin_path)?;
+            return Ok("Ctrl+Shift+Esc is configured for sway.\nThe shortcut is active immediately.".into());
+        }
+        None => {}
+    }
 
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default().to_uppercase();
     if desktop.contains("KDE") || desktop.contains("PLASMA") {
         register_kde_shortcut()?;
         Ok("Ctrl+Shift+Esc is configured for KDE.\nLog out and back in for the shortcut to take effect.".into())
@@ -225,6 +238,96 @@ fn register_mate_shortcut(bin_path: &std::path::Path) -> Result<(), String> {
     Ok(())
 }
 
+fn register_hyprland_shortcut(bin_path: &Path) -> Result<(), String> {
+    let dir = user_config_dir().join("hypr");
+    let main = dir.join("hyprland.conf");
+    if !main.exists() {
+        return Err(format!("{} not found", main.display()));
+    }
+    let snippet = dir.join("task-manager.conf");
+    let content = format!(
+        "# Written by Task Manager\nbind = CTRL SHIFT, Escape, exec, {}\n",
+        shell_quote(&bin_path.to_string_lossy())
+    );
+    // Hyprland takes the rest of the line as the path, quotes included
+    let source_line = format!("source = {}", snippet.display());
+    include_snippet(&main, &snippet, &source_line, &content)?;
+
+    // Hyprland reloads on config changes by itself unless autoreload is
+    // disabled; ask explicitly so the bind works either way
+    run_reload("hyprctl")
+}
+
+fn register_sway_shortcut(bin_path: &Path) -> Result<(), String> {
+    let dir = user_config_dir().join("sway");
+    let main = dir.join("config");
+    if !main.exists() {
+        // Without a user config sway reads /etc/sway/config; start from a
+        // copy of it so including our file doesn't drop the defaults
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        fs::copy("/etc/sway/config", &main)
+            .map_err(|e| format!("No sway config found and /etc/sway/config could not be copied: {}", e))?;
+    }
+    let snippet = dir.join("task-manager.conf");
+    // sway hands exec commands to sh and expands include paths like a shell
+    let content = format!(
+        "# Written by Task Manager\nbindsym Ctrl+Shift+Escape exec {}\n",
+        shell_quote(&bin_path.to_string_lossy())
+    );
+    let include_line = format!("include {}", shell_quote(&snippet.to_string_lossy()));
+    include_snippet(&main, &snippet, &include_line, &content)?;
+
+    run_reload("swaymsg")
+}
+
+/// Run `<program> reload`, failing unless the compositor accepted it.
+fn run_reload(program: &str) -> Result<(), String> {
+    let output = Command::new(program)
+        .arg("reload")
+        .output()
+        .map_err(|e| format!("{} not found or failed: {}", program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} reload failed: {}",
+            program,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// `s` in single quotes for sh, with embedded single quotes escaped.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Write `content` to `snippet` and append `include_line` (which names the
+/// snippet) to the compositor's main config, unless it's already there.
+fn include_snippet(main: &Path, snippet: &Path, include_line: &str, content: &str) -> Result<(), String> {
+    fs::write(snippet, content)
+        .map_err(|e| format!("Failed to write {}: {}", snippet.display(), e))?;
+
+    let existing = fs::read_to_string(main)
+        .map_err(|e| format!("Failed to read {}: {}", main.display(), e))?;
+    let snippet_str = snippet.to_string_lossy();
+    if existing.lines().any(|line| !line.trim_start().starts_with('#') && line.contains(&*snippet_str)) {
+        return Ok(());
+    }
+
+    let mut updated = existing;
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated.push_str(&format!("\n# Task Manager shortcut\n{}\n", include_line));
+    fs::write(main, updated)
+        .map_err(|e| format!("Failed to update {}: {}", main.display(), e))
+}
+
+fn user_config_dir() -> PathBuf {
+    dirs::config_dir().unwrap_or_else(|| dirs::home_dir().expect("no home dir").join(".config"))
+}
+
 fn autostart_dest() -> PathBuf {
     dirs::home_dir()
         .expect("no home dir")
@@ -3,7 +3,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use crate::model::startup_entry::{StartupEntry, StartupSource};
+use crate::model::startup_entry::{StartupEntry, StartupImpact, StartupSource};
 
 pub struct StartupCollector;
 
@@ -140,13 +140,23 @@ impl StartupCollector {
                 .to_string()
         });
 
+        // Autostart apps aren't timed, so only well-known heavy ones get a rating
+        let exec = exec.unwrap_or_default();
+        let (impact, impact_reason) = if is_heavy_app(&exec) {
+            (StartupImpact::High, "Large application (not timed)")
+        } else {
+            (StartupImpact::NotMeasured, "Autostart entries aren't timed")
+        };
+
         Some(StartupEntry {
             name,
             comment: comment.unwrap_or_default(),
-            exec: exec.unwrap_or_default(),
+            exec,
             icon: icon.unwrap_or_default(),
             enabled,
             launch_minimized: launch_minimized.unwrap_or(false),
+            impact,
+            impact_reason: impact_reason.to_string(),
             wm_class,
             file_path: path.to_string_lossy().to_string(),
             source: StartupSource::Autostart,
@@ -179,6 +189,7 @@ impl StartupCollector {
 
         // Build a map of unit name → active state from list-units
         let active_map = Self::get_user_active_states();
+        let start_times = Self::get_user_start_times();
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         for line in stdout.lines() {
@@ -206,6 +217,10 @@ impl StartupCollector {
                     .get(unit_name)
                     .cloned()
                     .unwrap_or_else(|| "inactive".to_string());
+                let (impact, impact_reason) = match start_times.get(unit_name) {
+                    Some(&secs) => (rate_start_time(secs), format!("Took {:.2} s to start this session", secs)),
+                    None => (StartupImpact::NotMeasured, "Not started this session".to_string()),
+                };
 
                 entries.push(StartupEntry {
                     name: display_name,
@@ -218,6 +233,8 @@ impl StartupCollector {
                     file_path: unit_name.to_string(),
                     source: StartupSource::SystemdUser,
                     active_state,
+                    impact,
+                    impact_reason,
                 });
             }
         }
@@ -255,6 +272,26 @@ impl StartupCollector {
         map
     }
 
+    /// Map of unit name → seconds it took to start, from `systemd-analyze --user blame`.
+    fn get_user_start_times() -> std::collections::HashMap<String, f64> {
+        let output = match Command::new("systemd-analyze").args(["--user", "blame", "--no-pager"]).output() {
+            Ok(o) if o.status.success() => o,
+            Ok(_) => return std::collections::HashMap::new(),
+            Err(e) => {
+                log::debug!("systemd-analyze not available: {}", e);
+                return std::collections::HashMap::new();
+            }
+        };
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                // "1min 2.345s foo.service"
+                let (duration, unit) = line.trim().rsplit_once(char::is_whitespace)?;
+                Some((unit.to_string(), parse_systemd_duration(duration)?))
+            })
+            .collect()
+    }
+
     fn get_systemd_description(unit_name: &str) -> String {
         let output = Command::new("systemctl")
             .args(["--user", "show", unit_name, "--property=Description", "--no-pager"])
@@ -467,6 +504,89 @@ impl StartupCollector {
         Ok(())
     }
 
+    /// Write a new autostart entry to ~/.config/autostart and return it.
+    /// The command's program must exist (absolute path or on $PATH).
+    pub fn create_autostart(
+        name: &str,
+        exec: &str,
+        comment: &str,
+        launch_minimized: bool,
+    ) -> Result<StartupEntry, String> {
+        let name = name.trim();
+        let exec = exec.trim();
+        if name.is_empty() {
+            return Err("Name is required".to_string());
+        }
+        validate_exec(exec)?;
+
+        let home = std::env::var("HOME").map_err(|e| format!("Cannot get HOME: {}", e))?;
+        let user_dir = PathBuf::from(format!("{}/.config/autostart", home));
+        fs::create_dir_all(&user_dir)
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::ReadOnlyFilesystem {
+                    "Cannot create autostart dir: filesystem is read-only (immutable distro?)".to_string()
+                } else {
+                    format!("Cannot create autostart dir: {}", e)
+                }
+            })?;
+
+        // File name from the entry name, numbered if it's taken. System entries
+        // count as taken since a user file of the same name would override them.
+        let stem: String = name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let stem = if stem.is_empty() { "autostart".to_string() } else { stem };
+        let taken = |file: &str| {
+            user_dir.join(file).exists() || Path::new("/etc/xdg/autostart").join(file).exists()
+        };
+        let mut file_name = format!("{}.desktop", stem);
+        let mut n = 2;
+        while taken(&file_name) {
+            file_name = format!("{}-{}.desktop", stem, n);
+            n += 1;
+        }
+        let path = user_dir.join(&file_name);
+
+        // Values are single-line in desktop files
+        let single_line = |v: &str| v.replace('\n', " ");
+        let mut content = format!(
+            "[Desktop Entry]\nType=Application\nName={}\nExec={}\n",
+            single_line(name),
+            single_line(exec)
+        );
+        if !comment.trim().is_empty() {
+            content.push_str(&format!("Comment={}\n", single_line(comment.trim())));
+        }
+        content.push_str("X-GNOME-Autostart-enabled=true\n");
+
+        fs::write(&path, content)
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::ReadOnlyFilesystem {
+                    format!("Cannot write {}: filesystem is read-only (immutable distro?)", path.display())
+                } else {
+                    format!("Cannot write {}: {}", path.display(), e)
+                }
+            })?;
+        log::info!("Created autostart entry '{}' ({})", name, path.display());
+
+        let mut entry = Self::parse_desktop_file(&path)
+            .ok_or_else(|| format!("Cannot read back {}", path.display()))?;
+        // The entry exists at this point, so a failed window rule isn't fatal
+        if launch_minimized {
+            match Self::toggle_launch_mode(&entry, true) {
+                Ok(()) => entry.launch_minimized = true,
+                Err(e) => log::warn!("Created '{}' but could not set launch minimized: {}", name, e),
+            }
+        }
+        Ok(entry)
+    }
+
     pub fn toggle_launch_mode(entry: &StartupEntry, minimized: bool) -> Result<(), String> {
         if entry.source != StartupSource::Autostart {
             return Ok(()); // systemd services don't have windows
@@ -583,6 +703,90 @@ impl StartupCollector {
     }
 }
 
+/// Check that an Exec line's program exists and is executable. Only the
+/// program is checked; arguments and desktop-entry field codes are left alone.
+fn validate_exec(exec: &str) -> Result<(), String> {
+    let program = if let Some(rest) = exec.strip_prefix('"') {
+        rest.split('"').next().unwrap_or_default()
+    } else {
+        exec.split_whitespace().next().unwrap_or_default()
+    };
+    if program.is_empty() {
+        return Err("Command is required".to_string());
+    }
+
+    let is_executable = |path: &Path| {
+        use std::os::unix::fs::PermissionsExt;
+        path.is_file()
+            && fs::metadata(path).map(|m| m.permissions().mode() & 0o111 != 0).unwrap_or(false)
+    };
+    let found = if program.contains('/') {
+        is_executable(Path::new(program))
+    } else {
+        std::env::var("PATH")
+            .unwrap_or_default()
+            .split(':')
+            .any(|dir| is_executable(&Path::new(dir).join(program)))
+    };
+    if found {
+        Ok(())
+    } else {
+        Err(format!("Command not found or not executable: {}", program))
+    }
+}
+
+/// Seconds in a systemd time span such as "1min 2.345s", "345ms" or "1h 2min".
+fn parse_systemd_duration(text: &str) -> Option<f64> {
+    let mut total = 0.0;
+    for part in text.split_whitespace() {
+        let split = part.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+        let (number, unit) = part.split_at(split);
+        let scale = match unit {
+            "h" => 3600.0,
+            "min" => 60.0,
+            "s" => 1.0,
+            "ms" => 1e-3,
+            "us" | "µs" => 1e-6,
+            _ => return None,
+        };
+        total += number.parse::<f64>().ok()? * scale;
+    }
+    Some(total)
+}
+
+fn rate_start_time(secs: f64) -> StartupImpact {
+    if secs >= 3.0 {
+        StartupImpact::High
+    } else if secs >= 1.0 {
+        StartupImpact::Medium
+    } else {
+        StartupImpact::Low
+    }
+}
+
+/// Apps known to take a lot of CPU, memory or disk while starting: browsers,
+/// Electron chat clients, sync clients and game launchers.
+const HEAVY_APPS: &[&str] = &[
+    "brave", "chrome", "chromium", "code", "discord", "dropbox", "element-desktop", "evolution",
+    "firefox", "google-chrome", "heroic", "insync", "jetbrains-toolbox", "lutris", "megasync",
+    "microsoft-edge", "nextcloud", "obs", "onedrive", "owncloud", "signal-desktop", "skype",
+    "skypeforlinux", "slack", "spotify", "steam", "teams", "teams-for-linux", "telegram-desktop",
+    "thunderbird", "virtualbox", "whatsapp", "zoom",
+];
+
+/// Whether the program of an Exec= line (or the Flatpak app it runs) is in
+/// HEAVY_APPS.
+fn is_heavy_app(exec: &str) -> bool {
+    let program = exec
+        .split_whitespace()
+        .find(|arg| !matches!(*arg, "env" | "flatpak" | "run") && !arg.starts_with('-') && !arg.contains('='))
+        .unwrap_or_default();
+    let name = program.rsplit('/').next().unwrap_or(program).to_lowercase();
+    // Flatpak app IDs: com.discordapp.Discord → discord
+    let flatpak_name = name.rsplit('.').next().unwrap_or(&name);
+    HEAVY_APPS.contains(&name.as_str()) || HEAVY_APPS.contains(&flatpak_name)
+}
+
 /// Manages KWin window rules in ~/.config/kwinrulesrc for launch-minimized behavior.
 mod kwin_rules {
     use std::fs;
@@ -0,0 +1,48 @@
+use std::fs;
+
+/// Facts about the running system that don't change until reboot.
+pub struct SystemInfo {
+    pub hostname: String,
+    /// Kernel release, e.g. "6.8.0-45-generic"
+    pub kernel: String,
+    /// Full /proc/version line (compiler, build date)
+    pub kernel_version: String,
+    /// PRETTY_NAME from os-release
+    pub distro: String,
+    /// Unix seconds
+    pub boot_time: u64,
+}
+
+impl SystemInfo {
+    pub fn read() -> Self {
+        let kernel_version = fs::read_to_string("/proc/version")
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default();
+        // "Linux version <release> (builder@host) ..."
+        let kernel = kernel_version.split_whitespace().nth(2).unwrap_or("Unknown").to_string();
+
+        Self {
+            hostname: fs::read_to_string("/proc/sys/kernel/hostname")
+                .map(|s| s.trim().to_string())
+                .unwrap_or_else(|_| "Unknown".to_string()),
+            kernel,
+            kernel_version,
+            distro: read_distro().unwrap_or_else(|| "Unknown".to_string()),
+            boot_time: super::process::get_boot_time(),
+        }
+    }
+}
+
+/// Distribution name from /etc/os-release (or /usr/lib/os-release).
+fn read_distro() -> Option<String> {
+    let content = fs::read_to_string("/etc/os-release")
+        .or_else(|_| fs::read_to_string("/usr/lib/os-release"))
+        .ok()?;
+    let value = |key: &str| {
+        content.lines().find_map(|line| {
+            let value = line.strip_prefix(key)?.strip_prefix('=')?;
+            Some(value.trim_matches('"').to_string())
+        })
+    };
+    value("PRETTY_NAME").or_else(|| value("NAME"))
+}
@@ -0,0 +1,132 @@
+// Editing systemd unit files, like `systemctl edit`: either a full copy of
+// the unit in the admin config directory (which takes precedence over the
+// vendor file in /usr) or a drop-in override next to it.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditMode {
+    /// Replace the whole unit (`systemctl edit --full`)
+    Full,
+    /// Add settings in `<unit>.d/override.conf` (`systemctl edit`)
+    Override,
+}
+
+/// A unit to edit; `user` units live in the per-user manager.
+#[derive(Debug, Clone)]
+pub struct UnitFile {
+    pub unit: String,
+    pub user: bool,
+    /// File the unit was loaded from
+    pub fragment_path: PathBuf,
+}
+
+impl UnitFile {
+    /// Look up where `unit` was loaded from. A name without a type suffix is
+    /// taken to be a service.
+    pub fn find(unit: &str, user: bool) -> Result<Self, String> {
+        let unit = if unit.contains('.') { unit.to_string() } else { format!("{}.service", unit) };
+        let mut command = Command::new("systemctl");
+        if user {
+            command.arg("--user");
+        }
+        let output = command
+            .args(["show", "-p", "FragmentPath", "--value", &unit])
+            .output()
+            .map_err(|e| format!("Failed to run systemctl: {}", e))?;
+        let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !output.status.success() || path.is_empty() {
+            return Err(format!("{} has no unit file", unit));
+        }
+        Ok(Self { unit, user, fragment_path: PathBuf::from(path) })
+    }
+
+    /// Where an edit in `mode` is saved.
+    pub fn target_path(&self, mode: EditMode) -> PathBuf {
+        let dir = if self.user {
+            dirs::config_dir().unwrap_or_else(|| PathBuf::from(".config")).join("systemd/user")
+        } else {
+            PathBuf::from("/etc/systemd/system")
+        };
+        match mode {
+            EditMode::Full => dir.join(&self.unit),
+            EditMode::Override => dir.join(format!("{}.d", self.unit)).join("override.conf"),
+        }
+    }
+
+    /// Whether saving in Full mode overwrites the loaded file itself rather
+    /// than shadowing a vendor copy.
+    pub fn is_local(&self) -> bool {
+        self.fragment_path == self.target_path(EditMode::Full)
+    }
+
+    /// Text to start editing from: the existing edit if there is one, else
+    /// the loaded unit (Full) or an empty override.
+    pub fn load(&self, mode: EditMode) -> Result<String, String> {
+        let target = self.target_path(mode);
+        if target.exists() {
+            return fs::read_to_string(&target).map_err(|e| format!("Failed to read {}: {}", target.display(), e));
+        }
+        match mode {
+            EditMode::Full => fs::read_to_string(&self.fragment_path)
+                .map_err(|e| format!("Failed to read {}: {}", self.fragment_path.display(), e)),
+            EditMode::Override => Ok(format!(
+                "# Drop-in override for {}\n# Settings here are applied on top of {}.\n# To replace a list such as ExecStart=, clear it first with an empty ExecStart=.\n\n",
+                self.unit,
+                self.fragment_path.display()
+            )),
+        }
+    }
+
+    /// Write `contents` for `mode` and reload the manager. System units are
+    /// written through pkexec.
+    pub fn save(&self, mode: EditMode, contents: &str) -> Result<PathBuf, String> {
+        let target = self.target_path(mode);
+        if self.user {
+            if let Some(dir) = target.parent() {
+                fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+            }
+            fs::write(&target, contents).map_err(|e| format!("Failed to write {}: {}", target.display(), e))?;
+            let status = Command::new("systemctl")
+                .args(["--user", "daemon-reload"])
+                .status()
+                .map_err(|e| format!("Failed to run systemctl: {}", e))?;
+            if !status.success() {
+                return Err(format!("systemctl --user daemon-reload failed ({})", status));
+            }
+            return Ok(target);
+        }
+
+        // One pkexec prompt for creating the directory, writing and reloading
+        let mut child = Command::new("pkexec")
+            .args([
+                "/bin/sh",
+                "-c",
+                "mkdir -p -- \"$(dirname -- \"$1\")\" && cat > \"$1\" && systemctl daemon-reload",
+                "sh",
+            ])
+            .arg(&target)
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to execute pkexec: {}", e))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(contents.as_bytes())
+                .map_err(|e| format!("Failed to write {}: {}", target.display(), e))?;
+        }
+        let output = child.wait_with_output().map_err(|e| format!("Failed to wait for pkexec: {}", e))?;
+        if output.status.success() {
+            Ok(target)
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("Read-only file system") {
+                return Err("Cannot modify: filesystem is read-only (immutable distro?)".to_string());
+            }
+            Err(format!("Saving {} failed ({}): {}", target.display(), output.status, stderr.trim()))
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fs;
+use crate::model::ProcessInfo;
 use x11rb::connection::Connection;
 use x11rb::protocol::xproto::*;
 
@@ -8,6 +9,10 @@ enum ResolverBackend {
         conn: x11rb::rust_connection::RustConnection,
         root: u32,
     },
+    /// wlroots compositors (sway, Hyprland, ...) via zwlr_foreign_toplevel_management_v1
+    WlrToplevel(wlr::ToplevelList),
+    /// Plasma Wayland: window list queried from KWin over D-Bus
+    KWin(kwin::KWinWindows),
     Wayland,
     None,
 }
@@ -16,6 +21,27 @@ pub struct WindowResolver {
     backend: ResolverBackend,
 }
 
+/// Window titles from one collection. X11 (and newer KWin) report the owning
+/// PID; Wayland toplevels only carry an app id, matched to processes later.
+#[derive(Clone, Default)]
+pub struct WindowTitles {
+    pub by_pid: HashMap<u32, String>,
+    pub by_app_id: HashMap<String, String>,
+}
+
+impl WindowTitles {
+    fn from_pids(by_pid: HashMap<u32, String>) -> Self {
+        Self { by_pid, by_app_id: HashMap::new() }
+    }
+
+    /// Titles keyed by PID, with app ids matched against `processes`.
+    pub fn by_process(&self, processes: &[ProcessInfo]) -> HashMap<u32, String> {
+        let mut map = map_app_ids_to_pids(&self.by_app_id, processes);
+        map.extend(self.by_pid.iter().map(|(pid, title)| (*pid, title.clone())));
+        map
+    }
+}
+
 impl WindowResolver {
     pub fn new() -> Self {
         // Detect session type
@@ -24,6 +50,29 @@ impl WindowResolver {
             .unwrap_or(false)
             || std::env::var("WAYLAND_DISPLAY").is_ok();
 
+        // The compositor's toplevel list covers native Wayland and XWayland
+        // windows alike, so prefer it over XWayland where it's offered
+        if is_wayland {
+            match wlr::ToplevelList::connect() {
+                Ok(list) => {
+                    log::info!("Using wlr-foreign-toplevel for window titles");
+                    return Self {
+                        backend: ResolverBackend::WlrToplevel(list),
+                    };
+                }
+                Err(e) => log::debug!("wlr-foreign-toplevel unavailable: {}", e),
+            }
+            match kwin::KWinWindows::connect() {
+                Ok(kwin) => {
+                    log::info!("Using KWin D-Bus window list for window titles");
+                    return Self {
+                        backend: ResolverBackend::KWin(kwin),
+                    };
+                }
+                Err(e) => log::debug!("KWin window list unavailable: {}", e),
+            }
+        }
+
         // Try X11 first (even on Wayland, XWayland may be available)
         match x11rb::connect(None) {
             Ok((conn, screen_num)) => {
@@ -49,15 +98,17 @@ impl WindowResolver {
         }
     }
 
-    pub fn collect(&self) -> HashMap<u32, String> {
-        match &self.backend {
-            ResolverBackend::X11 { conn, root } => self.collect_x11(conn, *root),
-            ResolverBackend::Wayland => self.collect_wayland(),
-            ResolverBackend::None => HashMap::new(),
+    pub fn collect(&mut self) -> WindowTitles {
+        match &mut self.backend {
+            ResolverBackend::X11 { conn, root } => WindowTitles::from_pids(Self::collect_x11(conn, *root)),
+            ResolverBackend::WlrToplevel(list) => list.collect(),
+            ResolverBackend::KWin(kwin) => kwin.collect(),
+            ResolverBackend::Wayland => WindowTitles::from_pids(Self::collect_wayland()),
+            ResolverBackend::None => WindowTitles::default(),
         }
     }
 
-    fn collect_x11(&self, conn: &x11rb::rust_connection::RustConnection, root: u32) -> HashMap<u32, String> {
+    fn collect_x11(conn: &x11rb::rust_connection::RustConnection, root: u32) -> HashMap<u32, String> {
         let mut map = HashMap::new();
 
         // Get _NET_CLIENT_LIST
@@ -96,7 +147,7 @@ impl WindowResolver {
         map
     }
 
-    fn collect_wayland(&self) -> HashMap<u32, String> {
+    fn collect_wayland() -> HashMap<u32, String> {
         let mut map = HashMap::new();
 
         // Scan /proc for GUI processes
@@ -224,6 +275,51 @@ fn is_wayland_gui_process(pid: u32) -> bool {
     false
 }
 
+/// Map Wayland app ids to the PID of the process that owns the window.
+///
+/// Wayland doesn't tell clients which process a toplevel belongs to, so each
+/// app id (e.g. "firefox", "org.gnome.Nautilus") is matched against process
+/// comm names and exe basenames. When several processes match (browser
+/// helpers, multi-process apps), the one whose parent doesn't match is used.
+fn map_app_ids_to_pids(titles_by_app_id: &HashMap<String, String>, processes: &[ProcessInfo]) -> HashMap<u32, String> {
+    let mut map = HashMap::new();
+    if titles_by_app_id.is_empty() {
+        return map;
+    }
+
+    // Lowercased app id, plus its last reverse-DNS component, -> app id
+    let mut keys: HashMap<String, &str> = HashMap::new();
+    for app_id in titles_by_app_id.keys() {
+        let lower = app_id.to_lowercase();
+        if let Some(last) = lower.rsplit('.').next() {
+            keys.entry(last.to_string()).or_insert(app_id);
+        }
+        keys.insert(lower, app_id);
+    }
+
+    // PID -> (PPID, matched app id)
+    let mut matches: HashMap<u32, (u32, &str)> = HashMap::new();
+    for proc in processes {
+        let comm = proc.name.to_lowercase();
+        let exe = proc.exe_path.rsplit('/').next().unwrap_or_default().to_lowercase();
+        let app_id = keys.get(&comm).or_else(|| keys.get(&exe));
+        if let Some(app_id) = app_id {
+            matches.insert(proc.pid as u32, (proc.ppid as u32, app_id));
+        }
+    }
+
+    for (pid, (ppid, app_id)) in &matches {
+        let parent_matches = matches.get(ppid).is_some_and(|(_, parent_app)| parent_app == app_id);
+        if !parent_matches {
+            if let Some(title) = titles_by_app_id.get(*app_id) {
+                map.insert(*pid, title.clone());
+            }
+        }
+    }
+
+    map
+}
+
 fn read_proc_comm(pid: u32) -> Option<String> {
     let comm_path = format!("/proc/{}/comm", pid);
     fs::read_to_string(&comm_path)
@@ -237,3 +333,309 @@ fn read_proc_cmdline(pid: u32) -> Option<String> {
         .ok()
         .map(|s| s.replace('\0', " ").trim().to_string())
 }
+
+mod wlr {
+    use std::collections::HashMap;
+    use wayland_client::backend::ObjectId;
+    use wayland_client::globals::{registry_queue_init, GlobalListContents};
+    use wayland_client::protocol::wl_registry;
+    use wayland_client::{event_created_child, Connection, Dispatch, EventQueue, Proxy, QueueHandle};
+    use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_handle_v1::{
+        self, ZwlrForeignToplevelHandleV1,
+    };
+    use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_manager_v1::{
+        self, ZwlrForeignToplevelManagerV1,
+    };
+
+    /// Live list of the compositor's toplevels, kept current by dispatching
+    /// the manager's events on every collect.
+    pub struct ToplevelList {
+        queue: EventQueue<State>,
+        state: State,
+        _manager: ZwlrForeignToplevelManagerV1,
+    }
+
+    #[derive(Default)]
+    struct State {
+        toplevels: HashMap<ObjectId, Toplevel>,
+    }
+
+    #[derive(Default)]
+    struct Toplevel {
+        title: String,
+        app_id: String,
+        activated: bool,
+    }
+
+    impl ToplevelList {
+        pub fn connect() -> Result<Self, String> {
+            let conn = Connection::connect_to_env().map_err(|e| e.to_string())?;
+            let (globals, mut queue) = registry_queue_init::<State>(&conn).map_err(|e| e.to_string())?;
+            let qh = queue.handle();
+            let manager: ZwlrForeignToplevelManagerV1 = globals
+                .bind(&qh, 1..=3, ())
+                .map_err(|e| format!("compositor doesn't offer the protocol ({})", e))?;
+
+            // Receive the initial toplevels and their properties
+            let mut state = State::default();
+            queue.roundtrip(&mut state).map_err(|e| e.to_string())?;
+
+            Ok(Self {
+                queue,
+                state,
+                _manager: manager,
+            })
+        }
+
+        /// Window titles keyed by app id. Of several windows with the same
+        /// app id, the focused one wins.
+        pub fn collect(&mut self) -> super::WindowTitles {
+            if let Err(e) = self.queue.roundtrip(&mut self.state) {
+                log::warn!("wlr-foreign-toplevel dispatch failed: {}", e);
+                return super::WindowTitles::default();
+            }
+
+            let mut titles_by_app_id: HashMap<String, String> = HashMap::new();
+            for toplevel in self.state.toplevels.values() {
+                if toplevel.app_id.is_empty() || toplevel.title.is_empty() {
+                    continue;
+                }
+                if toplevel.activated || !titles_by_app_id.contains_key(&toplevel.app_id) {
+                    titles_by_app_id.insert(toplevel.app_id.clone(), super::clean_window_title(&toplevel.title));
+                }
+            }
+
+            super::WindowTitles {
+                by_pid: HashMap::new(),
+                by_app_id: titles_by_app_id,
+            }
+        }
+    }
+
+    impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for State {
+        fn event(
+            _state: &mut Self,
+            _registry: &wl_registry::WlRegistry,
+            _event: wl_registry::Event,
+            _data: &GlobalListContents,
+            _conn: &Connection,
+            _qh: &QueueHandle<Self>,
+        ) {
+        }
+    }
+
+    impl Dispatch<ZwlrForeignToplevelManagerV1, ()> for State {
+        fn event(
+            state: &mut Self,
+            _manager: &ZwlrForeignToplevelManagerV1,
+            event: zwlr_foreign_toplevel_manager_v1::Event,
+            _data: &(),
+            _conn: &Connection,
+            _qh: &QueueHandle<Self>,
+        ) {
+            if let zwlr_foreign_toplevel_manager_v1::Event::Toplevel { toplevel } = event {
+                state.toplevels.insert(toplevel.id(), Toplevel::default());
+            }
+        }
+
+        event_created_child!(State, ZwlrForeignToplevelManagerV1, [
+            zwlr_foreign_toplevel_manager_v1::EVT_TOPLEVEL_OPCODE => (ZwlrForeignToplevelHandleV1, ()),
+        ]);
+    }
+
+    impl Dispatch<ZwlrForeignToplevelHandleV1, ()> for State {
+        fn event(
+            state: &mut Self,
+            handle: &ZwlrForeignToplevelHandleV1,
+            event: zwlr_foreign_toplevel_handle_v1::Event,
+            _data: &(),
+            _conn: &Connection,
+            _qh: &QueueHandle<Self>,
+        ) {
+            use zwlr_foreign_toplevel_handle_v1::Event;
+
+            if let Event::Closed = event {
+                state.toplevels.remove(&handle.id());
+                handle.destroy();
+                return;
+            }
+            let Some(toplevel) = state.toplevels.get_mut(&handle.id()) else {
+                return;
+            };
+            match event {
+                Event::Title { title } => toplevel.title = title,
+                Event::AppId { app_id } => toplevel.app_id = app_id,
+                Event::State { state } => {
+                    // Array of native-endian u32 state values
+                    toplevel.activated = state
+                        .chunks_exact(4)
+                        .map(|c| u32::from_ne_bytes([c[0], c[1], c[2], c[3]]))
+                        .any(|s| s == zwlr_foreign_toplevel_handle_v1::State::Activated as u32);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+mod kwin {
+    use gtk4::gio;
+    use gtk4::glib;
+    use gtk4::glib::prelude::*;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    const KWIN_SERVICE: &str = "org.kde.KWin";
+    const CALL_TIMEOUT_MS: i32 = 1000;
+    /// How often the worker re-reads the window list.
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+    /// Window list kept current by a worker thread, so a slow or hung KWin
+    /// never holds up the collector.
+    pub struct KWinWindows {
+        latest: Arc<Mutex<super::WindowTitles>>,
+    }
+
+    /// KWin has no plain "list windows" call, so windows are enumerated through
+    /// its krunner plugin (the "window" keyword matches every window) and then
+    /// looked up one by one via getWindowInfo.
+    struct KWinClient {
+        conn: gio::DBusConnection,
+    }
+
+    impl KWinWindows {
+        pub fn connect() -> Result<Self, String> {
+            let conn = gio::bus_get_sync(gio::BusType::Session, gio::Cancellable::NONE)
+                .map_err(|e| e.to_string())?;
+            let client = KWinClient { conn };
+            // Probe once so non-KDE sessions fall through to the next backend
+            client.window_ids()?;
+
+            let latest = Arc::new(Mutex::new(super::WindowTitles::default()));
+            let target = Arc::downgrade(&latest);
+            thread::Builder::new()
+                .name("kwin-windows".into())
+                .spawn(move || loop {
+                    let titles = client.query();
+                    // Stop once the resolver is gone
+                    let Some(latest) = target.upgrade() else {
+                        break;
+                    };
+                    if let Ok(mut latest) = latest.lock() {
+                        *latest = titles;
+                    }
+                    drop(latest);
+                    thread::sleep(REFRESH_INTERVAL);
+                })
+                .map_err(|e| e.to_string())?;
+            Ok(Self { latest })
+        }
+
+        /// The most recent window list the worker has read.
+        pub fn collect(&mut self) -> super::WindowTitles {
+            self.latest.lock().map(|titles| titles.clone()).unwrap_or_default()
+        }
+    }
+
+    impl KWinClient {
+        fn query(&self) -> super::WindowTitles {
+            let ids = match self.window_ids() {
+                Ok(ids) => ids,
+                Err(e) => {
+                    log::warn!("KWin window list failed: {}", e);
+                    return super::WindowTitles::default();
+                }
+            };
+
+            let mut map = HashMap::new();
+            let mut titles_by_app_id: HashMap<String, String> = HashMap::new();
+            for id in ids {
+                let Some(info) = self.window_info(&id) else {
+                    continue;
+                };
+                let text = |key: &str| info.get(key).and_then(|v| v.get::<String>()).unwrap_or_default();
+                let caption = text("caption");
+                if caption.is_empty() {
+                    continue;
+                }
+                let title = super::clean_window_title(&caption);
+
+                // Newer KWin versions report the owning PID directly
+                let pid = info.get("pid").and_then(|v| {
+                    v.get::<i32>().map(|p| p as u32).or_else(|| v.get::<u32>())
+                });
+                match pid {
+                    Some(pid) if pid > 0 => {
+                        map.insert(pid, title);
+                    }
+                    _ => {
+                        let desktop_file = text("desktopFile");
+                        let app_id = if desktop_file.is_empty() { text("resourceClass") } else { desktop_file };
+                        if !app_id.is_empty() {
+                            titles_by_app_id.entry(app_id).or_insert(title);
+                        }
+                    }
+                }
+            }
+
+            super::WindowTitles {
+                by_pid: map,
+                by_app_id: titles_by_app_id,
+            }
+        }
+
+        /// UUIDs of all managed windows, from org.kde.krunner1.Match("window").
+        /// Match ids have the form `<action>_<window uuid>` (see KWin's
+        /// windowsrunner.cpp); the action prefix is stripped and each window
+        /// listed once.
+        fn window_ids(&self) -> Result<Vec<String>, String> {
+            let reply = self.call(
+                "/WindowsRunner",
+                "org.kde.krunner1",
+                "Match",
+                ("window",).to_variant(),
+                "(a(sssida{sv}))",
+            )?;
+            let matches = reply.child_value(0);
+            let mut seen = HashSet::new();
+            Ok(matches
+                .iter()
+                .filter_map(|m| m.child_value(0).get::<String>())
+                .filter_map(|id| id.split_once('_').map(|(_, uuid)| uuid.to_string()))
+                .filter(|uuid| !uuid.is_empty() && seen.insert(uuid.clone()))
+                .collect())
+        }
+
+        fn window_info(&self, id: &str) -> Option<HashMap<String, glib::Variant>> {
+            let reply = self
+                .call("/KWin", "org.kde.KWin", "getWindowInfo", (id,).to_variant(), "(a{sv})")
+                .ok()?;
+            reply.child_value(0).get()
+        }
+
+        fn call(
+            &self,
+            path: &str,
+            interface: &str,
+            method: &str,
+            args: glib::Variant,
+            reply_type: &str,
+        ) -> Result<glib::Variant, String> {
+            self.conn
+                .call_sync(
+                    Some(KWIN_SERVICE),
+                    path,
+                    interface,
+                    method,
+                    Some(&args),
+                    glib::VariantTy::new(reply_type).ok(),
+                    gio::DBusCallFlags::NO_AUTO_START,
+                    CALL_TIMEOUT_MS,
+                    gio::Cancellable::NONE,
+                )
+                .map_err(|e| e.to_string())
+        }
+    }
+}
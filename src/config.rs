@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -7,10 +8,150 @@ pub struct Config {
     pub window_width: i32,
     pub window_height: i32,
     pub refresh_interval_ms: u64,
+    /// Refresh behavior while the window isn't focused (minimized, hidden to the tray, ...)
+    #[serde(default)]
+    pub background_refresh: BackgroundRefresh,
     pub visible_columns: Vec<String>,
+    /// Process list sort (column id); other tables use `table_sorts`
     pub sort_column: String,
     pub sort_ascending: bool,
     pub show_all_processes: bool,
+    #[serde(default = "default_true")]
+    pub show_tray_icon: bool,
+    /// Start hidden in the tray instead of showing the window
+    #[serde(default)]
+    pub start_minimized: bool,
+    /// Exe paths (or names, for processes without one) starred in the process list
+    #[serde(default)]
+    pub watched_processes: Vec<String>,
+    /// Nice values re-applied to every new process of an executable, by exe path
+    #[serde(default)]
+    pub priority_rules: HashMap<String, i32>,
+    /// How the process list is grouped
+    #[serde(default)]
+    pub process_view: ProcessView,
+    /// How kernel threads appear in the Apps and Units views
+    #[serde(default)]
+    pub kernel_threads: KernelThreads,
+    /// Show process CPU usage as a share of all cores (0–100%) instead of one core
+    #[serde(default)]
+    pub cpu_of_total: bool,
+    /// Smaller rows and text in the process list
+    #[serde(default)]
+    pub compact_rows: bool,
+    /// Read every process's smaps_rollup for the PSS column (costly)
+    #[serde(default)]
+    pub measure_pss: bool,
+    /// When ending or signalling a process asks for confirmation first
+    #[serde(default)]
+    pub confirm_kill: ConfirmKill,
+    /// Follow End Task's SIGTERM with SIGKILL if the process is still running
+    #[serde(default)]
+    pub escalate_end_task: bool,
+    /// Sidebar page selected on startup
+    #[serde(default = "default_tab")]
+    pub default_tab: String,
+    /// Performance sub-panel last viewed, restored on startup
+    #[serde(default = "default_performance_panel")]
+    pub performance_panel: String,
+    #[serde(default)]
+    pub temperature_unit: TemperatureUnit,
+    /// Time window (seconds) graphs start with
+    #[serde(default = "default_graph_window_secs")]
+    pub graph_window_secs: usize,
+    /// Draw graph series as smooth curves rather than straight segments
+    #[serde(default)]
+    pub smooth_graphs: bool,
+    #[serde(default)]
+    pub graph_theme: GraphTheme,
+    /// Desktop notifications for processes crossing the thresholds below
+    #[serde(default)]
+    pub alerts_enabled: bool,
+    /// CPU percent (as shown in the process list) that must be sustained; 0 disables
+    #[serde(default = "default_alert_cpu_percent")]
+    pub alert_cpu_percent: f64,
+    #[serde(default = "default_alert_cpu_secs")]
+    pub alert_cpu_secs: u64,
+    /// Resident memory in MiB; 0 disables
+    #[serde(default = "default_alert_memory_mb")]
+    pub alert_memory_mb: u64,
+    /// Desktop notification when a systemd service enters the failed state
+    #[serde(default)]
+    pub service_alerts_enabled: bool,
+    /// Sort of the services, timers, users and startup tables, by table name
+    #[serde(default)]
+    pub table_sorts: HashMap<String, TableSort>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackgroundRefresh {
+    /// Same speed as in the foreground
+    #[default]
+    Normal,
+    /// At most one snapshot every few seconds
+    Slow,
+    /// No collection at all (alerts and the tray icon stop updating too)
+    Paused,
+}
+
+/// Colors of the graph background and grid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphTheme {
+    /// Follow the light/dark style of the desktop
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessView {
+    /// Heuristic application groups
+    #[default]
+    Apps,
+    /// One group per systemd unit
+    Units,
+    /// PID/PPID hierarchy
+    Tree,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KernelThreads {
+    /// One "Kernel" group
+    #[default]
+    Grouped,
+    /// A row per thread
+    Separate,
+    Hidden,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmKill {
+    Never,
+    #[default]
+    CriticalOnly,
+    Always,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableSort {
+    /// Column id, or title for columns without one
+    pub column: String,
+    pub ascending: bool,
 }
 
 impl Default for Config {
@@ -19,6 +160,7 @@ impl Default for Config {
             window_width: 1200,
             window_height: 800,
             refresh_interval_ms: 1000,
+            background_refresh: BackgroundRefresh::Normal,
             visible_columns: vec![
                 "name".into(),
                 "pid".into(),
@@ -32,6 +174,29 @@ impl Default for Config {
             sort_column: "cpu".into(),
             sort_ascending: false,
             show_all_processes: true,
+            show_tray_icon: true,
+            start_minimized: false,
+            watched_processes: Vec::new(),
+            priority_rules: HashMap::new(),
+            process_view: ProcessView::Apps,
+            kernel_threads: KernelThreads::Grouped,
+            cpu_of_total: false,
+            compact_rows: false,
+            measure_pss: false,
+            confirm_kill: ConfirmKill::CriticalOnly,
+            escalate_end_task: false,
+            default_tab: default_tab(),
+            performance_panel: default_performance_panel(),
+            temperature_unit: TemperatureUnit::Celsius,
+            graph_window_secs: default_graph_window_secs(),
+            smooth_graphs: false,
+            graph_theme: GraphTheme::System,
+            alerts_enabled: false,
+            alert_cpu_percent: default_alert_cpu_percent(),
+            alert_cpu_secs: default_alert_cpu_secs(),
+            alert_memory_mb: default_alert_memory_mb(),
+            service_alerts_enabled: false,
+            table_sorts: HashMap::new(),
         }
     }
 }
@@ -57,6 +222,34 @@ impl Config {
     }
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn default_tab() -> String {
+    "processes".into()
+}
+
+fn default_performance_panel() -> String {
+    "overview".into()
+}
+
+fn default_graph_window_secs() -> usize {
+    60
+}
+
+fn default_alert_cpu_percent() -> f64 {
+    80.0
+}
+
+fn default_alert_cpu_secs() -> u64 {
+    10
+}
+
+fn default_alert_memory_mb() -> u64 {
+    4096
+}
+
 fn config_path() -> PathBuf {
     dirs::config_dir()
         .unwrap_or_else(|| PathBuf::from("~/.config"))
@@ -19,6 +19,28 @@ fn main() {
         backend::shortcut_daemon::run_daemon();
     }
 
+    // One-shot snapshot to stdout for scripts and headless sessions
+    if let Some(mode) = std::env::args().find(|a| matches!(a.as_str(), "--dump" | "--dump-json" | "--dump-csv")) {
+        std::process::exit(dump_snapshot(&mode));
+    }
+
     let app = app::TaskManagerApp::new();
     std::process::exit(app.run());
 }
+
+fn dump_snapshot(mode: &str) -> i32 {
+    let snapshot = backend::Collector::collect_once();
+    let mut out = std::io::stdout().lock();
+    let result = match mode {
+        "--dump-json" => backend::export::write_snapshot_json(&mut out, &snapshot, true),
+        "--dump-csv" => backend::export::write_processes_csv(&mut out, &snapshot.processes),
+        _ => backend::export::write_snapshot_text(&mut out, &snapshot),
+    };
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    }
+}
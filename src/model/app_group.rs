@@ -1,40 +1,73 @@
 use super::ProcessInfo;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AppGroup {
     pub leader: ProcessInfo,
     pub children: Vec<ProcessInfo>,
     pub total_cpu: f64,
     pub total_memory: u64,
+    pub total_pss: u64,
+    pub total_shared: u64,
+    pub total_private: u64,
+    pub total_swap: u64,
     pub total_vram: u64,
     pub total_disk_read_rate: f64,
     pub total_disk_write_rate: f64,
+    pub total_rx_bytes_sec: f64,
+    pub total_tx_bytes_sec: f64,
+    pub total_connections: u32,
+    pub total_fds: u32,
 }
 
 impl AppGroup {
     pub fn new(leader: ProcessInfo) -> Self {
         let total_cpu = leader.cpu_percent;
         let total_memory = leader.memory_bytes;
+        let total_pss = leader.pss_bytes;
+        let total_shared = leader.shared_bytes;
+        let total_private = leader.private_bytes;
+        let total_swap = leader.swap_bytes;
         let total_vram = leader.vram_bytes;
         let total_disk_read_rate = leader.disk_read_rate;
         let total_disk_write_rate = leader.disk_write_rate;
+        let total_rx_bytes_sec = leader.rx_bytes_sec;
+        let total_tx_bytes_sec = leader.tx_bytes_sec;
+        let total_connections = leader.connection_count;
+        let total_fds = leader.fd_count;
         Self {
             leader,
             children: Vec::new(),
             total_cpu,
             total_memory,
+            total_pss,
+            total_shared,
+            total_private,
+            total_swap,
             total_vram,
             total_disk_read_rate,
             total_disk_write_rate,
+            total_rx_bytes_sec,
+            total_tx_bytes_sec,
+            total_connections,
+            total_fds,
         }
     }
 
     pub fn add_child(&mut self, child: ProcessInfo) {
         self.total_cpu += child.cpu_percent;
         self.total_memory += child.memory_bytes;
+        self.total_pss += child.pss_bytes;
+        self.total_shared += child.shared_bytes;
+        self.total_private += child.private_bytes;
+        self.total_swap += child.swap_bytes;
         self.total_vram += child.vram_bytes;
         self.total_disk_read_rate += child.disk_read_rate;
         self.total_disk_write_rate += child.disk_write_rate;
+        self.total_rx_bytes_sec += child.rx_bytes_sec;
+        self.total_tx_bytes_sec += child.tx_bytes_sec;
+        self.total_connections += child.connection_count;
+        self.total_fds += child.fd_count;
         self.children.push(child);
     }
 
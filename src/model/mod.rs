@@ -3,6 +3,7 @@ mod system_snapshot;
 mod app_group;
 pub mod startup_entry;
 pub mod service_entry;
+pub mod timer_entry;
 
 pub use process_info::ProcessInfo;
 pub use system_snapshot::*;
@@ -12,14 +12,28 @@ pub struct ProcessInfo {
     pub cpu_percent: f64,
     pub memory_bytes: u64,
     pub memory_percent: f64,
+    /// Proportional set size: RSS with shared pages split between their users.
+    /// This and the other smaps_rollup values are 0 when it isn't readable.
+    pub pss_bytes: u64,
+    pub shared_bytes: u64,
+    pub private_bytes: u64,
+    pub swap_bytes: u64,
     pub vram_bytes: u64,
     pub disk_read_bytes: u64,
     pub disk_write_bytes: u64,
     pub disk_read_rate: f64,
     pub disk_write_rate: f64,
+    pub rx_bytes_sec: f64,
+    pub tx_bytes_sec: f64,
+    /// Open TCP/UDP sockets
+    pub connection_count: u32,
+    /// Open file descriptors (0 when /proc/<pid>/fd isn't readable)
+    pub fd_count: u32,
     pub nice: i32,
     pub threads: u64,
     pub start_time: u64,
+    /// Wall-clock start time (Unix seconds), derived from `start_time`
+    pub started_at: u64,
     pub uid: u32,
     pub user: String,
     pub container_type: String,
@@ -33,8 +47,11 @@ pub struct ProcessInfo {
     pub security_label: String,
     // Internal tracking for CPU delta calculation
     pub total_cpu_time: u64,
+    #[serde(skip)]
     pub prev_cpu_time: u64,
+    #[serde(skip)]
     pub prev_disk_read: u64,
+    #[serde(skip)]
     pub prev_disk_write: u64,
 }
 
@@ -51,14 +68,23 @@ impl Default for ProcessInfo {
             cpu_percent: 0.0,
             memory_bytes: 0,
             memory_percent: 0.0,
+            pss_bytes: 0,
+            shared_bytes: 0,
+            private_bytes: 0,
+            swap_bytes: 0,
             vram_bytes: 0,
             disk_read_bytes: 0,
             disk_write_bytes: 0,
             disk_read_rate: 0.0,
             disk_write_rate: 0.0,
+            rx_bytes_sec: 0.0,
+            tx_bytes_sec: 0.0,
+            connection_count: 0,
+            fd_count: 0,
             nice: 0,
             threads: 0,
             start_time: 0,
+            started_at: 0,
             uid: 0,
             user: String::new(),
             container_type: String::new(),
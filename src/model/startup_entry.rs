@@ -11,6 +11,30 @@ pub struct StartupEntry {
     pub source: StartupSource,
     /// For systemd services: "active", "inactive", "failed"; empty for autostart entries
     pub active_state: String,
+    pub impact: StartupImpact,
+    /// Why `impact` was rated so, for the tooltip
+    pub impact_reason: String,
+}
+
+/// Estimated effect on login time, like Windows Task Manager's startup impact.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StartupImpact {
+    #[default]
+    NotMeasured,
+    Low,
+    Medium,
+    High,
+}
+
+impl std::fmt::Display for StartupImpact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartupImpact::NotMeasured => write!(f, "Not measured"),
+            StartupImpact::Low => write!(f, "Low"),
+            StartupImpact::Medium => write!(f, "Medium"),
+            StartupImpact::High => write!(f, "High"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
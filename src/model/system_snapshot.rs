@@ -1,21 +1,35 @@
 use super::ProcessInfo;
 use super::AppGroup;
+use serde::Serialize;
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct CpuInfo {
     pub total_percent: f64,
     pub per_core_percent: Vec<f64>,
     pub core_count: usize,
     pub model_name: String,
     pub frequency_mhz: f64,
+    pub max_frequency_mhz: f64,
     pub uptime_secs: u64,
     pub temperature_celsius: f64,
     pub per_core_temperatures: Vec<f64>,
     pub per_core_frequencies: Vec<(f64, String)>,
 }
 
-#[derive(Debug, Clone, Default)]
+/// Classic Unix load averages and task counts from /proc/loadavg
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+    /// Tasks (threads) currently runnable
+    pub runnable: u32,
+    /// All tasks (threads) on the system
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct MemoryInfo {
     pub total: u64,
     pub used: u64,
@@ -23,37 +37,72 @@ pub struct MemoryInfo {
     pub cached: u64,
     pub swap_total: u64,
     pub swap_used: u64,
+    pub swap_devices: Vec<SwapDevice>,
 }
 
-#[derive(Debug, Clone, Default)]
+/// One active swap area from /proc/swaps
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SwapDevice {
+    pub name: String,
+    /// "partition" or "file", as reported by the kernel
+    pub swap_type: String,
+    pub is_zram: bool,
+    pub size: u64,
+    pub used: u64,
+    pub priority: i32,
+    /// Compressed size of the data stored in a zram device (0 for disk swap)
+    pub compressed: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DiskInfo {
     pub devices: Vec<DiskDevice>,
+    pub filesystems: Vec<FilesystemUsage>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DiskDevice {
     pub name: String,
     pub read_bytes_sec: f64,
     pub write_bytes_sec: f64,
     pub total_read: u64,
     pub total_write: u64,
+    /// Completed operations per second
+    pub read_iops: f64,
+    pub write_iops: f64,
+    /// Average time per completed operation (queue + service), like iostat's r_await/w_await
+    pub read_await_ms: f64,
+    pub write_await_ms: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FilesystemUsage {
+    pub device: String,
+    pub mount_point: String,
+    pub fs_type: String,
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+    pub is_pseudo: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct NetworkInfo {
     pub interfaces: Vec<NetworkInterface>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct NetworkInterface {
     pub name: String,
     pub rx_bytes_sec: f64,
     pub tx_bytes_sec: f64,
     pub total_rx: u64,
     pub total_tx: u64,
+    /// Software interface (bridge, veth, tun, ...) with no backing device
+    pub is_virtual: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct GpuInfo {
     pub available: bool,
     pub name: String,
@@ -64,9 +113,13 @@ pub struct GpuInfo {
     pub power_watts: f64,
     pub power_limit_watts: f64,
     pub fan_speed_percent: u32,
+    /// Current core and memory clocks; 0 when the driver doesn't report them
+    pub core_clock_mhz: u32,
+    pub mem_clock_mhz: u32,
+    pub is_discrete: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct BatteryInfo {
     pub available: bool,
     pub percent: f64,
@@ -74,21 +127,36 @@ pub struct BatteryInfo {
     pub power_watts: f64,
     pub time_remaining_secs: u64,
     pub ac_connected: bool,
+    /// Full capacity as a percentage of the design capacity
+    pub health_percent: Option<f64>,
+    pub cycle_count: Option<u64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SystemSnapshot {
     pub processes: Vec<ProcessInfo>,
     pub app_groups: Vec<AppGroup>,
     pub cpu: CpuInfo,
+    pub load: LoadAverage,
     pub memory: MemoryInfo,
     pub disk: DiskInfo,
     pub network: NetworkInfo,
     pub gpu: Vec<GpuInfo>,
     pub battery: BatteryInfo,
+    pub psi: crate::backend::psi::PsiMetrics,
     pub process_count: usize,
     pub thread_count: u64,
     pub app_histories: HashMap<String, crate::backend::history::AppHistory>,
+    /// System-wide TCP/UDP sockets
+    pub connections: Vec<crate::backend::net_per_process::NetConnection>,
+}
+
+impl SystemSnapshot {
+    /// The GPU to show by default: the first discrete GPU, else the first available one.
+    pub fn primary_gpu_index(&self) -> Option<usize> {
+        self.gpu.iter().position(|g| g.available && g.is_discrete)
+            .or_else(|| self.gpu.iter().position(|g| g.available))
+    }
 }
 
 impl Default for SystemSnapshot {
@@ -97,14 +165,17 @@ impl Default for SystemSnapshot {
             processes: Vec::new(),
             app_groups: Vec::new(),
             cpu: CpuInfo::default(),
+            load: LoadAverage::default(),
             memory: MemoryInfo::default(),
             disk: DiskInfo::default(),
             network: NetworkInfo::default(),
             gpu: Vec::new(),
             battery: BatteryInfo::default(),
+            psi: crate::backend::psi::PsiMetrics::default(),
             process_count: 0,
             thread_count: 0,
             app_histories: HashMap::new(),
+            connections: Vec::new(),
         }
     }
 }
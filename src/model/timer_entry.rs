@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Default)]
+pub struct TimerEntry {
+    /// Timer unit, e.g. "logrotate.timer"
+    pub unit: String,
+    /// Unit the timer starts, e.g. "logrotate.service"
+    pub activates: String,
+    /// Next elapse time as printed by systemctl; empty if not scheduled
+    pub next: String,
+    /// Time until the next elapse, e.g. "5h 3min"
+    pub left: String,
+    /// Last trigger time; empty if it never ran
+    pub last: String,
+    /// Time since the last trigger
+    pub passed: String,
+    /// Per-user timer (`systemctl --user`) rather than a system one
+    pub user: bool,
+}
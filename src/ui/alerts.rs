@@ -0,0 +1,184 @@
+use gtk4 as gtk;
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::prelude::*;
+
+use crate::config::Config;
+use crate::model::service_entry::ServiceEntry;
+use crate::model::{ProcessInfo, SystemSnapshot};
+use crate::util;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+const NOTIFICATIONS_NAME: &str = "org.freedesktop.Notifications";
+const NOTIFICATIONS_PATH: &str = "/org/freedesktop/Notifications";
+
+/// Watches collector snapshots for runaway processes and sends a desktop
+/// notification over org.freedesktop.Notifications. Thresholds are read from
+/// the config on every check, so changes in Preferences apply immediately.
+///
+/// A process alerts once per excursion: it has to drop back below the
+/// threshold (memory: below 90% of it) before it can alert again.
+pub struct ProcessAlerts {
+    connection: gio::DBusConnection,
+    config: Rc<RefCell<Config>>,
+    // PID -> when it went over the CPU threshold
+    cpu_over_since: HashMap<i32, Instant>,
+    cpu_notified: HashSet<i32>,
+    memory_notified: HashSet<i32>,
+}
+
+impl ProcessAlerts {
+    pub fn new(connection: &gio::DBusConnection, config: Rc<RefCell<Config>>) -> Self {
+        Self {
+            connection: connection.clone(),
+            config,
+            cpu_over_since: HashMap::new(),
+            cpu_notified: HashSet::new(),
+            memory_notified: HashSet::new(),
+        }
+    }
+
+    /// Check a freshly collected snapshot.
+    pub fn check(&mut self, snapshot: &SystemSnapshot) {
+        let (enabled, cpu_limit, cpu_secs, memory_limit, cpu_of_total) = {
+            let cfg = self.config.borrow();
+            (
+                cfg.alerts_enabled,
+                cfg.alert_cpu_percent,
+                cfg.alert_cpu_secs,
+                cfg.alert_memory_mb * 1024 * 1024,
+                cfg.cpu_of_total,
+            )
+        };
+        if !enabled {
+            self.cpu_over_since.clear();
+            self.cpu_notified.clear();
+            self.memory_notified.clear();
+            return;
+        }
+
+        let now = Instant::now();
+        let sustain = Duration::from_secs(cpu_secs);
+        // Compare in the process list's unit: one core = 100%, or the whole
+        // machine = 100% when CPU is shown as a share of the total
+        let cpu_scale = if cpu_of_total { snapshot.cpu.core_count.max(1) as f64 } else { 1.0 };
+        for proc in &snapshot.processes {
+            let cpu_percent = proc.cpu_percent / cpu_scale;
+            if cpu_limit > 0.0 && cpu_percent > cpu_limit {
+                let since = *self.cpu_over_since.entry(proc.pid).or_insert(now);
+                if now.duration_since(since) >= sustain && self.cpu_notified.insert(proc.pid) {
+                    self.notify(
+                        &format!("{} is using {} CPU", process_name(proc), util::format_percent(cpu_percent)),
+                        &format!("PID {} has been above {:.0}% CPU for {} seconds.", proc.pid, cpu_limit, cpu_secs),
+                    );
+                }
+            } else {
+                self.cpu_over_since.remove(&proc.pid);
+                self.cpu_notified.remove(&proc.pid);
+            }
+
+            if memory_limit > 0 && proc.memory_bytes > memory_limit {
+                if self.memory_notified.insert(proc.pid) {
+                    self.notify(
+                        &format!("{} is using {} of memory", process_name(proc), util::format_bytes(proc.memory_bytes)),
+                        &format!("PID {} is above the {} memory limit.", proc.pid, util::format_bytes(memory_limit)),
+                    );
+                }
+            } else if proc.memory_bytes < memory_limit / 10 * 9 {
+                self.memory_notified.remove(&proc.pid);
+            }
+        }
+
+        // Forget processes that exited
+        let alive: HashSet<i32> = snapshot.processes.iter().map(|p| p.pid).collect();
+        self.cpu_over_since.retain(|pid, _| alive.contains(pid));
+        self.cpu_notified.retain(|pid| alive.contains(pid));
+        self.memory_notified.retain(|pid| alive.contains(pid));
+    }
+
+    fn notify(&self, summary: &str, body: &str) {
+        send_notification(&self.connection, summary, body);
+    }
+}
+
+/// Watches successive service lists and notifies when a unit enters the
+/// `failed` state. The first list only records the current states, so units
+/// that were already failed at startup don't alert.
+pub struct ServiceAlerts {
+    connection: gio::DBusConnection,
+    // Unit name -> active state from the previous check
+    states: Option<HashMap<String, String>>,
+}
+
+impl ServiceAlerts {
+    pub fn new(connection: &gio::DBusConnection) -> Self {
+        Self {
+            connection: connection.clone(),
+            states: None,
+        }
+    }
+
+    /// Compare with the previous check; returns how many units are failed now.
+    pub fn check(&mut self, entries: &[ServiceEntry]) -> usize {
+        if let Some(states) = &self.states {
+            for entry in entries.iter().filter(|e| e.active_state == "failed") {
+                if states.get(&entry.name).map(String::as_str) != Some("failed") {
+                    send_notification(
+                        &self.connection,
+                        &format!("{} failed", entry.name),
+                        if entry.description.is_empty() { &entry.name } else { &entry.description },
+                    );
+                }
+            }
+        }
+        self.states = Some(entries.iter().map(|e| (e.name.clone(), e.active_state.clone())).collect());
+        entries.iter().filter(|e| e.active_state == "failed").count()
+    }
+
+    /// Forget the recorded states, e.g. while monitoring is switched off.
+    pub fn reset(&mut self) {
+        self.states = None;
+    }
+}
+
+fn send_notification(connection: &gio::DBusConnection, summary: &str, body: &str) {
+    log::info!("Alert: {} — {}", summary, body);
+    let args = (
+        "Task Manager",
+        0u32,
+        "utilities-system-monitor",
+        summary,
+        body,
+        Vec::<String>::new(),
+        HashMap::<String, glib::Variant>::new(),
+        -1i32,
+    )
+        .to_variant();
+    connection.call(
+        Some(NOTIFICATIONS_NAME),
+        NOTIFICATIONS_PATH,
+        NOTIFICATIONS_NAME,
+        "Notify",
+        Some(&args),
+        None,
+        gio::DBusCallFlags::NONE,
+        -1,
+        None::<&gio::Cancellable>,
+        |result| {
+            if let Err(e) = result {
+                log::warn!("Failed to send notification: {}", e);
+            }
+        },
+    );
+}
+
+fn process_name(proc: &ProcessInfo) -> &str {
+    if proc.display_name.is_empty() {
+        &proc.name
+    } else {
+        &proc.display_name
+    }
+}
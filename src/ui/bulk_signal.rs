@@ -0,0 +1,280 @@
+use gtk4 as gtk;
+use gtk::prelude::*;
+use nix::sys::signal::Signal;
+use nix::unistd::Pid;
+
+use crate::backend::process_restart;
+use crate::model::ProcessInfo;
+use crate::ui::process_tab::is_critical_process;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Signals offered by the dialog: (label, signal).
+const SIGNALS: &[(&str, Signal)] = &[
+    ("End (SIGTERM)", Signal::SIGTERM),
+    ("Force Kill (SIGKILL)", Signal::SIGKILL),
+    ("Pause (SIGSTOP)", Signal::SIGSTOP),
+    ("Resume (SIGCONT)", Signal::SIGCONT),
+    ("Hangup (SIGHUP)", Signal::SIGHUP),
+    ("Interrupt (SIGINT)", Signal::SIGINT),
+];
+
+/// Processes the desktop session runs as the logged-in user. Picking your own
+/// user without a name leaves these alone, so it doesn't take the session down.
+const SESSION_PROCESSES: &[&str] = &[
+    "systemd", "gnome-session-binary", "gnome-shell", "mutter", "kwin_wayland", "kwin_x11",
+    "plasmashell", "ksmserver", "sway", "Hyprland", "niri", "river", "wayfire", "labwc",
+    "weston", "Xwayland", "Xorg", "dbus-daemon", "dbus-broker", "dbus-broker-launch",
+    "pipewire", "pipewire-pulse", "wireplumber", "pulseaudio", "xdg-desktop-portal",
+    "xdg-document-portal", "xdg-permission-store", "gnome-keyring-daemon", "kwalletd6",
+    "kwalletd5", "at-spi-bus-launcher", "at-spi2-registryd", "gvfsd",
+];
+
+/// Processes picked by the current pattern and user, split into the ones that
+/// will be signalled and the ones left alone, with the reason.
+struct Matches {
+    targets: Vec<ProcessInfo>,
+    excluded: Vec<(ProcessInfo, &'static str)>,
+}
+
+/// `killall`/`pkill` with a preview: signal every process whose name contains
+/// a pattern and/or that belongs to one user, skipping critical processes.
+/// Picking your own user without a name also skips the session itself
+/// (compositor, audio, D-Bus).
+pub fn show_bulk_signal_dialog(parent: &impl IsA<gtk::Window>, processes: Vec<ProcessInfo>) {
+    let dialog = gtk::Window::builder()
+        .title("Signal Processes by Name")
+        .default_width(560)
+        .default_height(520)
+        .modal(true)
+        .transient_for(parent)
+        .build();
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_margin_top(12);
+    vbox.set_margin_start(12);
+    vbox.set_margin_end(12);
+    vbox.set_margin_bottom(12);
+
+    let grid = gtk::Grid::new();
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+
+    let pattern_entry = gtk::Entry::new();
+    pattern_entry.set_placeholder_text(Some("e.g. chrome"));
+    pattern_entry.set_hexpand(true);
+    let exact_check = gtk::CheckButton::with_label("Exact name");
+
+    let mut users: Vec<String> = processes.iter().map(|p| p.user.clone()).filter(|u| !u.is_empty()).collect();
+    users.sort();
+    users.dedup();
+    let mut user_labels = vec!["Any user"];
+    user_labels.extend(users.iter().map(|u| u.as_str()));
+    let user_dropdown = gtk::DropDown::from_strings(&user_labels);
+
+    let signal_labels: Vec<&str> = SIGNALS.iter().map(|(label, _)| *label).collect();
+    let signal_dropdown = gtk::DropDown::from_strings(&signal_labels);
+
+    let rows: [(&str, &gtk::Widget); 3] = [
+        ("Name", pattern_entry.upcast_ref()),
+        ("User", user_dropdown.upcast_ref()),
+        ("Signal", signal_dropdown.upcast_ref()),
+    ];
+    for (i, (label, widget)) in rows.iter().enumerate() {
+        let key = gtk::Label::new(Some(label));
+        key.set_halign(gtk::Align::Start);
+        key.add_css_class("dim-label");
+        grid.attach(&key, 0, i as i32, 1, 1);
+        grid.attach(*widget, 1, i as i32, 1, 1);
+    }
+    grid.attach(&exact_check, 2, 0, 1, 1);
+    vbox.append(&grid);
+
+    let summary_label = gtk::Label::new(None);
+    summary_label.set_halign(gtk::Align::Start);
+    summary_label.set_wrap(true);
+    vbox.append(&summary_label);
+
+    let preview = gtk::ListBox::new();
+    preview.set_selection_mode(gtk::SelectionMode::None);
+    let preview_scroll = gtk::ScrolledWindow::builder()
+        .child(&preview)
+        .vexpand(true)
+        .build();
+    preview_scroll.add_css_class("card");
+    vbox.append(&preview_scroll);
+
+    let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    button_box.set_halign(gtk::Align::End);
+    let cancel_btn = gtk::Button::with_label("Cancel");
+    let send_btn = gtk::Button::with_label("Send Signal");
+    send_btn.add_css_class("destructive-action");
+    send_btn.set_sensitive(false);
+    button_box.append(&cancel_btn);
+    button_box.append(&send_btn);
+    vbox.append(&button_box);
+
+    let matches: Rc<RefCell<Matches>> = Rc::new(RefCell::new(Matches { targets: Vec::new(), excluded: Vec::new() }));
+    let own_pid = std::process::id() as i32;
+    let own_uid = unsafe { libc::getuid() };
+
+    // Recompute the preview whenever an input changes
+    let refresh: Rc<dyn Fn()> = {
+        let pattern_entry = pattern_entry.clone();
+        let exact_check = exact_check.clone();
+        let user_dropdown = user_dropdown.clone();
+        let signal_dropdown = signal_dropdown.clone();
+        let summary_label = summary_label.clone();
+        let preview = preview.clone();
+        let send_btn = send_btn.clone();
+        let matches = matches.clone();
+        Rc::new(move || {
+            let pattern = pattern_entry.text().trim().to_lowercase();
+            let user = match user_dropdown.selected() {
+                0 | gtk::INVALID_LIST_POSITION => None,
+                n => users.get(n as usize - 1),
+            };
+            let exact = exact_check.is_active();
+
+            let mut found = Matches { targets: Vec::new(), excluded: Vec::new() };
+            // Nothing matches until a name or a user narrows it down
+            if !pattern.is_empty() || user.is_some() {
+                for p in &processes {
+                    if p.pid == own_pid || user.is_some_and(|u| *u != p.user) {
+                        continue;
+                    }
+                    if !pattern.is_empty() && !name_matches(p, &pattern, exact) {
+                        continue;
+                    }
+                    if is_critical_process(p.pid) {
+                        found.excluded.push((p.clone(), "critical"));
+                    } else if pattern.is_empty() && p.uid == own_uid && SESSION_PROCESSES.contains(&p.name.as_str()) {
+                        found.excluded.push((p.clone(), "session"));
+                    } else {
+                        found.targets.push(p.clone());
+                    }
+                }
+            }
+
+            while let Some(row) = preview.first_child() {
+                preview.remove(&row);
+            }
+            for (p, reason) in found.targets.iter().map(|p| (p, None))
+                .chain(found.excluded.iter().map(|(p, reason)| (p, Some(*reason))))
+            {
+                let text = format!(
+                    "{:<8} {:<24} {:<12}{}",
+                    p.pid,
+                    p.name,
+                    p.user,
+                    reason.map(|r| format!(" ({}, skipped)", r)).unwrap_or_default()
+                );
+                let label = gtk::Label::new(Some(&text));
+                label.set_halign(gtk::Align::Start);
+                label.add_css_class("monospace");
+                if reason.is_some() {
+                    label.add_css_class("dim-label");
+                }
+                label.set_margin_start(8);
+                label.set_margin_top(1);
+                label.set_margin_bottom(1);
+                preview.append(&label);
+            }
+
+            let (signal_label, _) = SIGNALS[signal_dropdown.selected() as usize % SIGNALS.len()];
+            let mut summary = if pattern.is_empty() && user.is_none() {
+                "Enter a name or pick a user.".to_string()
+            } else {
+                format!("{} process(es) will receive {}.", found.targets.len(), signal_label)
+            };
+            if !found.excluded.is_empty() {
+                summary.push_str(&format!(
+                    " {} critical or session process(es) excluded.",
+                    found.excluded.len()
+                ));
+            }
+            summary_label.set_text(&summary);
+            send_btn.set_sensitive(!found.targets.is_empty());
+            *matches.borrow_mut() = found;
+        })
+    };
+
+    {
+        let refresh = refresh.clone();
+        pattern_entry.connect_changed(move |_| refresh());
+    }
+    {
+        let refresh = refresh.clone();
+        exact_check.connect_toggled(move |_| refresh());
+    }
+    {
+        let refresh = refresh.clone();
+        user_dropdown.connect_selected_notify(move |_| refresh());
+    }
+    {
+        let refresh = refresh.clone();
+        signal_dropdown.connect_selected_notify(move |_| refresh());
+    }
+    refresh();
+
+    let dialog_for_cancel = dialog.clone();
+    cancel_btn.connect_clicked(move |_| dialog_for_cancel.close());
+
+    let dialog_for_send = dialog.clone();
+    send_btn.connect_clicked(move |_| {
+        let (signal_label, signal) = SIGNALS[signal_dropdown.selected() as usize % SIGNALS.len()];
+        let matches = matches.borrow();
+        let mut failed = Vec::new();
+        let mut sent = 0;
+        for p in &matches.targets {
+            // Skip PIDs that were reused by another program since the preview
+            if !still_same_process(p) {
+                continue;
+            }
+            match nix::sys::signal::kill(Pid::from_raw(p.pid), signal) {
+                Ok(()) => sent += 1,
+                Err(e) => failed.push(format!("{} (PID {}): {}", p.name, p.pid, e)),
+            }
+        }
+        log::info!("Sent {} to {} process(es), {} failed", signal_label, sent, failed.len());
+
+        if !failed.is_empty() {
+            let message = format!(
+                "Sent {} to {} process(es), but {} could not be signalled:\n\n{}",
+                signal_label,
+                sent,
+                failed.len(),
+                failed.join("\n")
+            );
+            let error = gtk::MessageDialog::new(
+                dialog_for_send.transient_for().as_ref(),
+                gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+                gtk::MessageType::Error,
+                gtk::ButtonsType::Ok,
+                &message,
+            );
+            error.connect_response(|d, _| d.close());
+            error.present();
+        }
+        dialog_for_send.close();
+    });
+
+    dialog.set_child(Some(&vbox));
+    dialog.present();
+}
+
+/// Case-insensitive match of the (lowercased) pattern against the process
+/// name, its display name and the executable's file name.
+fn name_matches(p: &ProcessInfo, pattern: &str, exact: bool) -> bool {
+    let exe_name = p.exe_path.rsplit('/').next().unwrap_or("");
+    [p.name.as_str(), p.display_name.as_str(), exe_name]
+        .iter()
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_lowercase())
+        .any(|name| if exact { name == pattern } else { name.contains(pattern) })
+}
+
+/// Whether the PID still belongs to the previewed process, by start time.
+fn still_same_process(p: &ProcessInfo) -> bool {
+    process_restart::read_stat(p.pid).is_some_and(|(_, start_time)| start_time == p.start_time)
+}
@@ -0,0 +1,63 @@
+use gtk4 as gtk;
+use gtk::prelude::*;
+
+use crate::config::{Config, TableSort};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Sort `column_view` by the saved column (matched by id, else by title) and
+/// report every later change of the primary sort column or direction.
+pub fn restore_and_track(
+    column_view: &gtk::ColumnView,
+    saved: Option<TableSort>,
+    on_change: impl Fn(TableSort) + 'static,
+) {
+    let Some(sorter) = column_view.sorter().and_downcast::<gtk::ColumnViewSorter>() else {
+        return;
+    };
+
+    if let Some(saved) = &saved {
+        let columns = column_view.columns();
+        let column = (0..columns.n_items())
+            .filter_map(|i| columns.item(i).and_downcast::<gtk::ColumnViewColumn>())
+            .find(|col| column_key(col) == saved.column);
+        if let Some(column) = column {
+            let order = if saved.ascending { gtk::SortType::Ascending } else { gtk::SortType::Descending };
+            column_view.sort_by_column(Some(&column), order);
+        }
+    }
+
+    let last = RefCell::new(saved);
+    sorter.connect_changed(move |sorter, _| {
+        let Some(column) = sorter.primary_sort_column() else {
+            return;
+        };
+        let current = TableSort {
+            column: column_key(&column),
+            ascending: sorter.primary_sort_order() == gtk::SortType::Ascending,
+        };
+        if last.borrow().as_ref() != Some(&current) {
+            on_change(current.clone());
+            *last.borrow_mut() = Some(current);
+        }
+    });
+}
+
+/// Restore and persist the sort of one table under `key` in `Config::table_sorts`.
+pub fn persist(column_view: &gtk::ColumnView, config: &Rc<RefCell<Config>>, key: &'static str) {
+    let saved = config.borrow().table_sorts.get(key).cloned();
+    let config = config.clone();
+    restore_and_track(column_view, saved, move |sort| {
+        let mut cfg = config.borrow_mut();
+        cfg.table_sorts.insert(key.to_string(), sort);
+        cfg.save();
+    });
+}
+
+fn column_key(column: &gtk::ColumnViewColumn) -> String {
+    column
+        .id()
+        .or_else(|| column.title())
+        .map(|s| s.to_string())
+        .unwrap_or_default()
+}
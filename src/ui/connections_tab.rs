@@ -0,0 +1,250 @@
+use gtk4 as gtk;
+use gtk::prelude::*;
+use gtk::glib;
+use gtk::gio;
+use gtk::subclass::prelude::ObjectSubclassIsExt;
+
+use crate::backend::net_per_process::NetConnection;
+use crate::config::Config;
+use crate::model::SystemSnapshot;
+use crate::ui::column_sort;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// ---------------------------------------------------------------------------
+// ConnectionObject - GObject wrapper for one socket
+// ---------------------------------------------------------------------------
+
+mod imp {
+    use super::*;
+    use gtk::glib;
+    use gtk::subclass::prelude::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    pub struct ConnectionObject {
+        pub connection: RefCell<Option<NetConnection>>,
+        // Name of the owning process, empty when unknown
+        pub process: RefCell<String>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ConnectionObject {
+        const NAME: &'static str = "ConnectionObject";
+        type Type = super::ConnectionObject;
+        type ParentType = glib::Object;
+    }
+
+    impl ObjectImpl for ConnectionObject {}
+}
+
+glib::wrapper! {
+    pub struct ConnectionObject(ObjectSubclass<imp::ConnectionObject>);
+}
+
+impl ConnectionObject {
+    pub fn new() -> Self {
+        glib::Object::builder().build()
+    }
+
+    pub fn set_from_connection(&self, connection: &NetConnection, process: &str) {
+        *self.imp().connection.borrow_mut() = Some(connection.clone());
+        *self.imp().process.borrow_mut() = process.to_string();
+    }
+
+    pub fn protocol(&self) -> String {
+        self.with(|c| c.protocol.clone())
+    }
+
+    pub fn local(&self) -> String {
+        self.with(|c| format_endpoint(&c.local_addr, c.local_port))
+    }
+
+    pub fn remote(&self) -> String {
+        self.with(|c| format_endpoint(&c.remote_addr, c.remote_port))
+    }
+
+    pub fn state(&self) -> String {
+        self.with(|c| c.state.clone())
+    }
+
+    pub fn pid(&self) -> i32 {
+        self.with(|c| c.pid)
+    }
+
+    pub fn process(&self) -> String {
+        self.imp().process.borrow().clone()
+    }
+
+    fn with<T: Default>(&self, f: impl Fn(&NetConnection) -> T) -> T {
+        self.imp().connection.borrow().as_ref().map(f).unwrap_or_default()
+    }
+}
+
+/// "addr:port", with IPv6 addresses bracketed like ss does
+fn format_endpoint(addr: &str, port: u16) -> String {
+    let port = if port == 0 { "*".to_string() } else { port.to_string() };
+    if addr.contains(':') {
+        format!("[{}]:{}", addr, port)
+    } else {
+        format!("{}:{}", addr, port)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ConnectionsTab - every TCP/UDP socket on the system, like `ss -tunap`
+// ---------------------------------------------------------------------------
+
+pub struct ConnectionsTab {
+    pub widget: gtk::Box,
+    store: gio::ListStore,
+    count_label: gtk::Label,
+}
+
+impl ConnectionsTab {
+    pub fn new(config: Rc<RefCell<Config>>) -> Self {
+        let widget = gtk::Box::new(gtk::Orientation::Vertical, 0);
+
+        let toolbar = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+        toolbar.add_css_class("toolbar");
+        toolbar.set_margin_start(6);
+        toolbar.set_margin_end(6);
+        toolbar.set_margin_top(6);
+        toolbar.set_margin_bottom(6);
+
+        let search_entry = gtk::SearchEntry::new();
+        search_entry.set_placeholder_text(Some("Search connections..."));
+        search_entry.set_hexpand(true);
+        search_entry.add_css_class("search-bar");
+        toolbar.append(&search_entry);
+
+        let count_label = gtk::Label::new(None);
+        count_label.add_css_class("dim-label");
+        toolbar.append(&count_label);
+        widget.append(&toolbar);
+
+        let store = gio::ListStore::new::<ConnectionObject>();
+
+        let search_weak = search_entry.downgrade();
+        let filter = gtk::CustomFilter::new(move |obj| {
+            let Some(search_entry) = search_weak.upgrade() else {
+                return true;
+            };
+            let text = search_entry.text().to_string().to_lowercase();
+            if text.is_empty() {
+                return true;
+            }
+            let conn = obj.downcast_ref::<ConnectionObject>().unwrap();
+            [conn.protocol(), conn.local(), conn.remote(), conn.state(), conn.process(), conn.pid().to_string()]
+                .iter()
+                .any(|field| field.to_lowercase().contains(&text))
+        });
+        let filter_model = gtk::FilterListModel::new(Some(store.clone()), Some(filter.clone()));
+        search_entry.connect_search_changed(move |_| {
+            filter.changed(gtk::FilterChange::Different);
+        });
+
+        let sort_model = gtk::SortListModel::new(Some(filter_model), None::<gtk::Sorter>);
+        let selection = gtk::SingleSelection::new(Some(sort_model.clone()));
+        selection.set_autoselect(false);
+
+        let column_view = gtk::ColumnView::new(Some(selection));
+        column_view.set_show_column_separators(true);
+        column_view.set_show_row_separators(false);
+
+        let columns: [(&str, &str, fn(&ConnectionObject) -> String, i32); 6] = [
+            ("Protocol", "protocol", |c| c.protocol(), 80),
+            ("Local Address", "local", |c| c.local(), -1),
+            ("Remote Address", "remote", |c| c.remote(), -1),
+            ("State", "state", |c| c.state(), 110),
+            ("Process", "process", |c| if c.process().is_empty() { "—".into() } else { c.process() }, 180),
+            ("PID", "pid", |c| if c.pid() > 0 { c.pid().to_string() } else { "—".into() }, 80),
+        ];
+        for (title, id, text_of, width) in columns {
+            let factory = gtk::SignalListItemFactory::new();
+            factory.connect_setup(|_, item| {
+                let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+                let label = gtk::Label::new(None);
+                label.set_halign(gtk::Align::Start);
+                label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+                item.set_child(Some(&label));
+            });
+            factory.connect_bind(move |_, item| {
+                let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+                let obj = item.item().and_downcast::<ConnectionObject>().unwrap();
+                let label = item.child().and_downcast::<gtk::Label>().unwrap();
+                label.set_text(&text_of(&obj));
+            });
+            let column = gtk::ColumnViewColumn::new(Some(title), Some(factory));
+            column.set_id(Some(id));
+            column.set_resizable(true);
+            if width > 0 {
+                column.set_fixed_width(width);
+            } else {
+                column.set_expand(true);
+            }
+            let sorter = if id == "pid" {
+                gtk::CustomSorter::new(|a, b| {
+                    let ca = a.downcast_ref::<ConnectionObject>().unwrap();
+                    let cb = b.downcast_ref::<ConnectionObject>().unwrap();
+                    ca.pid().cmp(&cb.pid()).into()
+                })
+            } else {
+                gtk::CustomSorter::new(move |a, b| {
+                    let ta = text_of(a.downcast_ref::<ConnectionObject>().unwrap()).to_lowercase();
+                    let tb = text_of(b.downcast_ref::<ConnectionObject>().unwrap()).to_lowercase();
+                    ta.cmp(&tb).into()
+                })
+            };
+            column.set_sorter(Some(&sorter));
+            column_view.append_column(&column);
+        }
+        if let Some(sorter) = column_view.sorter() {
+            sort_model.set_sorter(Some(&sorter));
+        }
+        column_sort::persist(&column_view, &config, "connections");
+
+        let scroll = gtk::ScrolledWindow::builder()
+            .vexpand(true)
+            .hexpand(true)
+            .child(&column_view)
+            .build();
+        widget.append(&scroll);
+
+        Self { widget, store, count_label }
+    }
+
+    /// Show the sockets of a new snapshot.
+    pub fn update(&mut self, snapshot: &SystemSnapshot) {
+        let names: HashMap<i32, &str> = snapshot
+            .processes
+            .iter()
+            .map(|p| {
+                let name = if p.display_name.is_empty() { &p.name } else { &p.display_name };
+                (p.pid, name.as_str())
+            })
+            .collect();
+
+        // Reuse existing objects by position, like the Users tab
+        let old_count = self.store.n_items() as usize;
+        for (i, conn) in snapshot.connections.iter().enumerate() {
+            let process = names.get(&conn.pid).copied().unwrap_or("");
+            match self.store.item(i as u32).and_downcast::<ConnectionObject>() {
+                Some(obj) => obj.set_from_connection(conn, process),
+                None => {
+                    let obj = ConnectionObject::new();
+                    obj.set_from_connection(conn, process);
+                    self.store.append(&obj);
+                }
+            }
+        }
+        let new_count = snapshot.connections.len();
+        if old_count > new_count {
+            self.store.splice(new_count as u32, (old_count - new_count) as u32, &[] as &[ConnectionObject]);
+        }
+        self.store.items_changed(0, old_count.min(new_count) as u32, old_count.min(new_count) as u32);
+
+        self.count_label.set_text(&format!("{} sockets", new_count));
+    }
+}
@@ -0,0 +1,117 @@
+use gtk4 as gtk;
+use gtk::gio;
+use gtk::glib::prelude::*;
+
+use crate::model::SystemSnapshot;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Small control interface for scripts and the shortcut daemon, exported on the
+// application's session bus connection like the tray icon.
+pub const SERVICE_NAME: &str = "org.task_manager.Linux";
+pub const SERVICE_PATH: &str = "/org/task_manager/Linux";
+
+const INTROSPECTION_XML: &str = r#"
+<node>
+  <interface name="org.task_manager.Linux">
+    <method name="Show"/>
+    <method name="Quit"/>
+    <method name="GetTopProcesses">
+      <arg name="n" type="u" direction="in"/>
+      <arg name="processes" type="a(isdt)" direction="out"/>
+    </method>
+  </interface>
+</node>
+"#;
+
+/// (pid, name, cpu percent, memory bytes), busiest first
+type TopProcess = (i32, String, f64, u64);
+
+pub struct DbusService {
+    top: Rc<RefCell<Vec<TopProcess>>>,
+}
+
+impl DbusService {
+    /// Export the interface on `connection` and claim the well-known name.
+    pub fn new(
+        connection: &gio::DBusConnection,
+        on_show: impl Fn() + 'static,
+        on_quit: impl Fn() + 'static,
+    ) -> Result<Self, String> {
+        let node = gio::DBusNodeInfo::for_xml(INTROSPECTION_XML).map_err(|e| e.to_string())?;
+        let iface = node.lookup_interface(SERVICE_NAME)
+            .ok_or_else(|| format!("Missing {} introspection data", SERVICE_NAME))?;
+
+        let top: Rc<RefCell<Vec<TopProcess>>> = Rc::new(RefCell::new(Vec::new()));
+        let top_for_call = top.clone();
+        connection
+            .register_object(SERVICE_PATH, &iface)
+            .method_call(move |_, _, _, _, method, params, invocation| {
+                let reply = match method {
+                    "Show" => {
+                        on_show();
+                        None
+                    }
+                    "Quit" => {
+                        on_quit();
+                        None
+                    }
+                    "GetTopProcesses" => {
+                        let (n,) = params.get::<(u32,)>().unwrap_or_default();
+                        let top = top_for_call.borrow();
+                        let list: Vec<TopProcess> = top.iter().take(n as usize).cloned().collect();
+                        Some((list,).to_variant())
+                    }
+                    _ => None,
+                };
+                invocation.return_value(reply.as_ref());
+            })
+            .build()
+            .map_err(|e| format!("Failed to export {}: {}", SERVICE_PATH, e))?;
+
+        gio::bus_own_name_on_connection(
+            connection,
+            SERVICE_NAME,
+            gio::BusNameOwnerFlags::NONE,
+            |_, name| log::info!("Acquired D-Bus name {}", name),
+            |_, name| log::warn!("Could not acquire D-Bus name {}", name),
+        );
+
+        Ok(Self { top })
+    }
+
+    /// Refresh the process ranking served by GetTopProcesses.
+    pub fn update(&self, snapshot: &SystemSnapshot) {
+        let mut top: Vec<TopProcess> = snapshot
+            .processes
+            .iter()
+            .map(|p| {
+                let name = if p.display_name.is_empty() { &p.name } else { &p.display_name };
+                (p.pid, name.clone(), p.cpu_percent, p.memory_bytes)
+            })
+            .collect();
+        top.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+        *self.top.borrow_mut() = top;
+    }
+}
+
+/// Ask a running instance to show its window. Used by the shortcut daemon,
+/// which has no GTK main loop of its own.
+pub fn show_running_instance() -> Result<(), String> {
+    let connection = gio::bus_get_sync(gio::BusType::Session, None::<&gio::Cancellable>)
+        .map_err(|e| e.to_string())?;
+    connection
+        .call_sync(
+            Some(SERVICE_NAME),
+            SERVICE_PATH,
+            SERVICE_NAME,
+            "Show",
+            None,
+            None,
+            gio::DBusCallFlags::NO_AUTO_START,
+            1000,
+            None::<&gio::Cancellable>,
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
@@ -1,14 +1,117 @@
 use gtk4 as gtk;
 use gtk::prelude::*;
 use gtk::glib;
-use std::cell::RefCell;
+use libadwaita as adw;
+use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
 use std::f64::consts::PI;
+use std::path::Path;
 use std::rc::Rc;
+use std::time::Instant;
 
-const POINTS_1MIN: usize = 60;    // 1 sample/sec for 1 minute
-const POINTS_5MIN: usize = 300;   // 1 sample/sec for 5 minutes
-const POINTS_30MIN: usize = 1800; // 1 sample/sec for 30 minutes
+use crate::config::{Config, GraphTheme};
+
+const WINDOW_1MIN: usize = 60;    // seconds
+const WINDOW_5MIN: usize = 300;   // seconds
+const WINDOW_30MIN: usize = 1800; // seconds
+
+/// Settings shared by a set of graphs, which follow changes on their next
+/// sample or redraw.
+#[derive(Clone)]
+pub struct GraphSettings {
+    // Collector refresh interval, so time windows map to the right number of samples
+    sample_interval_ms: Rc<Cell<u64>>,
+    // Time window newly created graphs start with
+    window_secs: Rc<Cell<usize>>,
+    // Draw series as smooth curves instead of straight segments between samples
+    smooth: Rc<Cell<bool>>,
+    // Background and grid colors
+    theme: Rc<Cell<GraphTheme>>,
+}
+
+impl GraphSettings {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            sample_interval_ms: Rc::new(Cell::new(config.refresh_interval_ms.max(1))),
+            window_secs: Rc::new(Cell::new(config.graph_window_secs)),
+            smooth: Rc::new(Cell::new(config.smooth_graphs)),
+            theme: Rc::new(Cell::new(config.graph_theme)),
+        }
+    }
+
+    pub fn sample_interval_ms(&self) -> u64 {
+        self.sample_interval_ms.get()
+    }
+
+    /// Tell the graphs how far apart pushed samples are.
+    pub fn set_sample_interval_ms(&self, ms: u64) {
+        self.sample_interval_ms.set(ms.max(1));
+    }
+
+    /// Set the time window (60, 300 or 1800 seconds) for graphs created from now on.
+    pub fn set_default_time_window(&self, secs: usize) {
+        self.window_secs.set(secs);
+    }
+
+    /// Switch between smooth curves and exact points; applies on the next redraw.
+    pub fn set_smooth_curves(&self, smooth: bool) {
+        self.smooth.set(smooth);
+    }
+
+    /// Choose light or dark graph colors, or follow the desktop style; applies on the next redraw.
+    pub fn set_graph_theme(&self, theme: GraphTheme) {
+        self.theme.set(theme);
+    }
+
+    /// Colors for the theme, resolving "follow the desktop style".
+    fn palette(&self) -> &'static Palette {
+        let dark = match self.theme.get() {
+            GraphTheme::Light => false,
+            GraphTheme::Dark => true,
+            GraphTheme::System => adw::StyleManager::default().is_dark(),
+        };
+        if dark { &DARK_PALETTE } else { &LIGHT_PALETTE }
+    }
+
+    /// Number of samples that cover `secs` at the current refresh interval.
+    fn points_for(&self, secs: usize) -> usize {
+        ((secs as u64 * 1000 / self.sample_interval_ms()) as usize).max(2)
+    }
+}
+
+type Rgba = (f64, f64, f64, f64);
+
+/// Colors of everything drawn around the series.
+struct Palette {
+    background: Rgba,
+    grid: Rgba,
+    guide: Rgba,
+    tooltip_background: Rgba,
+    tooltip_header: Rgba,
+    tooltip_text: Rgba,
+}
+
+const DARK_PALETTE: Palette = Palette {
+    background: (0.1, 0.1, 0.12, 1.0),
+    grid: (0.25, 0.25, 0.28, 1.0),
+    guide: (0.85, 0.85, 0.9, 0.6),
+    tooltip_background: (0.05, 0.05, 0.07, 0.9),
+    tooltip_header: (0.7, 0.7, 0.75, 1.0),
+    tooltip_text: (0.92, 0.92, 0.95, 1.0),
+};
+
+const LIGHT_PALETTE: Palette = Palette {
+    background: (0.98, 0.98, 0.99, 1.0),
+    grid: (0.82, 0.82, 0.85, 1.0),
+    guide: (0.3, 0.3, 0.35, 0.6),
+    tooltip_background: (1.0, 1.0, 1.0, 0.95),
+    tooltip_header: (0.4, 0.4, 0.45, 1.0),
+    tooltip_text: (0.12, 0.12, 0.15, 1.0),
+};
+
+fn set_source(cr: &gtk::cairo::Context, (r, g, b, a): Rgba) {
+    cr.set_source_rgba(r, g, b, a);
+}
 
 #[derive(Clone)]
 pub struct GraphColor {
@@ -23,53 +126,113 @@ impl GraphColor {
     }
 }
 
+/// Paints the graph into a context of the given size, with an optional hover position.
+type DrawFn = Rc<dyn Fn(&gtk::cairo::Context, f64, f64, Option<(f64, f64)>)>;
+
+#[derive(Clone)]
 pub struct GraphWidget {
     pub widget: gtk::Overlay,
-    drawing_area: gtk::DrawingArea,
+    time_selector: gtk::Box,
     data: Rc<RefCell<Vec<VecDeque<f64>>>>,
+    // When each sample was pushed, oldest first (shared by all series)
+    times: Rc<RefCell<VecDeque<Instant>>>,
     colors: Rc<RefCell<Vec<GraphColor>>>,
     labels: Rc<RefCell<Vec<String>>>,
     max_value: Rc<RefCell<f64>>,
+    // Per-series scale and visibility overrides (secondary axes such as temperature)
+    series_max: Rc<RefCell<Vec<Option<f64>>>>,
+    series_hidden: Rc<RefCell<Vec<bool>>>,
     title: Rc<RefCell<String>>,
-    window_size: Rc<RefCell<usize>>,
+    window_secs: Rc<RefCell<usize>>,
+    // How each series' value reads in the hover tooltip
+    formats: Rc<RefCell<Vec<Option<Rc<dyn Fn(f64) -> String>>>>>,
+    // Every drawing area showing this data (the graph itself plus its mirrors)
+    areas: Rc<RefCell<Vec<glib::WeakRef<gtk::DrawingArea>>>>,
+    settings: GraphSettings,
+    area: gtk::DrawingArea,
+    draw: DrawFn,
 }
 
 impl GraphWidget {
-    pub fn new(width: i32, height: i32) -> Self {
-        let data: Rc<RefCell<Vec<VecDeque<f64>>>> = Rc::new(RefCell::new(Vec::new()));
-        let colors: Rc<RefCell<Vec<GraphColor>>> = Rc::new(RefCell::new(Vec::new()));
-        let labels: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
-        let max_value: Rc<RefCell<f64>> = Rc::new(RefCell::new(100.0));
-        let title: Rc<RefCell<String>> = Rc::new(RefCell::new(String::new()));
-        let window_size: Rc<RefCell<usize>> = Rc::new(RefCell::new(POINTS_1MIN));
+    pub fn new(width: i32, height: i32, settings: &GraphSettings) -> Self {
+        Self::build(width, height, settings, None)
+    }
+
+    /// Another view of the same graph at a different size. It shares the
+    /// samples, colors and time window, and redraws whenever this graph does.
+    pub fn mirror(&self, width: i32, height: i32) -> Self {
+        let mirror = Self::build(width, height, &self.settings, Some(self));
+        mirror.set_time_selector_visible(false);
+        mirror
+    }
+
+    fn build(width: i32, height: i32, settings: &GraphSettings, source: Option<&GraphWidget>) -> Self {
+        let (data, times, colors, labels, max_value, series_max, series_hidden, title, window_secs, formats, areas) =
+            match source {
+                Some(src) => (
+                    src.data.clone(),
+                    src.times.clone(),
+                    src.colors.clone(),
+                    src.labels.clone(),
+                    src.max_value.clone(),
+                    src.series_max.clone(),
+                    src.series_hidden.clone(),
+                    src.title.clone(),
+                    src.window_secs.clone(),
+                    src.formats.clone(),
+                    src.areas.clone(),
+                ),
+                None => (
+                    Rc::new(RefCell::new(Vec::new())),
+                    Rc::new(RefCell::new(VecDeque::new())),
+                    Rc::new(RefCell::new(Vec::new())),
+                    Rc::new(RefCell::new(Vec::new())),
+                    Rc::new(RefCell::new(100.0)),
+                    Rc::new(RefCell::new(Vec::new())),
+                    Rc::new(RefCell::new(Vec::new())),
+                    Rc::new(RefCell::new(String::new())),
+                    Rc::new(RefCell::new(settings.window_secs.get())),
+                    Rc::new(RefCell::new(Vec::new())),
+                    Rc::new(RefCell::new(Vec::new())),
+                ),
+            };
+        let default_secs = *window_secs.borrow();
+        // Pointer position while hovering the graph
+        let hover: Rc<Cell<Option<(f64, f64)>>> = Rc::new(Cell::new(None));
 
         let area = gtk::DrawingArea::new();
         area.set_content_width(width);
         area.set_content_height(height);
         area.add_css_class("graph-area");
+        areas.borrow_mut().push(area.downgrade());
 
         let data_c = data.clone();
+        let times_c = times.clone();
         let colors_c = colors.clone();
         let max_c = max_value.clone();
-        let window_c = window_size.clone();
-
-        area.set_draw_func(move |_area, cr, w, h| {
-            let w = w as f64;
-            let h = h as f64;
+        let series_max_c = series_max.clone();
+        let series_hidden_c = series_hidden.clone();
+        let window_c = window_secs.clone();
+        let labels_c = labels.clone();
+        let formats_c = formats.clone();
+        let settings_c = settings.clone();
+
+        let draw: DrawFn = Rc::new(move |cr, w, h, hover| {
             let margin_left = 0.0;
             let margin_right = 4.0;
             let margin_top = 4.0;
             let margin_bottom = 4.0;
             let gw = w - margin_left - margin_right;
             let gh = h - margin_top - margin_bottom;
+            let palette = settings_c.palette();
 
             // Background
-            cr.set_source_rgba(0.1, 0.1, 0.12, 1.0);
+            set_source(cr, palette.background);
             rounded_rect(cr, 0.0, 0.0, w, h, 6.0);
             let _ = cr.fill();
 
             // Grid lines
-            cr.set_source_rgba(0.25, 0.25, 0.28, 1.0);
+            set_source(cr, palette.grid);
             cr.set_line_width(0.5);
             for i in 1..4 {
                 let y = margin_top + gh * (i as f64 / 4.0);
@@ -87,26 +250,37 @@ impl GraphWidget {
             // Draw data lines
             let data = data_c.borrow();
             let colors = colors_c.borrow();
-            let max = *max_c.borrow();
-            let max_points = *window_c.borrow();
+            let default_max = *max_c.borrow();
+            let series_max = series_max_c.borrow();
+            let series_hidden = series_hidden_c.borrow();
+            let max_points = settings_c.points_for(*window_c.borrow());
+            let smooth = settings_c.smooth.get();
 
             for (series_idx, series) in data.iter().enumerate() {
-                if series.is_empty() {
+                if series.is_empty() || series_hidden.get(series_idx).copied().unwrap_or(false) {
                     continue;
                 }
+                let max = series_max.get(series_idx).copied().flatten().unwrap_or(default_max);
                 let color = colors.get(series_idx).cloned().unwrap_or(GraphColor::new(0.3, 0.6, 1.0));
 
-                let n = series.len();
+                let n = series.len().min(max_points);
+                let series = series.iter().skip(series.len() - n);
                 let step = gw / (max_points as f64 - 1.0);
 
+                let points: Vec<(f64, f64)> = series
+                    .enumerate()
+                    .map(|(i, &val)| {
+                        let x = margin_left + (max_points - n + i) as f64 * step;
+                        (x, margin_top + gh - (val / max) * gh)
+                    })
+                    .collect();
+                let (first_x, first_y) = points[0];
+
                 // Fill area under curve
                 cr.set_source_rgba(color.r, color.g, color.b, 0.15);
-                cr.move_to(margin_left + (max_points - n) as f64 * step, margin_top + gh);
-                for (i, &val) in series.iter().enumerate() {
-                    let x = margin_left + (max_points - n + i) as f64 * step;
-                    let y = margin_top + gh - (val / max) * gh;
-                    cr.line_to(x, y);
-                }
+                cr.move_to(first_x, margin_top + gh);
+                cr.line_to(first_x, first_y);
+                trace_series(cr, &points, smooth, (margin_top, margin_top + gh));
                 cr.line_to(margin_left + (max_points - 1) as f64 * step, margin_top + gh);
                 cr.close_path();
                 let _ = cr.fill();
@@ -114,18 +288,130 @@ impl GraphWidget {
                 // Line
                 cr.set_source_rgba(color.r, color.g, color.b, 0.9);
                 cr.set_line_width(1.5);
-                for (i, &val) in series.iter().enumerate() {
-                    let x = margin_left + (max_points - n + i) as f64 * step;
-                    let y = margin_top + gh - (val / max) * gh;
-                    if i == 0 {
-                        cr.move_to(x, y);
+                cr.move_to(first_x, first_y);
+                trace_series(cr, &points, smooth, (margin_top, margin_top + gh));
+                let _ = cr.stroke();
+            }
+
+            if let Some((hx, hy)) = hover {
+                let step = gw / (max_points as f64 - 1.0);
+                let slot = ((hx - margin_left) / step).round().clamp(0.0, max_points as f64 - 1.0) as usize;
+
+                // Value of each visible series at the hovered sample
+                let labels = labels_c.borrow();
+                let formats = formats_c.borrow();
+                let mut lines: Vec<(GraphColor, String)> = Vec::new();
+                for (series_idx, series) in data.iter().enumerate() {
+                    if series_hidden.get(series_idx).copied().unwrap_or(false) {
+                        continue;
+                    }
+                    let n = series.len().min(max_points);
+                    if slot < max_points - n {
+                        continue;
+                    }
+                    let val = series[series.len() - n + slot - (max_points - n)];
+                    let text = match formats.get(series_idx).cloned().flatten() {
+                        Some(format) => format(val),
+                        None => format!("{:.1}", val),
+                    };
+                    let text = match labels.get(series_idx) {
+                        Some(label) if !label.is_empty() => format!("{}: {}", label, text),
+                        _ => text,
+                    };
+                    let color = colors.get(series_idx).cloned().unwrap_or(GraphColor::new(0.3, 0.6, 1.0));
+                    lines.push((color, text));
+                }
+
+                if !lines.is_empty() {
+                    // Guide line
+                    let x = margin_left + slot as f64 * step;
+                    set_source(cr, palette.guide);
+                    cr.set_line_width(1.0);
+                    cr.move_to(x, margin_top);
+                    cr.line_to(x, h - margin_bottom);
+                    let _ = cr.stroke();
+
+                    // Age of the hovered sample from when it was taken, so gaps
+                    // (paused or slowed collection) read correctly
+                    let times = times_c.borrow();
+                    let back = max_points - 1 - slot;
+                    let age_ms = match times.len().checked_sub(back + 1) {
+                        Some(idx) => times[idx].elapsed().as_millis() as u64,
+                        None => back as u64 * settings_c.sample_interval_ms(),
+                    };
+                    let header = if age_ms < 500 {
+                        "Now".to_string()
                     } else {
-                        cr.line_to(x, y);
+                        format!("{:.0} s ago", age_ms as f64 / 1000.0)
+                    };
+
+                    cr.select_font_face("Sans", gtk::cairo::FontSlant::Normal, gtk::cairo::FontWeight::Normal);
+                    cr.set_font_size(11.0);
+                    let text_width = |text: &str| cr.text_extents(text).map(|e| e.x_advance()).unwrap_or(0.0);
+                    let swatch = 10.0;
+                    let line_height = 15.0;
+                    let padding = 6.0;
+                    let content_width = lines
+                        .iter()
+                        .map(|(_, text)| swatch + text_width(text))
+                        .fold(text_width(&header), f64::max);
+                    let box_w = content_width + padding * 2.0;
+                    let box_h = line_height * (lines.len() + 1) as f64 + padding * 2.0 - 4.0;
+
+                    // Keep the box beside the guide line and inside the graph
+                    let mut box_x = x + 8.0;
+                    if box_x + box_w > w {
+                        box_x = x - 8.0 - box_w;
+                    }
+                    let box_x = box_x.max(0.0);
+                    let box_y = (hy - box_h / 2.0).clamp(0.0, (h - box_h).max(0.0));
+
+                    set_source(cr, palette.tooltip_background);
+                    rounded_rect(cr, box_x, box_y, box_w, box_h, 4.0);
+                    let _ = cr.fill();
+
+                    let mut y = box_y + padding + 10.0;
+                    set_source(cr, palette.tooltip_header);
+                    cr.move_to(box_x + padding, y);
+                    let _ = cr.show_text(&header);
+                    for (color, text) in &lines {
+                        y += line_height;
+                        cr.set_source_rgba(color.r, color.g, color.b, 1.0);
+                        cr.rectangle(box_x + padding, y - 7.0, 6.0, 6.0);
+                        let _ = cr.fill();
+                        set_source(cr, palette.tooltip_text);
+                        cr.move_to(box_x + padding + swatch, y);
+                        let _ = cr.show_text(text);
                     }
                 }
-                let _ = cr.stroke();
             }
         });
+        let draw_c = draw.clone();
+        let hover_c = hover.clone();
+        area.set_draw_func(move |_area, cr, w, h| draw_c(cr, w as f64, h as f64, hover_c.get()));
+
+        // Repaint right away when the desktop switches between light and dark
+        let area_weak = area.downgrade();
+        adw::StyleManager::default().connect_dark_notify(move |_| {
+            if let Some(area) = area_weak.upgrade() {
+                area.queue_draw();
+            }
+        });
+
+        let motion = gtk::EventControllerMotion::new();
+        let hover_m = hover.clone();
+        let area_m = area.clone();
+        motion.connect_motion(move |_, x, y| {
+            hover_m.set(Some((x, y)));
+            area_m.queue_draw();
+        });
+        let hover_m = hover.clone();
+        let area_m = area.clone();
+        motion.connect_leave(move |_| {
+            hover_m.set(None);
+            area_m.queue_draw();
+        });
+        area.add_controller(motion);
 
         // Create overlay to hold drawing area and dropdown
         let overlay = gtk::Overlay::new();
@@ -140,23 +426,30 @@ impl GraphWidget {
 
         let time_options = gtk::StringList::new(&["1 min", "5 min", "30 min"]);
         let dropdown = gtk::DropDown::new(Some(time_options), None::<gtk::Expression>);
-        dropdown.set_selected(0); // Default to 1 min
+        dropdown.set_selected(match default_secs {
+            WINDOW_5MIN => 1,
+            WINDOW_30MIN => 2,
+            _ => 0,
+        });
         dropdown.add_css_class("graph-time-selector");
 
         let data_clone = data.clone();
-        let window_clone = window_size.clone();
-        let area_clone = area.clone();
+        let times_clone = times.clone();
+        let window_clone = window_secs.clone();
+        let areas_clone = areas.clone();
+        let settings_clone = settings.clone();
 
         dropdown.connect_selected_notify(move |dropdown| {
             let selected = dropdown.selected();
-            let new_size = match selected {
-                0 => POINTS_1MIN,
-                1 => POINTS_5MIN,
-                2 => POINTS_30MIN,
-                _ => POINTS_1MIN,
+            let new_secs = match selected {
+                0 => WINDOW_1MIN,
+                1 => WINDOW_5MIN,
+                2 => WINDOW_30MIN,
+                _ => WINDOW_1MIN,
             };
 
-            *window_clone.borrow_mut() = new_size;
+            *window_clone.borrow_mut() = new_secs;
+            let new_size = settings_clone.points_for(new_secs);
 
             // Truncate data if necessary
             let mut data = data_clone.borrow_mut();
@@ -165,39 +458,172 @@ impl GraphWidget {
                     series.pop_front();
                 }
             }
+            let mut times = times_clone.borrow_mut();
+            while times.len() > new_size {
+                times.pop_front();
+            }
 
-            area_clone.queue_draw();
+            redraw_all(&areas_clone);
         });
 
         dropdown_box.append(&dropdown);
         overlay.add_overlay(&dropdown_box);
 
-        Self {
+        let graph = Self {
             widget: overlay,
-            drawing_area: area,
+            time_selector: dropdown_box,
             data,
+            times,
             colors,
             labels,
             max_value,
+            series_max,
+            series_hidden,
             title,
-            window_size,
+            window_secs,
+            formats,
+            areas,
+            settings: settings.clone(),
+            area,
+            draw,
+        };
+        if source.is_none() {
+            graph.add_context_menu();
+        }
+        graph
+    }
+
+    /// Right-click menu on the graph with "Save Graph as PNG…".
+    fn add_context_menu(&self) {
+        let menu = gtk::gio::Menu::new();
+        menu.append(Some("Save Graph as PNG…"), Some("graph.save-png"));
+        let popover = gtk::PopoverMenu::from_model(Some(&menu));
+        popover.set_parent(&self.widget);
+        popover.set_has_arrow(false);
+
+        let action_group = gtk::gio::SimpleActionGroup::new();
+        let save_action = gtk::gio::SimpleAction::new("save-png", None);
+        let graph = self.clone();
+        save_action.connect_activate(move |_, _| graph.show_save_dialog());
+        action_group.add_action(&save_action);
+        self.widget.insert_action_group("graph", Some(&action_group));
+
+        let gesture = gtk::GestureClick::new();
+        gesture.set_button(3);
+        gesture.connect_pressed(move |gesture, _, x, y| {
+            popover.set_pointing_to(Some(&gtk::gdk::Rectangle::new(x as i32, y as i32, 1, 1)));
+            popover.popup();
+            gesture.set_state(gtk::EventSequenceState::Claimed);
+        });
+        self.area.add_controller(gesture);
+    }
+
+    fn show_save_dialog(&self) {
+        let window = self.widget.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+        let dialog = gtk::FileChooserDialog::new(
+            Some("Save Graph as PNG"),
+            window.as_ref(),
+            gtk::FileChooserAction::Save,
+            &[("Cancel", gtk::ResponseType::Cancel), ("Save", gtk::ResponseType::Accept)],
+        );
+        dialog.set_modal(true);
+        let title = self.title.borrow();
+        let name = if title.is_empty() { "graph" } else { title.as_str() };
+        dialog.set_current_name(&format!("{}.png", name.to_lowercase().replace(' ', "-")));
+
+        let graph = self.clone();
+        dialog.connect_response(move |d, response| {
+            let path = d.file().and_then(|f| f.path());
+            d.close();
+            let (true, Some(mut path)) = (response == gtk::ResponseType::Accept, path) else {
+                return;
+            };
+            if path.extension().is_none() {
+                path.set_extension("png");
+            }
+            if let Err(e) = graph.save_png(&path) {
+                log::error!("Saving graph to {} failed: {}", path.display(), e);
+                let message = gtk::MessageDialog::new(
+                    d.transient_for().as_ref(),
+                    gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+                    gtk::MessageType::Error,
+                    gtk::ButtonsType::Ok,
+                    &format!("Could not save graph:\n\n{}", e),
+                );
+                message.connect_response(|m, _| m.close());
+                message.present();
+            }
+        });
+        dialog.present();
+    }
+
+    /// Render the graph offscreen at its current on-screen size and write it as a PNG.
+    pub fn save_png(&self, path: &Path) -> Result<(), String> {
+        let width = if self.area.width() > 0 { self.area.width() } else { self.area.content_width() };
+        let height = if self.area.height() > 0 { self.area.height() } else { self.area.content_height() };
+        let surface = gtk::cairo::ImageSurface::create(gtk::cairo::Format::ARgb32, width, height)
+            .map_err(|e| e.to_string())?;
+        {
+            let cr = gtk::cairo::Context::new(&surface).map_err(|e| e.to_string())?;
+            (self.draw)(&cr, width as f64, height as f64, None);
         }
+        let mut file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+        surface.write_to_png(&mut file).map_err(|e| e.to_string())
+    }
+
+    /// Name used for the graph, e.g. as the default file name when saving it.
+    pub fn set_title(&self, title: &str) {
+        *self.title.borrow_mut() = title.to_string();
     }
 
     pub fn set_series_count(&self, count: usize, colors: Vec<GraphColor>) {
         let mut data = self.data.borrow_mut();
-        let window_size = *self.window_size.borrow();
+        let window_size = self.settings.points_for(*self.window_secs.borrow());
         data.resize_with(count, || VecDeque::with_capacity(window_size));
         *self.colors.borrow_mut() = colors;
     }
 
+    /// Name the series for the hover tooltip, in series order.
+    pub fn set_labels(&self, labels: &[&str]) {
+        *self.labels.borrow_mut() = labels.iter().map(|l| l.to_string()).collect();
+    }
+
+    /// Format one series' values in the hover tooltip (default: plain number).
+    pub fn set_series_format(&self, series: usize, format: impl Fn(f64) -> String + 'static) {
+        let mut formats = self.formats.borrow_mut();
+        if series >= formats.len() {
+            formats.resize(series + 1, None);
+        }
+        formats[series] = Some(Rc::new(format));
+    }
+
     pub fn set_max_value(&self, max: f64) {
         *self.max_value.borrow_mut() = max;
     }
 
+    /// Give one series its own scale instead of the graph-wide max value.
+    pub fn set_series_max(&self, series: usize, max: f64) {
+        let mut series_max = self.series_max.borrow_mut();
+        if series >= series_max.len() {
+            series_max.resize(series + 1, None);
+        }
+        series_max[series] = Some(max);
+        redraw_all(&self.areas);
+    }
+
+    /// Show or hide one series; hidden series keep collecting samples.
+    pub fn set_series_visible(&self, series: usize, visible: bool) {
+        let mut hidden = self.series_hidden.borrow_mut();
+        if series >= hidden.len() {
+            hidden.resize(series + 1, false);
+        }
+        hidden[series] = !visible;
+        redraw_all(&self.areas);
+    }
+
     pub fn push_values(&self, values: &[f64]) {
         let mut data = self.data.borrow_mut();
-        let window_size = *self.window_size.borrow();
+        let window_size = self.settings.points_for(*self.window_secs.borrow());
         for (i, &val) in values.iter().enumerate() {
             if i >= data.len() {
                 data.push(VecDeque::with_capacity(window_size));
@@ -208,15 +634,90 @@ impl GraphWidget {
                 series.pop_front();
             }
         }
-        self.drawing_area.queue_draw();
+        let mut times = self.times.borrow_mut();
+        times.push_back(Instant::now());
+        if times.len() > window_size {
+            times.pop_front();
+        }
+        redraw_all(&self.areas);
+    }
+
+    /// Drop all collected samples, e.g. when the graph switches to another source.
+    pub fn clear(&self) {
+        for series in self.data.borrow_mut().iter_mut() {
+            series.clear();
+        }
+        self.times.borrow_mut().clear();
+        redraw_all(&self.areas);
+    }
+
+    /// Latest value of every visible series, formatted like the hover tooltip
+    /// (labelled when more than one series is shown).
+    pub fn summary(&self) -> String {
+        let data = self.data.borrow();
+        let hidden = self.series_hidden.borrow();
+        let labels = self.labels.borrow();
+        let formats = self.formats.borrow();
+        let shown: Vec<usize> = (0..data.len())
+            .filter(|&i| !hidden.get(i).copied().unwrap_or(false) && !data[i].is_empty())
+            .collect();
+        shown
+            .iter()
+            .map(|&i| {
+                let val = *data[i].back().unwrap();
+                let text = match formats.get(i).cloned().flatten() {
+                    Some(format) => format(val),
+                    None => format!("{:.1}", val),
+                };
+                match labels.get(i) {
+                    Some(label) if shown.len() > 1 && !label.is_empty() => format!("{} {}", label, text),
+                    _ => text,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+
+    /// Lowest, average and highest value of a series over the visible time
+    /// window (or the samples collected so far, if fewer).
+    pub fn series_stats(&self, series: usize) -> Option<(f64, f64, f64)> {
+        let data = self.data.borrow();
+        let series = data.get(series).filter(|s| !s.is_empty())?;
+        let min = series.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = series.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let avg = series.iter().sum::<f64>() / series.len() as f64;
+        Some((min, avg, max))
+    }
+
+    /// How many seconds the collected samples (and so `series_stats`) cover:
+    /// the whole time window once it is full, else the age of the oldest one.
+    pub fn sampled_secs(&self) -> usize {
+        let window = *self.window_secs.borrow();
+        let times = self.times.borrow();
+        if times.len() >= self.settings.points_for(window) {
+            return window;
+        }
+        times.front().map_or(0, |t| (t.elapsed().as_secs_f64().round() as usize).min(window))
+    }
+
+    /// Visible time window in seconds.
+    pub fn time_window(&self) -> usize {
+        *self.window_secs.borrow()
     }
 
     pub fn push_single(&self, value: f64) {
         self.push_values(&[value]);
     }
 
-    pub fn set_time_window(&self, points: usize) {
-        *self.window_size.borrow_mut() = points;
+    /// Show or hide the time window dropdown (hidden for small sparklines).
+    pub fn set_time_selector_visible(&self, visible: bool) {
+        self.time_selector.set_visible(visible);
+    }
+
+    /// Set the visible time window in seconds.
+    pub fn set_time_window(&self, secs: usize) {
+        *self.window_secs.borrow_mut() = secs;
+        let points = self.settings.points_for(secs);
 
         // Truncate data if reducing window size
         let mut data = self.data.borrow_mut();
@@ -225,11 +726,25 @@ impl GraphWidget {
                 series.pop_front();
             }
         }
+        let mut times = self.times.borrow_mut();
+        while times.len() > points {
+            times.pop_front();
+        }
 
-        self.drawing_area.queue_draw();
+        redraw_all(&self.areas);
     }
 }
 
+fn redraw_all(areas: &RefCell<Vec<glib::WeakRef<gtk::DrawingArea>>>) {
+    areas.borrow_mut().retain(|area| match area.upgrade() {
+        Some(area) => {
+            area.queue_draw();
+            true
+        }
+        None => false,
+    });
+}
+
 fn rounded_rect(cr: &gtk::cairo::Context, x: f64, y: f64, w: f64, h: f64, r: f64) {
     cr.new_sub_path();
     cr.arc(x + w - r, y + r, r, -PI / 2.0, 0.0);
@@ -238,3 +753,22 @@ fn rounded_rect(cr: &gtk::cairo::Context, x: f64, y: f64, w: f64, h: f64, r: f64
     cr.arc(x + r, y + r, r, PI, 3.0 * PI / 2.0);
     cr.close_path();
 }
+
+/// Extend the current path (which ends at `points[0]`) through the remaining
+/// points. Smooth mode uses Catmull-Rom segments as cubic Béziers, with the
+/// control points clamped to `y_range` so curves don't overshoot the plot.
+fn trace_series(cr: &gtk::cairo::Context, points: &[(f64, f64)], smooth: bool, y_range: (f64, f64)) {
+    for i in 1..points.len() {
+        let (x2, y2) = points[i];
+        if !smooth {
+            cr.line_to(x2, y2);
+            continue;
+        }
+        let (x0, y0) = points[i.saturating_sub(2)];
+        let (x1, y1) = points[i - 1];
+        let (x3, y3) = points[(i + 1).min(points.len() - 1)];
+        let c1 = (x1 + (x2 - x0) / 6.0, (y1 + (y2 - y0) / 6.0).clamp(y_range.0, y_range.1));
+        let c2 = (x2 - (x3 - x1) / 6.0, (y2 - (y3 - y1) / 6.0).clamp(y_range.0, y_range.1));
+        cr.curve_to(c1.0, c1.1, c2.0, c2.1, x2, y2);
+    }
+}
@@ -1,6 +1,17 @@
 pub mod process_tab;
+pub mod process_filter;
 pub mod performance_tab;
 pub mod graph_widget;
 pub mod startup_tab;
 pub mod services_tab;
+pub mod timers_view;
 pub mod users_tab;
+pub mod connections_tab;
+pub mod tray;
+pub mod preferences;
+pub mod alerts;
+pub mod bulk_signal;
+pub mod run_task;
+pub mod unit_editor;
+pub mod column_sort;
+pub mod dbus_service;
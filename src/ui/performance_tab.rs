@@ -2,25 +2,34 @@ use gtk4 as gtk;
 use gtk::prelude::*;
 use libadwaita as adw;
 
+use crate::backend::psi::PsiResource;
+use crate::backend::system_info::SystemInfo;
+use crate::config::{Config, TemperatureUnit};
 use crate::model::SystemSnapshot;
-use crate::ui::graph_widget::{GraphColor, GraphWidget};
+use crate::ui::graph_widget::{GraphColor, GraphSettings, GraphWidget};
 use crate::util;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 pub struct PerformanceTab {
     pub widget: gtk::Box,
     stack: gtk::Stack,
+    overview_panel: OverviewPanel,
     cpu_panel: CpuPanel,
     memory_panel: MemoryPanel,
     gpu_panel: GpuPanel,
     disk_panel: DiskPanel,
+    storage_panel: StoragePanel,
     network_panel: NetworkPanel,
     battery_panel: BatteryPanel,
     nav_list: gtk::ListBox,
     battery_row_added: bool,
+    // Battery was the saved panel; select it once its row is added
+    restore_battery: bool,
 }
 
 impl PerformanceTab {
-    pub fn new() -> Self {
+    pub fn new(config: Rc<RefCell<Config>>, graph_settings: &GraphSettings) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Horizontal, 0);
 
         // Sub-navigation sidebar
@@ -28,7 +37,7 @@ impl PerformanceTab {
         nav_list.set_selection_mode(gtk::SelectionMode::Single);
         nav_list.add_css_class("perf-sidebar");
 
-        let items = ["CPU", "Memory", "GPU", "Disk", "Network"];
+        let items = ["Overview", "CPU", "Memory", "GPU", "Disk", "Storage", "Network"];
         for name in &items {
             let label = gtk::Label::new(Some(name));
             label.set_halign(gtk::Align::Start);
@@ -51,40 +60,78 @@ impl PerformanceTab {
         stack.set_vexpand(true);
         stack.set_hexpand(true);
 
-        let cpu_panel = CpuPanel::new();
+        let cpu_panel = CpuPanel::new(graph_settings, &config);
         stack.add_named(&cpu_panel.widget, Some("cpu"));
 
-        let memory_panel = MemoryPanel::new();
+        let memory_panel = MemoryPanel::new(graph_settings);
         stack.add_named(&memory_panel.widget, Some("memory"));
 
-        let gpu_panel = GpuPanel::new();
+        let gpu_panel = GpuPanel::new(graph_settings, &config);
         stack.add_named(&gpu_panel.widget, Some("gpu"));
 
-        let disk_panel = DiskPanel::new();
+        let disk_panel = DiskPanel::new(graph_settings);
         stack.add_named(&disk_panel.widget, Some("disk"));
 
-        let network_panel = NetworkPanel::new();
+        let storage_panel = StoragePanel::new();
+        stack.add_named(&storage_panel.widget, Some("storage"));
+
+        let network_panel = NetworkPanel::new(graph_settings);
         stack.add_named(&network_panel.widget, Some("network"));
 
-        let battery_panel = BatteryPanel::new();
+        let battery_panel = BatteryPanel::new(graph_settings);
         stack.add_named(&battery_panel.widget, Some("battery"));
 
+        // Overview reuses the panels' graphs; a tile click selects the panel's row
+        let nav_ref = nav_list.clone();
+        let overview_panel = OverviewPanel::new(
+            &cpu_panel.graph,
+            &memory_panel.graph,
+            &disk_panel.graph,
+            &network_panel.graph,
+            &battery_panel.graph,
+            move |row| {
+                if let Some(row) = nav_ref.row_at_index(row) {
+                    nav_ref.select_row(Some(&row));
+                }
+            },
+        );
+        stack.add_named(&overview_panel.widget, Some("overview"));
+
         let stack_ref = stack.clone();
-        let names = ["cpu", "memory", "gpu", "disk", "network", "battery"];
         nav_list.connect_row_selected(move |_, row| {
             if let Some(row) = row {
                 let idx = row.index() as usize;
-                if let Some(name) = names.get(idx) {
+                if let Some(name) = PANELS.get(idx) {
                     stack_ref.set_visible_child_name(name);
                 }
             }
         });
 
-        // Select first row
-        if let Some(first) = nav_list.row_at_index(0) {
-            nav_list.select_row(Some(&first));
+        // Select the panel viewed last. Battery's row only exists once a
+        // battery shows up, so that one is selected from update().
+        let saved_row = PANELS
+            .iter()
+            .position(|name| *name == config.borrow().performance_panel)
+            .unwrap_or(0);
+        let restore_battery = saved_row == ROW_BATTERY as usize;
+        let initial_row = if restore_battery { 0 } else { saved_row as i32 };
+        if let Some(row) = nav_list.row_at_index(initial_row) {
+            nav_list.select_row(Some(&row));
         }
 
+        // Connected after the initial selection, so that the stand-in for
+        // Battery isn't saved over it
+        nav_list.connect_row_selected(move |_, row| {
+            let Some(name) = row.and_then(|row| PANELS.get(row.index() as usize)) else {
+                return;
+            };
+            let mut cfg = config.borrow_mut();
+            if cfg.performance_panel != *name {
+                cfg.performance_panel = name.to_string();
+                cfg.save();
+            }
+        });
+
         widget.append(&nav_scroll);
         widget.append(&gtk::Separator::new(gtk::Orientation::Vertical));
         widget.append(&stack);
@@ -92,24 +139,45 @@ impl PerformanceTab {
         Self {
             widget,
             stack,
+            overview_panel,
             cpu_panel,
             memory_panel,
             gpu_panel,
             disk_panel,
+            storage_panel,
             network_panel,
             battery_panel,
             nav_list,
             battery_row_added: false,
+            restore_battery,
+        }
+    }
+
+    /// Select a panel by its PANELS name ("cpu", "memory", ...).
+    pub fn show_panel(&self, name: &str) {
+        let row = PANELS.iter().position(|panel| *panel == name);
+        if let Some(row) = row.and_then(|i| self.nav_list.row_at_index(i as i32)) {
+            self.nav_list.select_row(Some(&row));
         }
     }
 
     pub fn update(&mut self, snapshot: &SystemSnapshot) {
         self.cpu_panel.update(&snapshot.cpu);
         self.memory_panel.update(&snapshot.memory);
-        self.gpu_panel.update(snapshot.gpu.first().unwrap_or(&crate::model::GpuInfo::default()));
+        let psi = snapshot.psi.available.then_some(&snapshot.psi);
+        update_pressure_label(&self.cpu_panel.pressure_label, psi.map(|p| &p.cpu), "CPU time", false);
+        update_pressure_label(&self.memory_panel.pressure_label, psi.map(|p| &p.memory), "memory", true);
+        update_pressure_label(&self.disk_panel.pressure_label, psi.map(|p| &p.io), "I/O", true);
+        self.gpu_panel.update(&snapshot.gpu, snapshot.primary_gpu_index(), &snapshot.processes);
         self.disk_panel.update(&snapshot.disk);
+        self.storage_panel.update(&snapshot.disk.filesystems);
         self.network_panel.update(&snapshot.network);
         self.battery_panel.update(&snapshot.battery);
+        self.overview_panel.update(
+            self.gpu_panel.selected_graph(),
+            snapshot.battery.available,
+            snapshot.cpu.uptime_secs,
+        );
 
         // Dynamically add Battery row to nav when battery is detected
         if snapshot.battery.available && !self.battery_row_added {
@@ -121,6 +189,209 @@ impl PerformanceTab {
             label.set_margin_end(12);
             self.nav_list.append(&label);
             self.battery_row_added = true;
+            // Unless another panel was picked in the meantime
+            let untouched = self.nav_list.selected_row().is_some_and(|row| row.index() == 0);
+            if self.restore_battery && untouched {
+                if let Some(row) = self.nav_list.row_at_index(ROW_BATTERY) {
+                    self.nav_list.select_row(Some(&row));
+                }
+            }
+        }
+    }
+}
+
+// ── Overview Panel ────────────────────────────────────────
+
+/// Stack page of each sidebar row, also the names saved in the config
+const PANELS: [&str; 8] = ["overview", "cpu", "memory", "gpu", "disk", "storage", "network", "battery"];
+
+// Rows of the panels in the sidebar, for the tiles' click-through
+const ROW_CPU: i32 = 1;
+const ROW_MEMORY: i32 = 2;
+const ROW_GPU: i32 = 3;
+const ROW_DISK: i32 = 4;
+const ROW_NETWORK: i32 = 6;
+const ROW_BATTERY: i32 = 7;
+
+// Positions of the optional tiles in OverviewPanel::tiles
+const TILE_GPU: usize = 4;
+const TILE_BATTERY: usize = 5;
+
+const TILE_GRAPH_WIDTH: i32 = 280;
+const TILE_GRAPH_HEIGHT: i32 = 90;
+
+/// Every graph on one page, as small mirrors of the panels' own graphs,
+/// below a summary of the system itself.
+struct OverviewPanel {
+    widget: gtk::ScrolledWindow,
+    grid: gtk::Grid,
+    uptime_label: gtk::Label,
+    // In display order; GPU and Battery are hidden until present
+    tiles: Vec<OverviewTile>,
+}
+
+struct OverviewTile {
+    widget: gtk::Box,
+    value_label: gtk::Label,
+    graph_box: gtk::Box,
+    // Full-size graph the mirror shows, compared to spot a rebuilt GPU graph
+    source: Option<GraphWidget>,
+    mirror: Option<GraphWidget>,
+}
+
+impl OverviewPanel {
+    fn new(
+        cpu: &GraphWidget,
+        memory: &GraphWidget,
+        disk: &GraphWidget,
+        network: &GraphWidget,
+        battery: &GraphWidget,
+        on_open: impl Fn(i32) + 'static,
+    ) -> Self {
+        let on_open = Rc::new(on_open);
+        let tiles: Vec<OverviewTile> = [
+            ("CPU", Some(cpu), ROW_CPU),
+            ("Memory", Some(memory), ROW_MEMORY),
+            ("Disk", Some(disk), ROW_DISK),
+            ("Network", Some(network), ROW_NETWORK),
+            ("GPU", None, ROW_GPU),
+            ("Battery", Some(battery), ROW_BATTERY),
+        ]
+        .into_iter()
+        .map(|(title, graph, row)| {
+            let on_open = on_open.clone();
+            let mut tile = OverviewTile::new(title, move || on_open(row));
+            tile.set_graph(graph);
+            tile
+        })
+        .collect();
+
+        let grid = gtk::Grid::new();
+        grid.set_row_spacing(16);
+        grid.set_column_spacing(16);
+        grid.set_column_homogeneous(true);
+
+        // System summary
+        let info = SystemInfo::read();
+        let system_title = gtk::Label::new(Some("System"));
+        system_title.add_css_class("heading");
+        system_title.set_halign(gtk::Align::Start);
+        let info_grid = gtk::Grid::new();
+        info_grid.set_row_spacing(6);
+        info_grid.set_column_spacing(24);
+        let kernel_label = gtk::Label::new(Some(&info.kernel));
+        kernel_label.set_tooltip_text(Some(&info.kernel_version));
+        let boot_text = gtk::glib::DateTime::from_unix_local(info.boot_time as i64)
+            .and_then(|dt| dt.format("%Y-%m-%d %H:%M:%S"))
+            .map(|s| s.to_string())
+            .unwrap_or_else(|_| "—".to_string());
+        let uptime_label = gtk::Label::new(Some("0m"));
+        add_info_row(&info_grid, 0, "Hostname", &gtk::Label::new(Some(&info.hostname)));
+        add_info_row(&info_grid, 1, "Distribution", &gtk::Label::new(Some(&info.distro)));
+        add_info_row(&info_grid, 2, "Kernel", &kernel_label);
+        add_info_row(&info_grid, 3, "Boot Time", &gtk::Label::new(Some(&boot_text)));
+        add_info_row(&info_grid, 4, "Uptime", &uptime_label);
+        let system_box = gtk::Box::new(gtk::Orientation::Vertical, 8);
+        system_box.append(&system_title);
+        system_box.append(&info_grid);
+
+        let content = gtk::Box::new(gtk::Orientation::Vertical, 16);
+        content.set_margin_top(16);
+        content.set_margin_start(16);
+        content.set_margin_end(16);
+        content.set_margin_bottom(16);
+        content.append(&system_box);
+        content.append(&grid);
+
+        let widget = gtk::ScrolledWindow::builder()
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .child(&content)
+            .build();
+
+        let mut panel = Self { widget, grid, uptime_label, tiles };
+        panel.tiles[TILE_GPU].widget.set_visible(false);
+        panel.tiles[TILE_BATTERY].widget.set_visible(false);
+        panel.layout();
+        panel
+    }
+
+    fn update(&mut self, gpu: Option<&GraphWidget>, battery_available: bool, uptime_secs: u64) {
+        self.uptime_label.set_text(&util::format_duration(uptime_secs));
+        let gpu_changed = {
+            let tile = &mut self.tiles[TILE_GPU];
+            let changed = tile.source.as_ref().map(|g| &g.widget) != gpu.map(|g| &g.widget);
+            if changed {
+                tile.set_graph(gpu);
+            }
+            changed
+        };
+        let battery_changed = self.tiles[TILE_BATTERY].widget.is_visible() != battery_available;
+        if gpu_changed || battery_changed {
+            self.tiles[TILE_GPU].widget.set_visible(gpu.is_some());
+            self.tiles[TILE_BATTERY].widget.set_visible(battery_available);
+            self.layout();
+        }
+
+        for tile in &self.tiles {
+            if let Some(source) = &tile.source {
+                tile.value_label.set_text(&source.summary());
+            }
+        }
+    }
+
+    /// Place the visible tiles two per row, without gaps for hidden ones.
+    fn layout(&mut self) {
+        while let Some(child) = self.grid.first_child() {
+            self.grid.remove(&child);
+        }
+        for (i, tile) in self.tiles.iter().filter(|t| t.widget.is_visible()).enumerate() {
+            self.grid.attach(&tile.widget, (i % 2) as i32, (i / 2) as i32, 1, 1);
+        }
+    }
+}
+
+impl OverviewTile {
+    fn new(title: &str, on_click: impl Fn() + 'static) -> Self {
+        let widget = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        widget.set_cursor_from_name(Some("pointer"));
+        widget.set_tooltip_text(Some(&format!("Open the {} page", title)));
+
+        let title_label = gtk::Label::new(Some(title));
+        title_label.add_css_class("heading");
+        title_label.set_halign(gtk::Align::Start);
+        title_label.set_hexpand(true);
+
+        let value_label = gtk::Label::new(None);
+        value_label.add_css_class("dim-label");
+        value_label.set_halign(gtk::Align::End);
+        value_label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        header.append(&title_label);
+        header.append(&value_label);
+
+        let graph_box = gtk::Box::new(gtk::Orientation::Vertical, 0);
+
+        widget.append(&header);
+        widget.append(&graph_box);
+
+        let click = gtk::GestureClick::new();
+        click.connect_released(move |_, _, _, _| on_click());
+        widget.add_controller(click);
+
+        Self { widget, value_label, graph_box, source: None, mirror: None }
+    }
+
+    fn set_graph(&mut self, graph: Option<&GraphWidget>) {
+        if let Some(mirror) = self.mirror.take() {
+            self.graph_box.remove(&mirror.widget);
+        }
+        self.value_label.set_text("");
+        self.source = graph.cloned();
+        if let Some(graph) = graph {
+            let mirror = graph.mirror(TILE_GRAPH_WIDTH, TILE_GRAPH_HEIGHT);
+            self.graph_box.append(&mirror.widget);
+            self.mirror = Some(mirror);
         }
     }
 }
@@ -130,17 +401,30 @@ impl PerformanceTab {
 struct CpuPanel {
     widget: gtk::Box,
     graph: GraphWidget,
+    graph_stack: gtk::Stack,
+    cores_grid: gtk::Grid,
+    core_graphs: Vec<(GraphWidget, gtk::Label)>,
+    graph_settings: GraphSettings,
     title_label: gtk::Label,
     utilization_label: gtk::Label,
     speed_label: gtk::Label,
     temperature_label: gtk::Label,
     cores_label: gtk::Label,
     uptime_label: gtk::Label,
+    pressure_label: gtk::Label,
+    stats_label: gtk::Label,
+    temperature_check: gtk::CheckButton,
+    // Unit the temperature toggle's scale was last written in
+    temperature_unit: TemperatureUnit,
+    // Source of the temperature unit, which Preferences saves into
+    config: Rc<RefCell<Config>>,
+    frequency_check: gtk::CheckButton,
+    peak_frequency_mhz: f64,
     initialized: bool,
 }
 
 impl CpuPanel {
-    fn new() -> Self {
+    fn new(graph_settings: &GraphSettings, config: &Rc<RefCell<Config>>) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 12);
         widget.set_margin_top(16);
         widget.set_margin_start(16);
@@ -150,10 +434,88 @@ impl CpuPanel {
         let title_label = gtk::Label::new(Some("CPU"));
         title_label.add_css_class("perf-label-title");
         title_label.set_halign(gtk::Align::Start);
-
-        let graph = GraphWidget::new(600, 200);
-        graph.set_series_count(1, vec![GraphColor::new(0.2, 0.6, 1.0)]);
+        title_label.set_hexpand(true);
+        title_label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+
+        // Overall / Logical processors switch
+        let mode_box = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+        mode_box.add_css_class("linked");
+        let overall_btn = gtk::ToggleButton::with_label("Overall");
+        let cores_btn = gtk::ToggleButton::with_label("Logical processors");
+        cores_btn.set_group(Some(&overall_btn));
+        overall_btn.set_active(true);
+        mode_box.append(&overall_btn);
+        mode_box.append(&cores_btn);
+
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        header.append(&title_label);
+        header.append(&mode_box);
+
+        // Utilization plus temperature (0–100 °C) and frequency (0–max) on their own scales
+        let graph = GraphWidget::new(600, 200, graph_settings);
+        graph.set_title("CPU");
+        graph.set_series_count(3, vec![
+            GraphColor::new(0.2, 0.6, 1.0), // Utilization
+            GraphColor::new(1.0, 0.45, 0.2), // Temperature
+            GraphColor::new(0.85, 0.8, 0.2), // Frequency
+        ]);
         graph.set_max_value(100.0);
+        graph.set_labels(&["Utilization", "Temperature", "Frequency"]);
+        graph.set_series_format(0, util::format_percent);
+        let config_for_format = config.clone();
+        graph.set_series_format(1, move |t| util::format_temperature(t, config_for_format.borrow().temperature_unit));
+        graph.set_series_format(2, util::format_frequency);
+        graph.set_series_max(1, 100.0);
+        graph.set_series_visible(1, false);
+        graph.set_series_visible(2, false);
+
+        // Average and peak utilization over the selected time window
+        let stats_label = gtk::Label::new(None);
+        stats_label.add_css_class("graph-stats");
+        stats_label.set_halign(gtk::Align::Start);
+        stats_label.set_valign(gtk::Align::Start);
+        stats_label.set_margin_start(8);
+        stats_label.set_margin_top(8);
+        stats_label.set_can_target(false);
+        graph.widget.add_overlay(&stats_label);
+
+        let temperature_check = series_toggle(TEMPERATURE_COLOR, &temperature_scale_text(config.borrow().temperature_unit));
+        let frequency_check = series_toggle("#d9cc33", "Frequency");
+        let series_box = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        series_box.append(&temperature_check);
+        series_box.append(&frequency_check);
+        for (check, series) in [(&temperature_check, 1), (&frequency_check, 2)] {
+            let graph_ref = graph.clone();
+            check.connect_toggled(move |c| graph_ref.set_series_visible(series, c.is_active()));
+        }
+
+        let overall_box = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        overall_box.append(&graph.widget);
+        overall_box.append(&series_box);
+
+        // Per-core sparklines, created on the first update once the core count is known
+        let cores_grid = gtk::Grid::new();
+        cores_grid.set_row_spacing(8);
+        cores_grid.set_column_spacing(8);
+        cores_grid.set_column_homogeneous(true);
+        let cores_scroll = gtk::ScrolledWindow::builder()
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .min_content_height(200)
+            .max_content_height(420)
+            .propagate_natural_height(true)
+            .child(&cores_grid)
+            .build();
+
+        let graph_stack = gtk::Stack::new();
+        graph_stack.set_transition_type(gtk::StackTransitionType::Crossfade);
+        graph_stack.set_vhomogeneous(false);
+        graph_stack.add_named(&overall_box, Some("overall"));
+        graph_stack.add_named(&cores_scroll, Some("cores"));
+
+        let stack_ref = graph_stack.clone();
+        cores_btn.connect_toggled(move |btn| {
+            stack_ref.set_visible_child_name(if btn.is_active() { "cores" } else { "overall" });
+        });
 
         let info_grid = gtk::Grid::new();
         info_grid.set_row_spacing(6);
@@ -164,26 +526,39 @@ impl CpuPanel {
         let temperature_label = gtk::Label::new(Some("N/A"));
         let cores_label = gtk::Label::new(Some("0"));
         let uptime_label = gtk::Label::new(Some("0m"));
+        let pressure_label = gtk::Label::new(Some("N/A"));
 
         add_info_row(&info_grid, 0, "Utilization", &utilization_label);
         add_info_row(&info_grid, 1, "Speed", &speed_label);
         add_info_row(&info_grid, 2, "Temperature", &temperature_label);
         add_info_row(&info_grid, 3, "Cores", &cores_label);
         add_info_row(&info_grid, 4, "Uptime", &uptime_label);
+        add_info_row(&info_grid, 5, "Pressure", &pressure_label);
 
-        widget.append(&title_label);
-        widget.append(&graph.widget);
+        widget.append(&header);
+        widget.append(&graph_stack);
         widget.append(&info_grid);
 
         Self {
             widget,
             graph,
+            graph_stack,
+            cores_grid,
+            core_graphs: Vec::new(),
+            graph_settings: graph_settings.clone(),
             title_label,
             utilization_label,
             speed_label,
             temperature_label,
             cores_label,
             uptime_label,
+            pressure_label,
+            stats_label,
+            temperature_check,
+            temperature_unit: config.borrow().temperature_unit,
+            config: config.clone(),
+            frequency_check,
+            peak_frequency_mhz: 0.0,
             initialized: false,
         }
     }
@@ -192,15 +567,127 @@ impl CpuPanel {
         if !self.initialized && !cpu.model_name.is_empty() {
             self.title_label.set_text(&format!("CPU — {}", cpu.model_name));
             self.cores_label.set_text(&format!("{} cores", cpu.core_count));
+            self.temperature_check.set_sensitive(cpu.temperature_celsius > 0.0);
+            self.frequency_check.set_sensitive(cpu.frequency_mhz > 0.0);
             self.initialized = true;
         }
+        let unit = self.config.borrow().temperature_unit;
+        if unit != self.temperature_unit {
+            self.temperature_unit = unit;
+            if let Some(label) = self.temperature_check.child().and_downcast::<gtk::Label>() {
+                label.set_markup(&series_markup(TEMPERATURE_COLOR, &temperature_scale_text(unit)));
+            }
+        }
+
+        // Frequency is scaled to the hardware maximum, or the highest speed seen
+        // so far when cpufreq doesn't report one
+        let peak = cpu.max_frequency_mhz.max(cpu.frequency_mhz);
+        if peak > self.peak_frequency_mhz {
+            self.peak_frequency_mhz = peak;
+            self.graph.set_series_max(2, peak);
+            self.frequency_check.set_tooltip_text(Some(&format!(
+                "Scale: 0–{}",
+                util::format_frequency(peak)
+            )));
+        }
 
-        self.graph.push_single(cpu.total_percent);
+        self.graph.push_values(&[cpu.total_percent, cpu.temperature_celsius, cpu.frequency_mhz]);
+        if let Some((_, avg, peak)) = self.graph.series_stats(0) {
+            // Name the span the samples really cover, which is shorter than
+            // the window until it fills up
+            let secs = self.graph.sampled_secs();
+            let span = if secs < 60 { format!("{} s", secs) } else { format!("{} min", secs / 60) };
+            self.stats_label.set_text(&format!(
+                "{} — Avg {} · Peak {}",
+                span,
+                util::format_percent(avg),
+                util::format_percent(peak)
+            ));
+        }
+        self.update_cores(cpu);
         self.utilization_label.set_text(&util::format_percent(cpu.total_percent));
         self.speed_label.set_text(&util::format_frequency(cpu.frequency_mhz));
-        self.temperature_label.set_text(&util::format_temperature(cpu.temperature_celsius));
+        self.temperature_label.set_text(&util::format_temperature(cpu.temperature_celsius, self.temperature_unit));
         self.uptime_label.set_text(&util::format_duration(cpu.uptime_secs));
     }
+
+    fn update_cores(&mut self, cpu: &crate::model::CpuInfo) {
+        let count = cpu.per_core_percent.len();
+        if count == 0 {
+            return;
+        }
+
+        if self.core_graphs.len() != count {
+            while let Some(child) = self.cores_grid.first_child() {
+                self.cores_grid.remove(&child);
+            }
+            self.core_graphs.clear();
+
+            // Roughly square layout, like Windows Task Manager
+            let columns = ((count as f64).sqrt().ceil() as usize).max(1);
+            for i in 0..count {
+                let cell = gtk::Box::new(gtk::Orientation::Vertical, 2);
+                let graph = GraphWidget::new(120, 60, &self.graph_settings);
+                graph.set_series_count(1, vec![GraphColor::new(0.2, 0.6, 1.0)]);
+                graph.set_max_value(100.0);
+                graph.set_series_format(0, util::format_percent);
+                graph.set_time_selector_visible(false);
+                let label = gtk::Label::new(Some(&format!("CPU {}", i)));
+                label.set_halign(gtk::Align::Start);
+                label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+                label.add_css_class("dim-label");
+                label.add_css_class("caption");
+                cell.append(&graph.widget);
+                cell.append(&label);
+                self.cores_grid.attach(&cell, (i % columns) as i32, (i / columns) as i32, 1, 1);
+                self.core_graphs.push((graph, label));
+            }
+        }
+
+        let showing_cores = self.graph_stack.visible_child_name().as_deref() == Some("cores");
+        for (i, (graph, label)) in self.core_graphs.iter().enumerate() {
+            let percent = cpu.per_core_percent[i];
+            graph.push_single(percent);
+            if !showing_cores {
+                continue;
+            }
+
+            let mut text = format!("CPU {}  {}", i, util::format_percent(percent));
+            if let Some((mhz, _)) = cpu.per_core_frequencies.get(i) {
+                text.push_str(&format!("  {}", util::format_frequency(*mhz)));
+            }
+            // Sensors usually report per physical core (or per package), so
+            // map logical CPUs onto them round-robin like the kernel numbers SMT siblings
+            if !cpu.per_core_temperatures.is_empty() {
+                let temp = cpu.per_core_temperatures[i % cpu.per_core_temperatures.len()];
+                text.push_str(&format!("  {}", util::format_temperature(temp, self.temperature_unit)));
+            }
+            label.set_text(&text);
+        }
+    }
+}
+
+/// Check button whose label starts with a dot in the series' graph color
+fn series_toggle(color: &str, text: &str) -> gtk::CheckButton {
+    let label = gtk::Label::new(None);
+    label.set_markup(&series_markup(color, text));
+    let check = gtk::CheckButton::new();
+    check.set_child(Some(&label));
+    check
+}
+
+fn series_markup(color: &str, text: &str) -> String {
+    format!("<span foreground=\"{}\">●</span> {}", color, text)
+}
+
+const TEMPERATURE_COLOR: &str = "#ff7333";
+const POWER_COLOR: &str = "#d94dbf";
+
+/// Temperature toggle text with the graph's 0–100 °C scale in `unit`.
+fn temperature_scale_text(unit: TemperatureUnit) -> String {
+    let (low, suffix) = util::convert_temperature(0.0, unit);
+    let (high, _) = util::convert_temperature(100.0, unit);
+    format!("Temperature ({:.0}–{:.0}{})", low, high, suffix)
 }
 
 // ── Memory Panel ──────────────────────────────────────────
@@ -213,11 +700,16 @@ struct MemoryPanel {
     cached_label: gtk::Label,
     swap_label: gtk::Label,
     total_label: gtk::Label,
+    pressure_label: gtk::Label,
+    swap_box: gtk::Box,
+    swap_grid: gtk::Grid,
+    // Device name plus used/size label and usage bar, for in-place updates
+    swap_rows: Vec<(String, gtk::Label, gtk::ProgressBar)>,
     initialized: bool,
 }
 
 impl MemoryPanel {
-    fn new() -> Self {
+    fn new(graph_settings: &GraphSettings) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 12);
         widget.set_margin_top(16);
         widget.set_margin_start(16);
@@ -228,8 +720,11 @@ impl MemoryPanel {
         title.add_css_class("perf-label-title");
         title.set_halign(gtk::Align::Start);
 
-        let graph = GraphWidget::new(600, 200);
+        let graph = GraphWidget::new(600, 200, graph_settings);
+        graph.set_title("Memory");
         graph.set_series_count(1, vec![GraphColor::new(0.6, 0.2, 0.8)]);
+        graph.set_labels(&["In use"]);
+        graph.set_series_format(0, |bytes| util::format_bytes(bytes as u64));
 
         let info_grid = gtk::Grid::new();
         info_grid.set_row_spacing(6);
@@ -240,16 +735,31 @@ impl MemoryPanel {
         let cached_label = gtk::Label::new(Some("0 B"));
         let swap_label = gtk::Label::new(Some("0 B"));
         let total_label = gtk::Label::new(Some("0 B"));
+        let pressure_label = gtk::Label::new(Some("N/A"));
 
         add_info_row(&info_grid, 0, "Used", &used_label);
         add_info_row(&info_grid, 1, "Available", &available_label);
         add_info_row(&info_grid, 2, "Cached", &cached_label);
         add_info_row(&info_grid, 3, "Swap", &swap_label);
         add_info_row(&info_grid, 4, "Total", &total_label);
+        add_info_row(&info_grid, 5, "Pressure", &pressure_label);
+
+        // Per-device swap breakdown (zram vs disk), hidden when there is no swap
+        let swap_box = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        swap_box.set_visible(false);
+        let swap_title = gtk::Label::new(Some("Swap devices"));
+        swap_title.add_css_class("heading");
+        swap_title.set_halign(gtk::Align::Start);
+        let swap_grid = gtk::Grid::new();
+        swap_grid.set_row_spacing(6);
+        swap_grid.set_column_spacing(24);
+        swap_box.append(&swap_title);
+        swap_box.append(&swap_grid);
 
         widget.append(&title);
         widget.append(&graph.widget);
         widget.append(&info_grid);
+        widget.append(&swap_box);
 
         Self {
             widget,
@@ -259,6 +769,10 @@ impl MemoryPanel {
             cached_label,
             swap_label,
             total_label,
+            pressure_label,
+            swap_box,
+            swap_grid,
+            swap_rows: Vec::new(),
             initialized: false,
         }
     }
@@ -279,6 +793,50 @@ impl MemoryPanel {
             util::format_bytes(mem.swap_used),
             util::format_bytes(mem.swap_total)
         ));
+        self.update_swap_devices(&mem.swap_devices);
+    }
+
+    fn update_swap_devices(&mut self, devices: &[crate::model::SwapDevice]) {
+        self.swap_box.set_visible(!devices.is_empty());
+
+        // Rebuild rows only when swap devices are added or removed
+        let same_devices = devices.len() == self.swap_rows.len()
+            && devices.iter().zip(&self.swap_rows).all(|(d, row)| d.name == row.0);
+        if !same_devices {
+            while let Some(child) = self.swap_grid.first_child() {
+                self.swap_grid.remove(&child);
+            }
+            self.swap_rows.clear();
+            for (i, dev) in devices.iter().enumerate() {
+                let row = i as i32;
+                let name = gtk::Label::new(Some(&dev.name));
+                name.set_halign(gtk::Align::Start);
+                name.set_ellipsize(gtk::pango::EllipsizeMode::Middle);
+                let kind = gtk::Label::new(Some(if dev.is_zram { "zram" } else { &dev.swap_type }));
+                kind.set_halign(gtk::Align::Start);
+                kind.add_css_class("dim-label");
+                let bar = gtk::ProgressBar::new();
+                bar.set_hexpand(true);
+                bar.set_valign(gtk::Align::Center);
+                let usage = gtk::Label::new(None);
+                usage.set_halign(gtk::Align::End);
+                self.swap_grid.attach(&name, 0, row, 1, 1);
+                self.swap_grid.attach(&kind, 1, row, 1, 1);
+                self.swap_grid.attach(&bar, 2, row, 1, 1);
+                self.swap_grid.attach(&usage, 3, row, 1, 1);
+                self.swap_rows.push((dev.name.clone(), usage, bar));
+            }
+        }
+
+        for (dev, (_, usage, bar)) in devices.iter().zip(&self.swap_rows) {
+            let fraction = if dev.size > 0 { dev.used as f64 / dev.size as f64 } else { 0.0 };
+            bar.set_fraction(fraction);
+            let mut text = format!("{} / {}", util::format_bytes(dev.used), util::format_bytes(dev.size));
+            if dev.is_zram && dev.compressed > 0 {
+                text.push_str(&format!(" ({} compressed)", util::format_bytes(dev.compressed)));
+            }
+            usage.set_text(&text);
+        }
     }
 }
 
@@ -286,19 +844,41 @@ impl MemoryPanel {
 
 struct GpuPanel {
     widget: gtk::Box,
-    graph: GraphWidget,
+    graph_stack: gtk::Stack,
+    // One graph per GPU so switching keeps each GPU's history
+    graphs: Vec<GraphWidget>,
+    graph_settings: GraphSettings,
+    // Power series scale per GPU: the power limit, or the highest draw seen
+    power_scales: Vec<f64>,
+    gpu_names: Vec<String>,
+    selector: gtk::DropDown,
+    temperature_check: gtk::CheckButton,
+    power_check: gtk::CheckButton,
+    toggle_handlers: Vec<(gtk::CheckButton, gtk::glib::SignalHandlerId)>,
+    // Unit the temperature toggle's scale was last written in
+    temperature_unit: TemperatureUnit,
+    // Source of the temperature unit, which Preferences saves into
+    config: Rc<RefCell<Config>>,
     title_label: gtk::Label,
     utilization_label: gtk::Label,
     vram_label: gtk::Label,
     temp_label: gtk::Label,
     power_label: gtk::Label,
     fan_label: gtk::Label,
+    core_clock_label: gtk::Label,
+    mem_clock_label: gtk::Label,
     no_gpu_label: gtk::Label,
-    initialized: bool,
+    info_grid: gtk::Grid,
+    process_title: gtk::Label,
+    process_list: gtk::ListBox,
+    process_scroll: gtk::ScrolledWindow,
 }
 
+/// Rows in the GPU panel's process list
+const GPU_TOP_PROCESSES: usize = 10;
+
 impl GpuPanel {
-    fn new() -> Self {
+    fn new(graph_settings: &GraphSettings, config: &Rc<RefCell<Config>>) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 12);
         widget.set_margin_top(16);
         widget.set_margin_start(16);
@@ -308,16 +888,22 @@ impl GpuPanel {
         let title_label = gtk::Label::new(Some("GPU"));
         title_label.add_css_class("perf-label-title");
         title_label.set_halign(gtk::Align::Start);
+        title_label.set_hexpand(true);
+        title_label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+
+        let selector = gtk::DropDown::new(Some(gtk::StringList::new(&[])), gtk::Expression::NONE);
+        selector.set_tooltip_text(Some("Select GPU"));
+        selector.set_visible(false);
 
-        let no_gpu_label = gtk::Label::new(Some("No NVIDIA GPU detected"));
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        header.append(&title_label);
+        header.append(&selector);
+
+        let no_gpu_label = gtk::Label::new(Some("No GPU detected"));
         no_gpu_label.set_halign(gtk::Align::Start);
 
-        let graph = GraphWidget::new(600, 200);
-        graph.set_series_count(2, vec![
-            GraphColor::new(0.2, 0.8, 0.4), // Utilization
-            GraphColor::new(0.8, 0.4, 0.2), // VRAM
-        ]);
-        graph.set_max_value(100.0);
+        let graph_stack = gtk::Stack::new();
+        graph_stack.set_transition_type(gtk::StackTransitionType::Crossfade);
 
         let info_grid = gtk::Grid::new();
         info_grid.set_row_spacing(6);
@@ -325,69 +911,267 @@ impl GpuPanel {
 
         let utilization_label = gtk::Label::new(Some("0%"));
         let vram_label = gtk::Label::new(Some("0 B"));
-        let temp_label = gtk::Label::new(Some("0 C"));
+        let temp_label = gtk::Label::new(Some(&util::format_temperature(0.0, config.borrow().temperature_unit)));
         let power_label = gtk::Label::new(Some("0 W"));
         let fan_label = gtk::Label::new(Some("0%"));
+        let core_clock_label = gtk::Label::new(Some("—"));
+        let mem_clock_label = gtk::Label::new(Some("—"));
 
         add_info_row(&info_grid, 0, "Utilization", &utilization_label);
         add_info_row(&info_grid, 1, "VRAM", &vram_label);
         add_info_row(&info_grid, 2, "Temperature", &temp_label);
         add_info_row(&info_grid, 3, "Power", &power_label);
         add_info_row(&info_grid, 4, "Fan Speed", &fan_label);
+        add_info_row(&info_grid, 5, "Core Clock", &core_clock_label);
+        add_info_row(&info_grid, 6, "Memory Clock", &mem_clock_label);
+
+        let stack_ref = graph_stack.clone();
+        selector.connect_selected_notify(move |dd| {
+            stack_ref.set_visible_child_name(&format!("gpu{}", dd.selected()));
+        });
 
-        widget.append(&title_label);
+        // Temperature and power draw on their own scales, hidden until asked
+        // for. The toggles apply to every GPU's graph.
+        let temperature_check = series_toggle(TEMPERATURE_COLOR, &temperature_scale_text(config.borrow().temperature_unit));
+        let power_check = series_toggle(POWER_COLOR, "Power");
+        let series_box = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        series_box.append(&temperature_check);
+        series_box.append(&power_check);
+        let graph_box = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        graph_box.append(&graph_stack);
+        graph_box.append(&series_box);
+
+        // Processes holding VRAM, from NVML or DRM fdinfo
+        let process_title = gtk::Label::new(Some("Processes by VRAM"));
+        process_title.add_css_class("heading");
+        process_title.set_halign(gtk::Align::Start);
+        let process_list = gtk::ListBox::new();
+        process_list.set_selection_mode(gtk::SelectionMode::None);
+        let process_scroll = gtk::ScrolledWindow::builder()
+            .child(&process_list)
+            .min_content_height(120)
+            .vexpand(true)
+            .build();
+        process_scroll.add_css_class("card");
+
+        widget.append(&header);
         widget.append(&no_gpu_label);
-        widget.append(&graph.widget);
+        widget.append(&graph_box);
         widget.append(&info_grid);
+        widget.append(&process_title);
+        widget.append(&process_scroll);
 
         Self {
             widget,
-            graph,
+            graph_stack,
+            graphs: Vec::new(),
+            graph_settings: graph_settings.clone(),
+            power_scales: Vec::new(),
+            gpu_names: Vec::new(),
+            selector,
+            temperature_check,
+            power_check,
+            toggle_handlers: Vec::new(),
+            temperature_unit: config.borrow().temperature_unit,
+            config: config.clone(),
             title_label,
             utilization_label,
             vram_label,
             temp_label,
             power_label,
             fan_label,
+            core_clock_label,
+            mem_clock_label,
             no_gpu_label,
-            initialized: false,
+            info_grid,
+            process_title,
+            process_list,
+            process_scroll,
         }
     }
 
-    fn update(&mut self, gpu: &crate::model::GpuInfo) {
-        if gpu.available {
-            self.no_gpu_label.set_visible(false);
-            self.graph.widget.set_visible(true);
+    fn update(
+        &mut self,
+        gpus: &[crate::model::GpuInfo],
+        primary: Option<usize>,
+        processes: &[crate::model::ProcessInfo],
+    ) {
+        let names: Vec<String> = gpus.iter().map(|g| g.name.clone()).collect();
+        if names != self.gpu_names {
+            self.rebuild(&names, primary.unwrap_or(0));
+        }
 
-            if !self.initialized {
-                self.title_label.set_text(&format!("GPU — {}", gpu.name));
-                self.initialized = true;
+        let unit = self.config.borrow().temperature_unit;
+        if unit != self.temperature_unit {
+            self.temperature_unit = unit;
+            if let Some(label) = self.temperature_check.child().and_downcast::<gtk::Label>() {
+                label.set_markup(&series_markup(TEMPERATURE_COLOR, &temperature_scale_text(unit)));
             }
+        }
 
-            let vram_pct = if gpu.vram_total > 0 {
-                (gpu.vram_used as f64 / gpu.vram_total as f64) * 100.0
-            } else {
-                0.0
-            };
+        // Keep every GPU's history running, not just the visible one
+        for ((gpu, graph), scale) in gpus.iter().zip(&self.graphs).zip(&mut self.power_scales) {
+            if gpu.available {
+                let peak = gpu.power_limit_watts.max(gpu.power_watts);
+                if peak > *scale {
+                    *scale = peak;
+                    graph.set_series_max(3, peak);
+                }
+                graph.push_values(&[
+                    gpu.utilization_percent,
+                    vram_percent(gpu),
+                    gpu.temperature as f64,
+                    gpu.power_watts,
+                ]);
+            }
+        }
 
-            self.graph.push_values(&[gpu.utilization_percent, vram_pct]);
-            self.utilization_label.set_text(&util::format_percent(gpu.utilization_percent));
-            self.vram_label.set_text(&format!(
-                "{} / {}",
-                util::format_bytes(gpu.vram_used),
-                util::format_bytes(gpu.vram_total)
-            ));
-            self.temp_label.set_text(&format!("{} C", gpu.temperature));
-            self.power_label.set_text(&format!(
-                "{:.0} W / {:.0} W",
-                gpu.power_watts, gpu.power_limit_watts
-            ));
-            self.fan_label.set_text(&format!("{}%", gpu.fan_speed_percent));
-        } else {
-            self.no_gpu_label.set_visible(true);
-            self.graph.widget.set_visible(false);
+        let selected = self.selector.selected() as usize;
+        match gpus.get(selected).filter(|g| g.available) {
+            Some(gpu) => {
+                self.no_gpu_label.set_visible(false);
+                self.graph_stack.set_visible(true);
+                self.info_grid.set_visible(true);
+
+                self.title_label.set_text(&format!("GPU {} — {}", selected, gpu.name));
+                self.utilization_label.set_text(&util::format_percent(gpu.utilization_percent));
+                self.vram_label.set_text(&format!(
+                    "{} / {}",
+                    util::format_bytes(gpu.vram_used),
+                    util::format_bytes(gpu.vram_total)
+                ));
+                self.temp_label.set_text(&util::format_temperature(gpu.temperature as f64, self.temperature_unit));
+                self.power_label.set_text(&format!(
+                    "{:.0} W / {:.0} W",
+                    gpu.power_watts, gpu.power_limit_watts
+                ));
+                self.fan_label.set_text(&format!("{}%", gpu.fan_speed_percent));
+                let clock_text = |mhz: u32| if mhz > 0 { util::format_frequency(mhz as f64) } else { "—".into() };
+                self.core_clock_label.set_text(&clock_text(gpu.core_clock_mhz));
+                self.mem_clock_label.set_text(&clock_text(gpu.mem_clock_mhz));
+                self.temperature_check.set_sensitive(gpu.temperature > 0);
+                let power_scale = self.power_scales.get(selected).copied().unwrap_or(0.0);
+                self.power_check.set_sensitive(power_scale > 0.0);
+                self.power_check.set_tooltip_text(Some(&format!("Scale: 0–{:.0} W", power_scale)));
+                self.update_processes(processes, gpus.len() > 1);
+            }
+            None => {
+                self.title_label.set_text("GPU");
+                self.no_gpu_label.set_visible(true);
+                self.graph_stack.set_visible(false);
+                self.info_grid.set_visible(false);
+                self.process_title.set_visible(false);
+                self.process_scroll.set_visible(false);
+            }
         }
     }
+
+    /// List the processes using the most VRAM. Per-process usage is summed
+    /// over all GPUs, so with several GPUs the list isn't per device.
+    fn update_processes(&self, processes: &[crate::model::ProcessInfo], all_gpus: bool) {
+        let mut users: Vec<&crate::model::ProcessInfo> = processes.iter().filter(|p| p.vram_bytes > 0).collect();
+        users.sort_by(|a, b| b.vram_bytes.cmp(&a.vram_bytes).then(a.pid.cmp(&b.pid)));
+
+        self.process_title.set_visible(true);
+        self.process_scroll.set_visible(true);
+        self.process_title.set_text(if all_gpus { "Processes by VRAM (all GPUs)" } else { "Processes by VRAM" });
+
+        while let Some(row) = self.process_list.first_child() {
+            self.process_list.remove(&row);
+        }
+        if users.is_empty() {
+            let label = gtk::Label::new(Some("No per-process VRAM usage reported"));
+            label.add_css_class("dim-label");
+            label.set_margin_top(8);
+            label.set_margin_bottom(8);
+            self.process_list.append(&label);
+            return;
+        }
+        for p in users.iter().take(GPU_TOP_PROCESSES) {
+            let name = if p.display_name.is_empty() { &p.name } else { &p.display_name };
+            let text = format!("{:>10}  {:<8} {}", util::format_bytes(p.vram_bytes), p.pid, name);
+            let label = gtk::Label::new(Some(&text));
+            label.set_halign(gtk::Align::Start);
+            label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+            label.add_css_class("monospace");
+            label.set_margin_start(8);
+            label.set_margin_top(1);
+            label.set_margin_bottom(1);
+            self.process_list.append(&label);
+        }
+    }
+
+    /// Graph of the GPU shown in the panel, if it has data.
+    fn selected_graph(&self) -> Option<&GraphWidget> {
+        if !self.graph_stack.is_visible() {
+            return None;
+        }
+        self.graphs.get(self.selector.selected() as usize)
+    }
+
+    fn rebuild(&mut self, names: &[String], selected: usize) {
+        for graph in self.graphs.drain(..) {
+            self.graph_stack.remove(&graph.widget);
+        }
+        self.power_scales = vec![0.0; names.len()];
+        for i in 0..names.len() {
+            let graph = GraphWidget::new(600, 200, &self.graph_settings);
+            graph.set_title(&format!("GPU {}", i));
+            graph.set_series_count(4, vec![
+                GraphColor::new(0.2, 0.8, 0.4), // Utilization
+                GraphColor::new(0.8, 0.4, 0.2), // VRAM
+                GraphColor::new(1.0, 0.45, 0.2), // Temperature
+                GraphColor::new(0.85, 0.3, 0.75), // Power
+            ]);
+            graph.set_max_value(100.0);
+            graph.set_labels(&["Utilization", "VRAM", "Temperature", "Power"]);
+            graph.set_series_format(0, util::format_percent);
+            graph.set_series_format(1, util::format_percent);
+            let config = self.config.clone();
+            graph.set_series_format(2, move |t| util::format_temperature(t, config.borrow().temperature_unit));
+            graph.set_series_format(3, |watts| format!("{:.0} W", watts));
+            graph.set_series_max(2, 100.0);
+            graph.set_series_visible(2, self.temperature_check.is_active());
+            graph.set_series_visible(3, self.power_check.is_active());
+            self.graph_stack.add_named(&graph.widget, Some(&format!("gpu{}", i)));
+            self.graphs.push(graph);
+        }
+
+        // Point the series toggles at the new graphs
+        for handler in self.toggle_handlers.drain(..) {
+            handler.0.disconnect(handler.1);
+        }
+        for (check, series) in [(&self.temperature_check, 2), (&self.power_check, 3)] {
+            let graphs = self.graphs.clone();
+            let handler = check.connect_toggled(move |c| {
+                for graph in &graphs {
+                    graph.set_series_visible(series, c.is_active());
+                }
+            });
+            self.toggle_handlers.push((check.clone(), handler));
+        }
+
+        let labels: Vec<String> = names.iter().enumerate()
+            .map(|(i, name)| format!("GPU {}: {}", i, name))
+            .collect();
+        let labels: Vec<&str> = labels.iter().map(|s| s.as_str()).collect();
+        self.selector.set_model(Some(&gtk::StringList::new(&labels)));
+        self.selector.set_visible(names.len() > 1);
+        if !names.is_empty() {
+            let selected = selected.min(names.len() - 1);
+            self.selector.set_selected(selected as u32);
+            self.graph_stack.set_visible_child_name(&format!("gpu{}", selected));
+        }
+        self.gpu_names = names.to_vec();
+    }
+}
+
+fn vram_percent(gpu: &crate::model::GpuInfo) -> f64 {
+    if gpu.vram_total > 0 {
+        (gpu.vram_used as f64 / gpu.vram_total as f64) * 100.0
+    } else {
+        0.0
+    }
 }
 
 // ── Disk Panel ────────────────────────────────────────────
@@ -395,11 +1179,19 @@ impl GpuPanel {
 struct DiskPanel {
     widget: gtk::Box,
     graph: GraphWidget,
+    // Device names behind the selector; entry 0 is "All"
+    device_names: Vec<String>,
+    selector: gtk::DropDown,
+    // Device the graph history belongs to (None = all)
+    graphed: Option<String>,
     info_label: gtk::Label,
+    iops_label: gtk::Label,
+    response_label: gtk::Label,
+    pressure_label: gtk::Label,
 }
 
 impl DiskPanel {
-    fn new() -> Self {
+    fn new(graph_settings: &GraphSettings) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 12);
         widget.set_margin_top(16);
         widget.set_margin_start(16);
@@ -409,42 +1201,102 @@ impl DiskPanel {
         let title = gtk::Label::new(Some("Disk"));
         title.add_css_class("perf-label-title");
         title.set_halign(gtk::Align::Start);
+        title.set_hexpand(true);
+
+        let selector = gtk::DropDown::new(Some(gtk::StringList::new(&["All"])), gtk::Expression::NONE);
+        selector.set_tooltip_text(Some("Select device"));
+
+        // Jumps to the process list filtered to what's using the disk
+        let processes_btn = gtk::Button::with_label("Show Processes");
+        processes_btn.set_tooltip_text(Some("Processes reading or writing now, busiest first"));
+        processes_btn.set_action_name(Some("win.show-disk-activity"));
 
-        let graph = GraphWidget::new(600, 200);
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        header.append(&title);
+        header.append(&processes_btn);
+        header.append(&selector);
+
+        let graph = GraphWidget::new(600, 200, graph_settings);
+        graph.set_title("Disk");
         graph.set_series_count(2, vec![
             GraphColor::new(0.2, 0.7, 0.9), // Read
             GraphColor::new(0.9, 0.5, 0.2), // Write
         ]);
         graph.set_max_value(100_000_000.0); // 100 MB/s default scale
+        graph.set_labels(&["Read", "Write"]);
+        graph.set_series_format(0, util::format_bytes_rate);
+        graph.set_series_format(1, util::format_bytes_rate);
 
         let info_label = gtk::Label::new(Some(""));
         info_label.set_halign(gtk::Align::Start);
         info_label.set_wrap(true);
 
-        widget.append(&title);
+        let stats_grid = gtk::Grid::new();
+        stats_grid.set_row_spacing(6);
+        stats_grid.set_column_spacing(24);
+        let iops_label = gtk::Label::new(Some("0"));
+        let response_label = gtk::Label::new(Some("N/A"));
+        let pressure_label = gtk::Label::new(Some("N/A"));
+        add_info_row(&stats_grid, 0, "IOPS", &iops_label);
+        add_info_row(&stats_grid, 1, "Response Time", &response_label);
+        add_info_row(&stats_grid, 2, "Pressure", &pressure_label);
+
+        widget.append(&header);
         widget.append(&graph.widget);
+        widget.append(&stats_grid);
         widget.append(&info_label);
 
         Self {
             widget,
             graph,
+            device_names: Vec::new(),
+            selector,
+            graphed: None,
             info_label,
+            iops_label,
+            response_label,
+            pressure_label,
         }
     }
 
     fn update(&mut self, disk: &crate::model::DiskInfo) {
+        let names: Vec<String> = disk.devices.iter().map(|d| d.name.clone()).collect();
+        if names != self.device_names {
+            self.rebuild_selector(names);
+        }
+
+        let selected = self.selected_device();
+        // History of one device means nothing for another
+        if selected != self.graphed {
+            self.graph.clear();
+            self.graphed = selected.clone();
+        }
+
         let mut total_read = 0.0f64;
         let mut total_write = 0.0f64;
+        let mut total_iops = (0.0f64, 0.0f64);
+        // Sum of await × IOPS, i.e. time spent per second, for an IOPS-weighted average
+        let mut busy_ms = 0.0f64;
         let mut info_parts = Vec::new();
 
         for dev in &disk.devices {
+            if selected.as_ref().is_some_and(|name| *name != dev.name) {
+                continue;
+            }
             total_read += dev.read_bytes_sec;
             total_write += dev.write_bytes_sec;
+            total_iops.0 += dev.read_iops;
+            total_iops.1 += dev.write_iops;
+            busy_ms += dev.read_await_ms * dev.read_iops + dev.write_await_ms * dev.write_iops;
             info_parts.push(format!(
-                "{}:  R: {}  W: {}",
+                "{}:  R: {}  W: {}  IOPS: {:.0}/{:.0}  Await: {:.1}/{:.1} ms",
                 dev.name,
                 util::format_bytes_rate(dev.read_bytes_sec),
-                util::format_bytes_rate(dev.write_bytes_sec)
+                util::format_bytes_rate(dev.write_bytes_sec),
+                dev.read_iops,
+                dev.write_iops,
+                dev.read_await_ms,
+                dev.write_await_ms
             ));
         }
 
@@ -454,6 +1306,166 @@ impl DiskPanel {
 
         self.graph.push_values(&[total_read, total_write]);
         self.info_label.set_text(&info_parts.join("\n"));
+
+        self.iops_label.set_text(&format!("{:.0} read, {:.0} write", total_iops.0, total_iops.1));
+        let ops = total_iops.0 + total_iops.1;
+        self.response_label.set_text(&if ops > 0.0 {
+            format!("{:.1} ms", busy_ms / ops)
+        } else {
+            "N/A".to_string()
+        });
+    }
+
+    fn selected_device(&self) -> Option<String> {
+        match self.selector.selected() {
+            0 | gtk::INVALID_LIST_POSITION => None,
+            n => self.device_names.get(n as usize - 1).cloned(),
+        }
+    }
+
+    /// Refill the selector when devices come or go, keeping the current
+    /// choice if that device still exists.
+    fn rebuild_selector(&mut self, names: Vec<String>) {
+        let current = self.selected_device();
+
+        let mut labels = vec!["All"];
+        labels.extend(names.iter().map(|n| n.as_str()));
+        let model = gtk::StringList::new(&labels);
+        let position = current
+            .and_then(|name| names.iter().position(|n| *n == name))
+            .map_or(0, |i| i as u32 + 1);
+        self.device_names = names;
+
+        self.selector.set_model(Some(&model));
+        self.selector.set_selected(position);
+    }
+}
+
+// ── Storage Panel ─────────────────────────────────────────
+
+struct StoragePanel {
+    widget: gtk::Box,
+    list_box: gtk::ListBox,
+    show_pseudo: gtk::CheckButton,
+    // Mount point plus value labels and usage bar, for in-place updates
+    rows: Vec<(String, gtk::Label, gtk::Label, gtk::Label, gtk::Label, gtk::ProgressBar)>,
+}
+
+impl StoragePanel {
+    fn new() -> Self {
+        let widget = gtk::Box::new(gtk::Orientation::Vertical, 12);
+        widget.set_margin_top(16);
+        widget.set_margin_start(16);
+        widget.set_margin_end(16);
+        widget.set_margin_bottom(16);
+
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        let title = gtk::Label::new(Some("Storage"));
+        title.add_css_class("perf-label-title");
+        title.set_halign(gtk::Align::Start);
+        title.set_hexpand(true);
+        let show_pseudo = gtk::CheckButton::with_label("Show pseudo filesystems");
+        header.append(&title);
+        header.append(&show_pseudo);
+
+        let list_box = gtk::ListBox::new();
+        list_box.set_selection_mode(gtk::SelectionMode::None);
+        list_box.add_css_class("boxed-list");
+
+        let scroll = gtk::ScrolledWindow::builder()
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .vexpand(true)
+            .child(&list_box)
+            .build();
+
+        widget.append(&header);
+        widget.append(&scroll);
+
+        Self {
+            widget,
+            list_box,
+            show_pseudo,
+            rows: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, filesystems: &[crate::model::FilesystemUsage]) {
+        let show_pseudo = self.show_pseudo.is_active();
+        let visible: Vec<&crate::model::FilesystemUsage> = filesystems.iter()
+            .filter(|f| show_pseudo || (!f.is_pseudo && f.total > 0))
+            .collect();
+
+        // Rebuild rows only when the set of mounts changes
+        let same_mounts = visible.len() == self.rows.len()
+            && visible.iter().zip(&self.rows).all(|(f, row)| f.mount_point == row.0);
+        if !same_mounts {
+            while let Some(child) = self.list_box.first_child() {
+                self.list_box.remove(&child);
+            }
+            self.rows.clear();
+            for fs in &visible {
+                self.rows.push(self.append_row(fs));
+            }
+        }
+
+        for (fs, (_, total, used, free, percent, bar)) in visible.iter().zip(&self.rows) {
+            let fraction = if fs.total > 0 { fs.used as f64 / fs.total as f64 } else { 0.0 };
+            total.set_text(&util::format_bytes(fs.total));
+            used.set_text(&util::format_bytes(fs.used));
+            free.set_text(&util::format_bytes(fs.available));
+            percent.set_text(&util::format_percent(fraction * 100.0));
+            bar.set_fraction(fraction);
+        }
+    }
+
+    fn append_row(
+        &self,
+        fs: &crate::model::FilesystemUsage,
+    ) -> (String, gtk::Label, gtk::Label, gtk::Label, gtk::Label, gtk::ProgressBar) {
+        let row = gtk::Box::new(gtk::Orientation::Vertical, 4);
+        row.set_margin_top(8);
+        row.set_margin_bottom(8);
+        row.set_margin_start(12);
+        row.set_margin_end(12);
+
+        let top = gtk::Box::new(gtk::Orientation::Horizontal, 8);
+        let mount_label = gtk::Label::new(Some(&fs.mount_point));
+        mount_label.set_halign(gtk::Align::Start);
+        mount_label.set_ellipsize(gtk::pango::EllipsizeMode::Middle);
+        mount_label.add_css_class("heading");
+        let device_label = gtk::Label::new(Some(&format!("{} · {}", fs.device, fs.fs_type)));
+        device_label.set_halign(gtk::Align::Start);
+        device_label.set_hexpand(true);
+        device_label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+        device_label.add_css_class("dim-label");
+        let percent = gtk::Label::new(None);
+        percent.set_halign(gtk::Align::End);
+        top.append(&mount_label);
+        top.append(&device_label);
+        top.append(&percent);
+
+        let bar = gtk::ProgressBar::new();
+
+        let info_grid = gtk::Grid::new();
+        info_grid.set_column_spacing(24);
+        let total = gtk::Label::new(None);
+        let used = gtk::Label::new(None);
+        let free = gtk::Label::new(None);
+        for (col, (name, value)) in [("Total", &total), ("Used", &used), ("Free", &free)].iter().enumerate() {
+            let label = gtk::Label::new(Some(name));
+            label.add_css_class("dim-label");
+            let cell = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+            cell.append(&label);
+            cell.append(*value);
+            info_grid.attach(&cell, col as i32, 0, 1, 1);
+        }
+
+        row.append(&top);
+        row.append(&bar);
+        row.append(&info_grid);
+        self.list_box.append(&row);
+
+        (fs.mount_point.clone(), total, used, free, percent, bar)
     }
 }
 
@@ -462,11 +1474,16 @@ impl DiskPanel {
 struct NetworkPanel {
     widget: gtk::Box,
     graph: GraphWidget,
+    // Interface names behind the selector; entry 0 is "All"
+    iface_names: Vec<String>,
+    selector: gtk::DropDown,
+    // Interface the graph history belongs to (None = all)
+    graphed: Option<String>,
     info_label: gtk::Label,
 }
 
 impl NetworkPanel {
-    fn new() -> Self {
+    fn new(graph_settings: &GraphSettings) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 12);
         widget.set_margin_top(16);
         widget.set_margin_start(16);
@@ -476,42 +1493,93 @@ impl NetworkPanel {
         let title = gtk::Label::new(Some("Network"));
         title.add_css_class("perf-label-title");
         title.set_halign(gtk::Align::Start);
+        title.set_hexpand(true);
 
-        let graph = GraphWidget::new(600, 200);
+        let selector = gtk::DropDown::new(Some(gtk::StringList::new(&["All"])), gtk::Expression::NONE);
+        selector.set_tooltip_text(Some("Select interface"));
+
+        let header = gtk::Box::new(gtk::Orientation::Horizontal, 12);
+        header.append(&title);
+        header.append(&selector);
+
+        let graph = GraphWidget::new(600, 200, graph_settings);
+        graph.set_title("Network");
         graph.set_series_count(2, vec![
             GraphColor::new(0.2, 0.8, 0.5), // Download
             GraphColor::new(0.8, 0.3, 0.3), // Upload
         ]);
         graph.set_max_value(10_000_000.0); // 10 MB/s default
+        graph.set_labels(&["Download", "Upload"]);
+        graph.set_series_format(0, util::format_bytes_rate);
+        graph.set_series_format(1, util::format_bytes_rate);
 
         let info_label = gtk::Label::new(Some(""));
         info_label.set_halign(gtk::Align::Start);
         info_label.set_wrap(true);
 
-        widget.append(&title);
+        widget.append(&header);
         widget.append(&graph.widget);
         widget.append(&info_label);
 
         Self {
             widget,
             graph,
+            iface_names: Vec::new(),
+            selector,
+            graphed: None,
             info_label,
         }
     }
 
     fn update(&mut self, net: &crate::model::NetworkInfo) {
+        let names: Vec<String> = net.interfaces.iter().map(|i| i.name.clone()).collect();
+        if names != self.iface_names {
+            self.rebuild_selector(names);
+        }
+
+        let selected = self.selected_interface();
+        // History of one interface means nothing for another
+        if selected != self.graphed {
+            self.graph.clear();
+            self.graphed = selected.clone();
+        }
+        // "All" leaves out bridges, veths and tunnels, whose traffic is
+        // already counted on the physical link (unless there is nothing else)
+        let has_physical = net.interfaces.iter().any(|i| !i.is_virtual);
+        let shown: Vec<&crate::model::NetworkInterface> = net
+            .interfaces
+            .iter()
+            .filter(|i| match &selected {
+                Some(name) => &i.name == name,
+                None => !i.is_virtual || !has_physical,
+            })
+            .collect();
+
         let mut total_rx = 0.0f64;
         let mut total_tx = 0.0f64;
         let mut info_parts = Vec::new();
 
-        for iface in &net.interfaces {
+        for iface in &shown {
             total_rx += iface.rx_bytes_sec;
             total_tx += iface.tx_bytes_sec;
             info_parts.push(format!(
-                "{}:  DL: {}  UL: {}",
+                "{}:  DL: {}  UL: {}  (received {}, sent {})",
                 iface.name,
                 util::format_bytes_rate(iface.rx_bytes_sec),
-                util::format_bytes_rate(iface.tx_bytes_sec)
+                util::format_bytes_rate(iface.tx_bytes_sec),
+                util::format_bytes(iface.total_rx),
+                util::format_bytes(iface.total_tx)
+            ));
+        }
+        if shown.len() > 1 {
+            let received: u64 = shown.iter().map(|i| i.total_rx).sum();
+            let sent: u64 = shown.iter().map(|i| i.total_tx).sum();
+            info_parts.push(format!(
+                "Total:  DL: {}  UL: {}  (received {}, sent {})",
+                util::format_bytes_rate(total_rx),
+                util::format_bytes_rate(total_tx),
+                util::format_bytes(received),
+                util::format_bytes(sent)
             ));
         }
 
@@ -521,6 +1589,30 @@ impl NetworkPanel {
         self.graph.push_values(&[total_rx, total_tx]);
         self.info_label.set_text(&info_parts.join("\n"));
     }
+
+    fn selected_interface(&self) -> Option<String> {
+        match self.selector.selected() {
+            0 | gtk::INVALID_LIST_POSITION => None,
+            n => self.iface_names.get(n as usize - 1).cloned(),
+        }
+    }
+
+    /// Refill the selector when interfaces come or go, keeping the current
+    /// choice if that interface still exists.
+    fn rebuild_selector(&mut self, names: Vec<String>) {
+        let current = self.selected_interface();
+
+        let mut labels = vec!["All"];
+        labels.extend(names.iter().map(|n| n.as_str()));
+        let model = gtk::StringList::new(&labels);
+        let position = current
+            .and_then(|name| names.iter().position(|n| *n == name))
+            .map_or(0, |i| i as u32 + 1);
+        self.iface_names = names;
+
+        self.selector.set_model(Some(&model));
+        self.selector.set_selected(position);
+    }
 }
 
 // ── Battery Panel ─────────────────────────────────────────
@@ -533,12 +1625,14 @@ struct BatteryPanel {
     power_label: gtk::Label,
     time_label: gtk::Label,
     ac_label: gtk::Label,
+    health_label: gtk::Label,
+    cycles_label: gtk::Label,
     no_battery_label: gtk::Label,
     initialized: bool,
 }
 
 impl BatteryPanel {
-    fn new() -> Self {
+    fn new(graph_settings: &GraphSettings) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 12);
         widget.set_margin_top(16);
         widget.set_margin_start(16);
@@ -552,9 +1646,12 @@ impl BatteryPanel {
         let no_battery_label = gtk::Label::new(Some("No battery detected"));
         no_battery_label.set_halign(gtk::Align::Start);
 
-        let graph = GraphWidget::new(600, 200);
+        let graph = GraphWidget::new(600, 200, graph_settings);
+        graph.set_title("Battery");
         graph.set_series_count(1, vec![GraphColor::new(0.2, 0.8, 0.3)]);
         graph.set_max_value(100.0);
+        graph.set_labels(&["Charge"]);
+        graph.set_series_format(0, util::format_percent);
 
         let info_grid = gtk::Grid::new();
         info_grid.set_row_spacing(6);
@@ -565,12 +1662,16 @@ impl BatteryPanel {
         let power_label = gtk::Label::new(Some("0 W"));
         let time_label = gtk::Label::new(Some("N/A"));
         let ac_label = gtk::Label::new(Some("Unknown"));
+        let health_label = gtk::Label::new(Some("N/A"));
+        let cycles_label = gtk::Label::new(Some("N/A"));
 
         add_info_row(&info_grid, 0, "Charge", &charge_label);
         add_info_row(&info_grid, 1, "Status", &status_label);
         add_info_row(&info_grid, 2, "Power Draw", &power_label);
         add_info_row(&info_grid, 3, "Time Remaining", &time_label);
         add_info_row(&info_grid, 4, "AC Power", &ac_label);
+        add_info_row(&info_grid, 5, "Health", &health_label);
+        add_info_row(&info_grid, 6, "Charge Cycles", &cycles_label);
 
         widget.append(&title);
         widget.append(&no_battery_label);
@@ -589,6 +1690,8 @@ impl BatteryPanel {
             power_label,
             time_label,
             ac_label,
+            health_label,
+            cycles_label,
             no_battery_label,
             initialized: false,
         }
@@ -615,6 +1718,8 @@ impl BatteryPanel {
             }
 
             self.ac_label.set_text(if battery.ac_connected { "Connected" } else { "Disconnected" });
+            self.health_label.set_text(&battery.health_percent.map_or("N/A".to_string(), |h| format!("{:.1}%", h)));
+            self.cycles_label.set_text(&battery.cycle_count.map_or("N/A".to_string(), |c| c.to_string()));
         } else {
             self.no_battery_label.set_visible(true);
             self.graph.widget.set_visible(false);
@@ -624,6 +1729,35 @@ impl BatteryPanel {
 
 // ── Helpers ───────────────────────────────────────────────
 
+/// Show the 10s pressure stall averages of one resource, with all windows in the tooltip.
+/// `what` names the contended resource; CPU has no system-wide "full" line.
+fn update_pressure_label(label: &gtk::Label, psi: Option<&PsiResource>, what: &str, has_full: bool) {
+    let Some(psi) = psi else {
+        label.set_text("N/A");
+        label.set_tooltip_text(Some("Pressure stall information is not available on this kernel"));
+        return;
+    };
+
+    if has_full {
+        label.set_text(&format!("some {:.1}%  full {:.1}%", psi.some_avg10, psi.full_avg10));
+    } else {
+        label.set_text(&format!("some {:.1}%", psi.some_avg10));
+    }
+
+    let mut tooltip = format!(
+        "Share of time tasks were stalled waiting for {} (10s / 60s / 300s)\n\
+         some: {:.2}% / {:.2}% / {:.2}%",
+        what, psi.some_avg10, psi.some_avg60, psi.some_avg300
+    );
+    if has_full {
+        tooltip.push_str(&format!(
+            "\nfull: {:.2}% / {:.2}% / {:.2}%",
+            psi.full_avg10, psi.full_avg60, psi.full_avg300
+        ));
+    }
+    label.set_tooltip_text(Some(&tooltip));
+}
+
 fn add_info_row(grid: &gtk::Grid, row: i32, label_text: &str, value_label: &gtk::Label) {
     let label = gtk::Label::new(Some(label_text));
     label.set_halign(gtk::Align::Start);
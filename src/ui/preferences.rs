@@ -0,0 +1,416 @@
+use gtk4 as gtk;
+use gtk::prelude::*;
+use libadwaita as adw;
+use adw::prelude::*;
+
+use crate::config::{BackgroundRefresh, Config, ConfirmKill, GraphTheme, KernelThreads, ProcessView, TemperatureUnit};
+use crate::ui::graph_widget::GraphSettings;
+use crate::ui::process_tab::ProcessTab;
+use crate::util;
+use crate::window::{REFRESH_INTERVALS, TABS};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Graph time windows offered as the default: (label, seconds).
+const GRAPH_WINDOWS: &[(&str, usize)] = &[("1 minute", 60), ("5 minutes", 300), ("30 minutes", 1800)];
+
+/// Show the preferences window. Settings that already have a window action
+/// (refresh interval, start minimized) go through it so the menu stays in sync.
+pub fn show_preferences(
+    window: &adw::ApplicationWindow,
+    config: Rc<RefCell<Config>>,
+    process_tab: Rc<RefCell<ProcessTab>>,
+    graph_settings: GraphSettings,
+) {
+    let prefs = adw::PreferencesWindow::builder()
+        .title("Preferences")
+        .transient_for(window)
+        .modal(true)
+        .search_enabled(false)
+        .default_width(560)
+        .default_height(560)
+        .build();
+
+    let page = adw::PreferencesPage::new();
+    let cfg = config.borrow().clone();
+
+    // General
+    let general = adw::PreferencesGroup::builder().title("General").build();
+
+    let interval_labels: Vec<&str> = REFRESH_INTERVALS.iter().map(|(label, _)| *label).collect();
+    let interval_row = adw::ComboRow::builder()
+        .title("Update speed")
+        .model(&gtk::StringList::new(&interval_labels))
+        .build();
+    if let Some(pos) = REFRESH_INTERVALS.iter().position(|(_, ms)| *ms == cfg.refresh_interval_ms) {
+        interval_row.set_selected(pos as u32);
+    }
+    let win = window.clone();
+    interval_row.connect_selected_notify(move |row| {
+        if let Some((_, ms)) = REFRESH_INTERVALS.get(row.selected() as usize) {
+            ActionGroupExt::activate_action(&win, "refresh-interval", Some(&ms.to_variant()));
+        }
+    });
+    general.add(&interval_row);
+
+    const BACKGROUND_MODES: [(BackgroundRefresh, &str); 3] = [
+        (BackgroundRefresh::Normal, "Keep updating"),
+        (BackgroundRefresh::Slow, "Every 5 seconds"),
+        (BackgroundRefresh::Paused, "Pause"),
+    ];
+    let background_labels: Vec<&str> = BACKGROUND_MODES.iter().map(|(_, label)| *label).collect();
+    let background_row = adw::ComboRow::builder()
+        .title("When not focused")
+        .subtitle("Pausing also stops alerts and the tray icon")
+        .model(&gtk::StringList::new(&background_labels))
+        .build();
+    background_row.set_selected(
+        BACKGROUND_MODES.iter().position(|(mode, _)| *mode == cfg.background_refresh).unwrap_or(0) as u32,
+    );
+    let config_ref = config.clone();
+    let win = window.clone();
+    background_row.connect_selected_notify(move |row| {
+        if let Some((mode, _)) = BACKGROUND_MODES.get(row.selected() as usize) {
+            {
+                let mut cfg = config_ref.borrow_mut();
+                cfg.background_refresh = *mode;
+                cfg.save();
+            }
+            ActionGroupExt::activate_action(&win, "apply-refresh-mode", None);
+        }
+    });
+    general.add(&background_row);
+
+    let tab_labels: Vec<&str> = TABS.iter().map(|(_, label)| *label).collect();
+    let tab_row = adw::ComboRow::builder()
+        .title("Default tab")
+        .subtitle("Page shown when the window opens")
+        .model(&gtk::StringList::new(&tab_labels))
+        .build();
+    tab_row.set_selected(TABS.iter().position(|(name, _)| *name == cfg.default_tab).unwrap_or(0) as u32);
+    let config_ref = config.clone();
+    tab_row.connect_selected_notify(move |row| {
+        if let Some((name, _)) = TABS.get(row.selected() as usize) {
+            let mut cfg = config_ref.borrow_mut();
+            cfg.default_tab = name.to_string();
+            cfg.save();
+        }
+    });
+    general.add(&tab_row);
+
+    const UNITS: [(TemperatureUnit, &str); 3] = [
+        (TemperatureUnit::Celsius, "Celsius (°C)"),
+        (TemperatureUnit::Fahrenheit, "Fahrenheit (°F)"),
+        (TemperatureUnit::Kelvin, "Kelvin (K)"),
+    ];
+    let unit_labels: Vec<&str> = UNITS.iter().map(|(_, label)| *label).collect();
+    let unit_row = adw::ComboRow::builder()
+        .title("Temperature unit")
+        .model(&gtk::StringList::new(&unit_labels))
+        .build();
+    unit_row.set_selected(UNITS.iter().position(|(unit, _)| *unit == cfg.temperature_unit).unwrap_or(0) as u32);
+    let config_ref = config.clone();
+    unit_row.connect_selected_notify(move |row| {
+        if let Some((unit, _)) = UNITS.get(row.selected() as usize) {
+            let mut cfg = config_ref.borrow_mut();
+            cfg.temperature_unit = *unit;
+            cfg.save();
+        }
+    });
+    general.add(&unit_row);
+    page.add(&general);
+
+    // Processes
+    let processes = adw::PreferencesGroup::builder().title("Processes").build();
+    const VIEWS: [(ProcessView, &str); 3] = [
+        (ProcessView::Apps, "Applications"),
+        (ProcessView::Units, "Systemd units"),
+        (ProcessView::Tree, "Process tree"),
+    ];
+    let view_labels: Vec<&str> = VIEWS.iter().map(|(_, label)| *label).collect();
+    let view_row = adw::ComboRow::builder()
+        .title("Group processes by")
+        .model(&gtk::StringList::new(&view_labels))
+        .build();
+    view_row.set_selected(VIEWS.iter().position(|(view, _)| *view == cfg.process_view).unwrap_or(0) as u32);
+    let process_tab_for_view = process_tab.clone();
+    view_row.connect_selected_notify(move |row| {
+        if let Some((view, _)) = VIEWS.get(row.selected() as usize) {
+            process_tab_for_view.borrow().set_view(*view);
+        }
+    });
+    processes.add(&view_row);
+
+    const KERNEL_MODES: [(KernelThreads, &str); 3] = [
+        (KernelThreads::Grouped, "Grouped"),
+        (KernelThreads::Separate, "Individually"),
+        (KernelThreads::Hidden, "Hidden"),
+    ];
+    let kernel_labels: Vec<&str> = KERNEL_MODES.iter().map(|(_, label)| *label).collect();
+    let kernel_row = adw::ComboRow::builder()
+        .title("Kernel threads")
+        .subtitle("Grouped shows a single Kernel row; Hidden also hides them from the process tree")
+        .model(&gtk::StringList::new(&kernel_labels))
+        .build();
+    kernel_row.set_selected(KERNEL_MODES.iter().position(|(mode, _)| *mode == cfg.kernel_threads).unwrap_or(0) as u32);
+    let process_tab_for_kernel = process_tab.clone();
+    let config_for_kernel = config.clone();
+    kernel_row.connect_selected_notify(move |row| {
+        if let Some((mode, _)) = KERNEL_MODES.get(row.selected() as usize) {
+            process_tab_for_kernel.borrow().set_kernel_threads(*mode);
+            let mut cfg = config_for_kernel.borrow_mut();
+            cfg.kernel_threads = *mode;
+            cfg.save();
+        }
+    });
+    processes.add(&kernel_row);
+
+    let cpu_total_row = adw::SwitchRow::builder()
+        .title("CPU as % of total")
+        .subtitle("Divide process CPU usage by the number of cores, so a fully busy system reads 100%")
+        .active(cfg.cpu_of_total)
+        .build();
+    let config_for_cpu_total = config.clone();
+    let process_tab_for_cpu_total = process_tab.clone();
+    cpu_total_row.connect_active_notify(move |row| {
+        process_tab_for_cpu_total.borrow().set_cpu_of_total(row.is_active());
+        let mut cfg = config_for_cpu_total.borrow_mut();
+        cfg.cpu_of_total = row.is_active();
+        cfg.save();
+    });
+    processes.add(&cpu_total_row);
+
+    let compact_row = adw::SwitchRow::builder()
+        .title("Compact rows")
+        .subtitle("Smaller rows and text, to fit more processes on screen")
+        .active(cfg.compact_rows)
+        .build();
+    let config_for_compact = config.clone();
+    let process_tab_for_compact = process_tab.clone();
+    compact_row.connect_active_notify(move |row| {
+        process_tab_for_compact.borrow().set_compact(row.is_active());
+        let mut cfg = config_for_compact.borrow_mut();
+        cfg.compact_rows = row.is_active();
+        cfg.save();
+    });
+    processes.add(&compact_row);
+
+    let pss_row = adw::SwitchRow::builder()
+        .title("Measure PSS memory")
+        .subtitle("Adds the PSS column; reading it for every process costs noticeable CPU")
+        .active(cfg.measure_pss)
+        .build();
+    let config_for_pss = config.clone();
+    pss_row.connect_active_notify(move |row| {
+        process_tab.borrow().set_measure_pss(row.is_active());
+        let mut cfg = config_for_pss.borrow_mut();
+        cfg.measure_pss = row.is_active();
+        cfg.save();
+    });
+    processes.add(&pss_row);
+
+    const CONFIRM_MODES: [(ConfirmKill, &str); 3] = [
+        (ConfirmKill::CriticalOnly, "Critical processes only"),
+        (ConfirmKill::Always, "Always"),
+        (ConfirmKill::Never, "Never (critical processes too)"),
+    ];
+    let confirm_labels: Vec<&str> = CONFIRM_MODES.iter().map(|(_, label)| *label).collect();
+    let confirm_row = adw::ComboRow::builder()
+        .title("Confirm before ending processes")
+        .model(&gtk::StringList::new(&confirm_labels))
+        .build();
+    confirm_row.set_selected(CONFIRM_MODES.iter().position(|(mode, _)| *mode == cfg.confirm_kill).unwrap_or(0) as u32);
+    let config_for_confirm = config.clone();
+    confirm_row.connect_selected_notify(move |row| {
+        if let Some((mode, _)) = CONFIRM_MODES.get(row.selected() as usize) {
+            let mut cfg = config_for_confirm.borrow_mut();
+            cfg.confirm_kill = *mode;
+            cfg.save();
+        }
+    });
+    processes.add(&confirm_row);
+
+    let escalate_row = adw::SwitchRow::builder()
+        .title("Force kill if End Task doesn't work")
+        .subtitle("Sends SIGKILL when the process is still running 3 seconds after SIGTERM")
+        .active(cfg.escalate_end_task)
+        .build();
+    let config_for_escalate = config.clone();
+    escalate_row.connect_active_notify(move |row| {
+        let mut cfg = config_for_escalate.borrow_mut();
+        cfg.escalate_end_task = row.is_active();
+        cfg.save();
+    });
+    processes.add(&escalate_row);
+    page.add(&processes);
+
+    // Graphs
+    let graphs = adw::PreferencesGroup::builder().title("Graphs").build();
+    let window_labels: Vec<&str> = GRAPH_WINDOWS.iter().map(|(label, _)| *label).collect();
+    let graph_window_row = adw::ComboRow::builder()
+        .title("Default time window")
+        .subtitle("Applies to graphs from the next start")
+        .model(&gtk::StringList::new(&window_labels))
+        .build();
+    graph_window_row.set_selected(
+        GRAPH_WINDOWS.iter().position(|(_, secs)| *secs == cfg.graph_window_secs).unwrap_or(0) as u32,
+    );
+    let config_ref = config.clone();
+    let settings_for_window = graph_settings.clone();
+    graph_window_row.connect_selected_notify(move |row| {
+        if let Some((_, secs)) = GRAPH_WINDOWS.get(row.selected() as usize) {
+            settings_for_window.set_default_time_window(*secs);
+            let mut cfg = config_ref.borrow_mut();
+            cfg.graph_window_secs = *secs;
+            cfg.save();
+        }
+    });
+    graphs.add(&graph_window_row);
+
+    let smooth_row = adw::SwitchRow::builder()
+        .title("Smooth curves")
+        .subtitle("Off draws straight lines between the exact samples")
+        .active(cfg.smooth_graphs)
+        .build();
+    let config_ref = config.clone();
+    let settings_for_smooth = graph_settings.clone();
+    smooth_row.connect_active_notify(move |row| {
+        settings_for_smooth.set_smooth_curves(row.is_active());
+        let mut cfg = config_ref.borrow_mut();
+        cfg.smooth_graphs = row.is_active();
+        cfg.save();
+    });
+    graphs.add(&smooth_row);
+
+    const GRAPH_THEMES: [(GraphTheme, &str); 3] = [
+        (GraphTheme::System, "Follow system style"),
+        (GraphTheme::Light, "Light"),
+        (GraphTheme::Dark, "Dark"),
+    ];
+    let theme_labels: Vec<&str> = GRAPH_THEMES.iter().map(|(_, label)| *label).collect();
+    let theme_row = adw::ComboRow::builder()
+        .title("Graph colors")
+        .subtitle("Background and grid of the performance graphs")
+        .model(&gtk::StringList::new(&theme_labels))
+        .build();
+    theme_row.set_selected(
+        GRAPH_THEMES.iter().position(|(theme, _)| *theme == cfg.graph_theme).unwrap_or(0) as u32,
+    );
+    let config_ref = config.clone();
+    theme_row.connect_selected_notify(move |row| {
+        if let Some((theme, _)) = GRAPH_THEMES.get(row.selected() as usize) {
+            graph_settings.set_graph_theme(*theme);
+            let mut cfg = config_ref.borrow_mut();
+            cfg.graph_theme = *theme;
+            cfg.save();
+        }
+    });
+    graphs.add(&theme_row);
+    page.add(&graphs);
+
+    // Tray
+    let tray = adw::PreferencesGroup::builder().title("Tray").build();
+    let tray_row = adw::SwitchRow::builder()
+        .title("Show tray icon")
+        .subtitle("Takes effect after restart")
+        .active(cfg.show_tray_icon)
+        .build();
+    let config_ref = config.clone();
+    tray_row.connect_active_notify(move |row| {
+        let mut cfg = config_ref.borrow_mut();
+        cfg.show_tray_icon = row.is_active();
+        cfg.save();
+    });
+    tray.add(&tray_row);
+
+    let minimized_row = adw::SwitchRow::builder()
+        .title("Start minimized to tray")
+        .active(cfg.start_minimized)
+        .build();
+    tray_row
+        .bind_property("active", &minimized_row, "sensitive")
+        .sync_create()
+        .build();
+    let win = window.clone();
+    let config_ref = config.clone();
+    minimized_row.connect_active_notify(move |row| {
+        if row.is_active() != config_ref.borrow().start_minimized {
+            ActionGroupExt::activate_action(&win, "start-minimized", None);
+        }
+    });
+    tray.add(&minimized_row);
+    page.add(&tray);
+
+    // Alerts
+    let alerts = adw::PreferencesGroup::builder()
+        .title("Alerts")
+        .description("Desktop notification when a process runs away or a service fails")
+        .build();
+    let alerts_row = adw::SwitchRow::builder()
+        .title("Notify about heavy processes")
+        .active(cfg.alerts_enabled)
+        .build();
+    let config_ref = config.clone();
+    alerts_row.connect_active_notify(move |row| {
+        let mut cfg = config_ref.borrow_mut();
+        cfg.alerts_enabled = row.is_active();
+        cfg.save();
+    });
+    alerts.add(&alerts_row);
+
+    let cpu_row = adw::SpinRow::with_range(0.0, 6400.0, 10.0);
+    cpu_row.set_title("CPU above (%)");
+    cpu_row.set_subtitle("As shown in the process list; 0 disables");
+    cpu_row.set_value(cfg.alert_cpu_percent);
+    let config_ref = config.clone();
+    cpu_row.connect_value_notify(move |row| {
+        let mut cfg = config_ref.borrow_mut();
+        cfg.alert_cpu_percent = row.value();
+        cfg.save();
+    });
+    alerts.add(&cpu_row);
+
+    let cpu_secs_row = adw::SpinRow::with_range(1.0, 600.0, 1.0);
+    cpu_secs_row.set_title("For at least (seconds)");
+    cpu_secs_row.set_value(cfg.alert_cpu_secs as f64);
+    let config_ref = config.clone();
+    cpu_secs_row.connect_value_notify(move |row| {
+        let mut cfg = config_ref.borrow_mut();
+        cfg.alert_cpu_secs = row.value() as u64;
+        cfg.save();
+    });
+    alerts.add(&cpu_secs_row);
+
+    let memory_row = adw::SpinRow::with_range(0.0, 1024.0, 0.5);
+    memory_row.set_digits(1);
+    memory_row.set_title("Memory above (GiB)");
+    memory_row.set_subtitle("0 disables");
+    memory_row.set_value(cfg.alert_memory_mb as f64 / 1024.0);
+    let config_ref = config.clone();
+    memory_row.connect_value_notify(move |row| {
+        let mut cfg = config_ref.borrow_mut();
+        cfg.alert_memory_mb = (row.value() * 1024.0).round() as u64;
+        cfg.save();
+    });
+    alerts.add(&memory_row);
+
+    for row in [&cpu_row, &cpu_secs_row, &memory_row] {
+        alerts_row.bind_property("active", row, "sensitive").sync_create().build();
+    }
+
+    let service_alerts_row = adw::SwitchRow::builder()
+        .title("Notify when a service fails")
+        .subtitle("Checks systemd services every few seconds")
+        .active(cfg.service_alerts_enabled)
+        .build();
+    service_alerts_row.connect_active_notify(move |row| {
+        let mut cfg = config.borrow_mut();
+        cfg.service_alerts_enabled = row.is_active();
+        cfg.save();
+    });
+    alerts.add(&service_alerts_row);
+    page.add(&alerts);
+
+    prefs.add(&page);
+    prefs.present();
+}
@@ -0,0 +1,289 @@
+use gtk4 as gtk;
+use gtk::{gio, glib};
+use regex::Regex;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::ui::process_tab::ProcessObject;
+use crate::util;
+
+/// Parsed process search query. Whitespace-separated terms must all match:
+///
+/// - `firefox`            substring of name, PID or path
+/// - `/fire(fox|bird)/`   case-insensitive regex over name, PID, path and command line
+/// - `user:root`          substring of a text field (name, user, path, cmd, state, container)
+/// - `pid:1234`           exact value of a numeric field
+/// - `cpu>50`, `mem>=1G`  comparison on a numeric field (cpu, mem, pss, swap, vram, disk, pid, ppid, threads, nice, conns, fds)
+/// - `env:DISPLAY=:1`     environment variable set to exactly that value (`env:KEY` for any value);
+///                        only the current user's processes are checked, and each
+///                        process is read once per query in the background
+///
+/// Sizes for `mem`/`pss`/`swap`/`vram` and rates for `disk` (read + write per
+/// second) accept K/M/G/T suffixes and default to MiB.
+pub struct ProcessQuery {
+    terms: Vec<SearchTerm>,
+    env: Rc<EnvLookup>,
+}
+
+/// Results of the query's `env:` terms, read from /proc off the UI thread.
+#[derive(Default)]
+struct EnvLookup {
+    /// Only processes of this user are read
+    uid: u32,
+    /// Variable name and, if given, its exact value
+    terms: Vec<(String, Option<String>)>,
+    /// (PID, start time) → whether each term matched
+    results: RefCell<HashMap<(i32, u64), Vec<bool>>>,
+    queued: RefCell<HashSet<(i32, u64)>>,
+    running: Cell<bool>,
+}
+
+enum SearchTerm {
+    Text(String),
+    Regex(Regex),
+    Field(&'static str, String),
+    Compare(&'static str, CompareOp, f64),
+    /// Index into the query's env terms
+    Env(usize),
+}
+
+#[derive(Clone, Copy)]
+enum CompareOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+const TEXT_FIELDS: &[&str] = &["name", "user", "path", "cmd", "state", "container"];
+const NUMERIC_FIELDS: &[&str] = &["cpu", "mem", "pss", "swap", "vram", "disk", "pid", "ppid", "threads", "nice", "conns", "fds"];
+
+impl ProcessQuery {
+    pub fn parse(query: &str) -> Self {
+        let query = query.trim();
+        // A query that is a single /regex/ may contain spaces
+        let mut env = EnvLookup { uid: unsafe { libc::getuid() }, ..Default::default() };
+        let terms = if query.len() >= 2 && query.starts_with('/') && query.ends_with('/') {
+            vec![parse_term(query, &mut env.terms)]
+        } else {
+            query.split_whitespace().map(|token| parse_term(token, &mut env.terms)).collect()
+        };
+        Self { terms, env: Rc::new(env) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn matches(&self, obj: &ProcessObject) -> bool {
+        self.terms.iter().all(|term| term.matches(obj, &self.env))
+    }
+
+    /// Read the environments of processes that `env:` terms are still waiting
+    /// on, then call `on_done` so the filter can run again with the results.
+    pub fn lookup_env(&self, on_done: impl Fn() + 'static) {
+        let env = self.env.clone();
+        if env.running.get() || env.queued.borrow().is_empty() {
+            return;
+        }
+        let keys: Vec<(i32, u64)> = env.queued.borrow_mut().drain().collect();
+        let terms = env.terms.clone();
+        env.running.set(true);
+        glib::spawn_future_local(async move {
+            let results = gio::spawn_blocking(move || {
+                keys.into_iter()
+                    .map(|key| (key, env_matches(key.0, &terms)))
+                    .collect::<Vec<_>>()
+            })
+            .await;
+            env.running.set(false);
+            if let Ok(results) = results {
+                env.results.borrow_mut().extend(results);
+                on_done();
+            }
+        });
+    }
+}
+
+impl EnvLookup {
+    /// Cached result for the term; unknown processes are queued and don't match yet.
+    fn matches(&self, obj: &ProcessObject, term: usize) -> bool {
+        if obj.uid() != self.uid {
+            return false;
+        }
+        let key = (obj.pid(), obj.started_at());
+        match self.results.borrow().get(&key) {
+            Some(matched) => matched.get(term).copied().unwrap_or(false),
+            None => {
+                self.queued.borrow_mut().insert(key);
+                false
+            }
+        }
+    }
+}
+
+impl SearchTerm {
+    fn matches(&self, obj: &ProcessObject, env: &EnvLookup) -> bool {
+        match self {
+            SearchTerm::Text(text) => {
+                obj.display_name().to_lowercase().contains(text)
+                    || obj.pid().to_string().contains(text)
+                    || obj.exe_path().to_lowercase().contains(text)
+            }
+            SearchTerm::Regex(re) => {
+                re.is_match(&obj.display_name())
+                    || re.is_match(&obj.pid().to_string())
+                    || re.is_match(&obj.exe_path())
+                    || re.is_match(&obj.command())
+            }
+            SearchTerm::Field(field, value) => text_field(obj, field)
+                .map(|v| v.to_lowercase().contains(value))
+                .unwrap_or(false),
+            SearchTerm::Compare(field, op, value) => {
+                let Some(actual) = numeric_field(obj, field) else {
+                    return false;
+                };
+                match op {
+                    CompareOp::Lt => actual < *value,
+                    CompareOp::Le => actual <= *value,
+                    CompareOp::Gt => actual > *value,
+                    CompareOp::Ge => actual >= *value,
+                    CompareOp::Eq => actual == *value,
+                }
+            }
+            SearchTerm::Env(term) => env.matches(obj, *term),
+        }
+    }
+}
+
+fn parse_term(token: &str, env_terms: &mut Vec<(String, Option<String>)>) -> SearchTerm {
+    // /regex/ — an invalid pattern falls back to a substring match
+    if token.len() >= 2 && token.starts_with('/') && token.ends_with('/') {
+        let pattern = &token[1..token.len() - 1];
+        return match Regex::new(&format!("(?i){}", pattern)) {
+            Ok(re) => SearchTerm::Regex(re),
+            Err(e) => {
+                log::debug!("Invalid search regex {:?}: {}", pattern, e);
+                SearchTerm::Text(pattern.to_lowercase())
+            }
+        };
+    }
+
+    // field:value
+    if let Some((key, value)) = token.split_once(':') {
+        let key = key.to_lowercase();
+        if key == "env" && !value.is_empty() {
+            env_terms.push(match value.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (value.to_string(), None),
+            });
+            return SearchTerm::Env(env_terms.len() - 1);
+        }
+        if let Some(field) = TEXT_FIELDS.iter().find(|f| **f == key) {
+            return SearchTerm::Field(field, value.to_lowercase());
+        }
+        if let Some(field) = NUMERIC_FIELDS.iter().find(|f| **f == key) {
+            if let Some(value) = parse_number(field, value) {
+                return SearchTerm::Compare(field, CompareOp::Eq, value);
+            }
+        }
+    }
+
+    // field<op>value
+    if let Some(pos) = token.find(['<', '>', '=']) {
+        let key = token[..pos].to_lowercase();
+        let rest = &token[pos..];
+        let (op, value) = if let Some(v) = rest.strip_prefix(">=") {
+            (CompareOp::Ge, v)
+        } else if let Some(v) = rest.strip_prefix("<=") {
+            (CompareOp::Le, v)
+        } else if let Some(v) = rest.strip_prefix('>') {
+            (CompareOp::Gt, v)
+        } else if let Some(v) = rest.strip_prefix('<') {
+            (CompareOp::Lt, v)
+        } else {
+            (CompareOp::Eq, rest.trim_start_matches('='))
+        };
+        if let Some(field) = NUMERIC_FIELDS.iter().find(|f| **f == key) {
+            if let Some(value) = parse_number(field, value) {
+                return SearchTerm::Compare(field, op, value);
+            }
+        }
+    }
+
+    SearchTerm::Text(token.to_lowercase())
+}
+
+/// Parse a comparison value; memory sizes are returned in bytes
+fn parse_number(field: &str, value: &str) -> Option<f64> {
+    if !matches!(field, "mem" | "pss" | "swap" | "vram" | "disk") {
+        return value.trim_end_matches('%').parse().ok();
+    }
+    let value = value.to_uppercase();
+    let plain_bytes = value.ends_with('B') && value[..value.len() - 1].ends_with(|c: char| c.is_ascii_digit());
+    let value = value.trim_end_matches("IB").trim_end_matches('B');
+    let split = value.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let multiplier = match unit {
+        "" if plain_bytes => 1.0,
+        "" => 1024.0 * 1024.0,
+        "K" => 1024.0,
+        "M" => 1024.0 * 1024.0,
+        "G" => 1024.0 * 1024.0 * 1024.0,
+        "T" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    number.parse::<f64>().ok().map(|n| n * multiplier)
+}
+
+/// For each (name, value) term, whether the process has `name` set (to
+/// `value`, if given). Variable names and values are case-sensitive.
+fn env_matches(pid: i32, terms: &[(String, Option<String>)]) -> Vec<bool> {
+    let Ok(environ) = std::fs::read(format!("/proc/{}/environ", pid)) else {
+        return vec![false; terms.len()];
+    };
+    let vars: Vec<(String, String)> = environ
+        .split(|&b| b == 0)
+        .filter_map(|var| {
+            let var = String::from_utf8_lossy(var);
+            var.split_once('=').map(|(key, val)| (key.to_string(), val.to_string()))
+        })
+        .collect();
+    terms.iter()
+        .map(|(name, value)| {
+            vars.iter().any(|(key, val)| key == name && value.as_ref().is_none_or(|v| v == val))
+        })
+        .collect()
+}
+
+fn text_field(obj: &ProcessObject, field: &str) -> Option<String> {
+    match field {
+        "name" => Some(obj.display_name()),
+        "user" => Some(obj.user()),
+        "path" => Some(obj.exe_path()),
+        "cmd" => Some(obj.command()),
+        "state" => Some(util::process_state_label(&obj.state()).to_string()),
+        "container" => Some(obj.container_type()),
+        _ => None,
+    }
+}
+
+fn numeric_field(obj: &ProcessObject, field: &str) -> Option<f64> {
+    match field {
+        "cpu" => Some(obj.shown_cpu_percent()),
+        "mem" => Some(obj.memory_bytes() as f64),
+        "pss" => Some(obj.pss_bytes() as f64),
+        "swap" => Some(obj.swap_bytes() as f64),
+        "vram" => Some(obj.vram_bytes() as f64),
+        "disk" => Some(obj.disk_rate()),
+        "pid" => Some(obj.pid() as f64),
+        "ppid" => Some(obj.ppid() as f64),
+        "threads" => Some(obj.threads() as f64),
+        "nice" => Some(obj.nice() as f64),
+        "conns" => Some(obj.connection_count() as f64),
+        "fds" => Some(obj.fd_count() as f64),
+        _ => None,
+    }
+}
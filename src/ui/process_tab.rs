@@ -3,13 +3,23 @@ use gtk::prelude::*;
 use gtk::glib;
 use gtk::gio;
 use gtk::subclass::prelude::ObjectSubclassIsExt;
-use std::cell::RefCell;
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use nix::sys::signal::{self, Signal};
 use nix::unistd::Pid;
 
+use crate::backend::{build_unit_groups, capabilities, cgroup, ioprio, is_kernel_thread, oom, process_restart, read_smaps_rollup, PriorityRules};
+use crate::backend::history::{self, AppHistory};
+use crate::backend::process_report::{self, ProcessReport};
+use crate::config::{Config, ConfirmKill, KernelThreads, ProcessView, TableSort};
 use crate::model::{AppGroup, SystemSnapshot};
+use crate::ui::column_sort;
+use crate::ui::graph_widget::{GraphColor, GraphSettings, GraphWidget};
+use crate::ui::process_filter::ProcessQuery;
 use crate::util;
 
 // GObject wrapper for process data in the model
@@ -19,17 +29,36 @@ mod imp {
     use gtk::subclass::prelude::*;
     use std::cell::RefCell;
 
-    #[derive(Default)]
+    // The most-watched values are properties, so cells can follow them
+    // through notify; the rest refresh on the "updated" signal.
+    #[derive(Default, glib::Properties)]
+    #[properties(wrapper_type = super::ProcessObject)]
     pub struct ProcessObject {
         pub pid: RefCell<i32>,
         pub ppid: RefCell<i32>,
+        #[property(get, set)]
         pub display_name: RefCell<String>,
+        #[property(get, set)]
         pub cpu_percent: RefCell<f64>,
+        // What the CPU column divides by: the core count when CPU is shown
+        // as a share of the whole machine, otherwise 1
+        pub cpu_scale: RefCell<f64>,
+        #[property(get, set)]
         pub memory_bytes: RefCell<u64>,
+        pub pss_bytes: RefCell<u64>,
+        pub shared_bytes: RefCell<u64>,
+        pub private_bytes: RefCell<u64>,
+        pub swap_bytes: RefCell<u64>,
         pub vram_bytes: RefCell<u64>,
         pub disk_read_rate: RefCell<f64>,
         pub disk_write_rate: RefCell<f64>,
+        pub net_rx_rate: RefCell<f64>,
+        pub net_tx_rate: RefCell<f64>,
+        pub connection_count: RefCell<u32>,
+        pub fd_count: RefCell<u32>,
+        #[property(get, set)]
         pub state: RefCell<String>,
+        pub started_at: RefCell<u64>,
         pub exe_path: RefCell<String>,
         pub is_group: RefCell<bool>,
         pub child_count: RefCell<u32>,
@@ -39,6 +68,12 @@ mod imp {
         pub uid: RefCell<u32>,
         pub threads: RefCell<u64>,
         pub command: RefCell<String>,
+        pub io_class: RefCell<String>,
+        pub security_label: RefCell<String>,
+        pub io_priority: RefCell<i32>,
+        pub tree_node: RefCell<bool>,
+        // Recent CPU samples of the app, for the sparkline (app group rows only)
+        pub cpu_history: RefCell<Vec<f64>>,
     }
 
     #[glib::object_subclass]
@@ -48,7 +83,14 @@ mod imp {
         type ParentType = glib::Object;
     }
 
-    impl ObjectImpl for ProcessObject {}
+    #[glib::derived_properties]
+    impl ObjectImpl for ProcessObject {
+        fn signals() -> &'static [glib::subclass::Signal] {
+            static SIGNALS: std::sync::OnceLock<Vec<glib::subclass::Signal>> = std::sync::OnceLock::new();
+            // Emitted after an in-place update from a new snapshot
+            SIGNALS.get_or_init(|| vec![glib::subclass::Signal::builder("updated").build()])
+        }
+    }
 }
 
 glib::wrapper! {
@@ -60,68 +102,149 @@ impl ProcessObject {
         glib::Object::builder().build()
     }
 
-    pub fn set_from_group(&self, group: &AppGroup) {
+    /// Tell bound cells that the values changed; see `connect_live_bind`.
+    pub fn notify_updated(&self) {
+        self.emit_by_name::<()>("updated", &[]);
+    }
+
+    pub fn set_from_group(&self, group: &AppGroup, cpu_scale: f64) {
         let imp = self.imp();
         *imp.pid.borrow_mut() = group.leader.pid;
         *imp.ppid.borrow_mut() = group.leader.ppid;
-        *imp.display_name.borrow_mut() = group.display_name().to_string();
-        *imp.cpu_percent.borrow_mut() = group.total_cpu;
-        *imp.memory_bytes.borrow_mut() = group.total_memory;
+        self.set_display_name(group.display_name().to_string());
+        *imp.cpu_scale.borrow_mut() = cpu_scale;
+        self.set_cpu_percent(group.total_cpu);
+        self.set_memory_bytes(group.total_memory);
+        *imp.pss_bytes.borrow_mut() = group.total_pss;
+        *imp.shared_bytes.borrow_mut() = group.total_shared;
+        *imp.private_bytes.borrow_mut() = group.total_private;
+        *imp.swap_bytes.borrow_mut() = group.total_swap;
         *imp.vram_bytes.borrow_mut() = group.total_vram;
         *imp.disk_read_rate.borrow_mut() = group.total_disk_read_rate;
         *imp.disk_write_rate.borrow_mut() = group.total_disk_write_rate;
-        *imp.state.borrow_mut() = group.leader.state.clone();
+        *imp.net_rx_rate.borrow_mut() = group.total_rx_bytes_sec;
+        *imp.net_tx_rate.borrow_mut() = group.total_tx_bytes_sec;
+        *imp.connection_count.borrow_mut() = group.total_connections;
+        *imp.fd_count.borrow_mut() = group.total_fds;
+        self.set_state(group.leader.state.clone());
+        *imp.started_at.borrow_mut() = group.leader.started_at;
         *imp.exe_path.borrow_mut() = group.leader.exe_path.clone();
         *imp.is_group.borrow_mut() = !group.children.is_empty();
         *imp.child_count.borrow_mut() = group.children.len() as u32;
+        *imp.tree_node.borrow_mut() = false;
         *imp.nice.borrow_mut() = group.leader.nice;
         *imp.container_type.borrow_mut() = group.leader.container_type.clone();
         *imp.user.borrow_mut() = group.leader.user.clone();
         *imp.uid.borrow_mut() = group.leader.uid;
         *imp.threads.borrow_mut() = group.leader.threads;
         *imp.command.borrow_mut() = group.leader.command.clone();
+        *imp.io_class.borrow_mut() = group.leader.io_class.clone();
+        *imp.security_label.borrow_mut() = group.leader.security_label.clone();
+        *imp.io_priority.borrow_mut() = group.leader.io_priority;
     }
 
-    pub fn set_from_process(&self, proc: &crate::model::ProcessInfo) {
+    /// A process in the PID/PPID tree: its own values, expandable when it has children
+    pub fn set_from_tree_node(&self, proc: &crate::model::ProcessInfo, child_count: usize, cpu_scale: f64) {
+        self.set_from_process(proc, cpu_scale);
+        let imp = self.imp();
+        *imp.is_group.borrow_mut() = child_count > 0;
+        *imp.child_count.borrow_mut() = child_count as u32;
+        *imp.tree_node.borrow_mut() = true;
+    }
+
+    pub fn set_from_process(&self, proc: &crate::model::ProcessInfo, cpu_scale: f64) {
         let imp = self.imp();
         *imp.pid.borrow_mut() = proc.pid;
         *imp.ppid.borrow_mut() = proc.ppid;
-        *imp.display_name.borrow_mut() = proc.display_name.clone();
-        *imp.cpu_percent.borrow_mut() = proc.cpu_percent;
-        *imp.memory_bytes.borrow_mut() = proc.memory_bytes;
+        self.set_display_name(proc.display_name.clone());
+        *imp.cpu_scale.borrow_mut() = cpu_scale;
+        self.set_cpu_percent(proc.cpu_percent);
+        self.set_memory_bytes(proc.memory_bytes);
+        *imp.pss_bytes.borrow_mut() = proc.pss_bytes;
+        *imp.shared_bytes.borrow_mut() = proc.shared_bytes;
+        *imp.private_bytes.borrow_mut() = proc.private_bytes;
+        *imp.swap_bytes.borrow_mut() = proc.swap_bytes;
         *imp.vram_bytes.borrow_mut() = proc.vram_bytes;
         *imp.disk_read_rate.borrow_mut() = proc.disk_read_rate;
         *imp.disk_write_rate.borrow_mut() = proc.disk_write_rate;
-        *imp.state.borrow_mut() = proc.state.clone();
+        *imp.net_rx_rate.borrow_mut() = proc.rx_bytes_sec;
+        *imp.net_tx_rate.borrow_mut() = proc.tx_bytes_sec;
+        *imp.connection_count.borrow_mut() = proc.connection_count;
+        *imp.fd_count.borrow_mut() = proc.fd_count;
+        self.set_state(proc.state.clone());
+        *imp.started_at.borrow_mut() = proc.started_at;
         *imp.exe_path.borrow_mut() = proc.exe_path.clone();
         *imp.is_group.borrow_mut() = false;
         *imp.child_count.borrow_mut() = 0;
+        *imp.tree_node.borrow_mut() = false;
+        imp.cpu_history.borrow_mut().clear();
         *imp.nice.borrow_mut() = proc.nice;
         *imp.container_type.borrow_mut() = proc.container_type.clone();
         *imp.user.borrow_mut() = proc.user.clone();
         *imp.uid.borrow_mut() = proc.uid;
         *imp.threads.borrow_mut() = proc.threads;
         *imp.command.borrow_mut() = proc.command.clone();
+        *imp.io_class.borrow_mut() = proc.io_class.clone();
+        *imp.security_label.borrow_mut() = proc.security_label.clone();
+        *imp.io_priority.borrow_mut() = proc.io_priority;
     }
 
     pub fn pid(&self) -> i32 { *self.imp().pid.borrow() }
     pub fn ppid(&self) -> i32 { *self.imp().ppid.borrow() }
-    pub fn display_name(&self) -> String { self.imp().display_name.borrow().clone() }
-    pub fn cpu_percent(&self) -> f64 { *self.imp().cpu_percent.borrow() }
-    pub fn memory_bytes(&self) -> u64 { *self.imp().memory_bytes.borrow() }
+    /// Disk read and write rate together
+    pub fn disk_rate(&self) -> f64 { self.disk_read_rate() + self.disk_write_rate() }
+    /// Doing something right now: using CPU, disk or network
+    pub fn is_active(&self) -> bool {
+        self.cpu_percent() >= ACTIVE_CPU_PERCENT
+            || self.disk_rate() > 0.0
+            || self.net_rx_rate() + self.net_tx_rate() > 0.0
+    }
+    /// CPU usage as displayed, per the "CPU as % of total" setting
+    pub fn shown_cpu_percent(&self) -> f64 { self.cpu_percent() / self.imp().cpu_scale.borrow().max(1.0) }
+    pub fn pss_bytes(&self) -> u64 { *self.imp().pss_bytes.borrow() }
+    pub fn shared_bytes(&self) -> u64 { *self.imp().shared_bytes.borrow() }
+    pub fn private_bytes(&self) -> u64 { *self.imp().private_bytes.borrow() }
+    pub fn swap_bytes(&self) -> u64 { *self.imp().swap_bytes.borrow() }
     pub fn vram_bytes(&self) -> u64 { *self.imp().vram_bytes.borrow() }
     pub fn disk_read_rate(&self) -> f64 { *self.imp().disk_read_rate.borrow() }
     pub fn disk_write_rate(&self) -> f64 { *self.imp().disk_write_rate.borrow() }
-    pub fn state(&self) -> String { self.imp().state.borrow().clone() }
+    pub fn net_rx_rate(&self) -> f64 { *self.imp().net_rx_rate.borrow() }
+    pub fn net_tx_rate(&self) -> f64 { *self.imp().net_tx_rate.borrow() }
+    pub fn connection_count(&self) -> u32 { *self.imp().connection_count.borrow() }
+    pub fn fd_count(&self) -> u32 { *self.imp().fd_count.borrow() }
     pub fn exe_path(&self) -> String { self.imp().exe_path.borrow().clone() }
     pub fn is_group(&self) -> bool { *self.imp().is_group.borrow() }
     pub fn child_count(&self) -> u32 { *self.imp().child_count.borrow() }
+    pub fn is_tree_node(&self) -> bool { *self.imp().tree_node.borrow() }
     pub fn nice(&self) -> i32 { *self.imp().nice.borrow() }
     pub fn container_type(&self) -> String { self.imp().container_type.borrow().clone() }
     pub fn user(&self) -> String { self.imp().user.borrow().clone() }
     pub fn uid(&self) -> u32 { *self.imp().uid.borrow() }
     pub fn threads(&self) -> u64 { *self.imp().threads.borrow() }
     pub fn command(&self) -> String { self.imp().command.borrow().clone() }
+    pub fn io_class(&self) -> String { self.imp().io_class.borrow().clone() }
+    pub fn security_label(&self) -> String { self.imp().security_label.borrow().clone() }
+    pub fn io_priority(&self) -> i32 { *self.imp().io_priority.borrow() }
+    pub fn started_at(&self) -> u64 { *self.imp().started_at.borrow() }
+    /// Recent CPU samples, scaled like `shown_cpu_percent`
+    pub fn shown_cpu_history(&self) -> Vec<f64> {
+        let scale = self.imp().cpu_scale.borrow().max(1.0);
+        self.imp().cpu_history.borrow().iter().map(|v| v / scale).collect()
+    }
+
+    pub fn set_cpu_history(&self, samples: Vec<f64>) {
+        *self.imp().cpu_history.borrow_mut() = samples;
+    }
+
+    /// I/O scheduling class and level as shown in the UI, e.g. "best-effort (4)"
+    pub fn io_priority_text(&self) -> String {
+        match self.io_class().as_str() {
+            "" => "—".to_string(),
+            "none" => "default".to_string(),
+            "idle" => "idle".to_string(),
+            class => format!("{} ({})", class, self.io_priority()),
+        }
+    }
 }
 
 /// Helper to unwrap TreeListRow → ProcessObject from a ListItem
@@ -133,12 +256,120 @@ fn get_process_obj(item: &gtk::ListItem) -> ProcessObject {
         .unwrap()
 }
 
-/// Helper to get ProcessObject from selection (with TreeListRow unwrapping)
-fn selected_process(sel: &gtk::SingleSelection) -> Option<ProcessObject> {
-    sel.selected_item()
-        .and_then(|i| i.downcast::<gtk::TreeListRow>().ok())
-        .and_then(|row| row.item())
-        .and_then(|i| i.downcast::<ProcessObject>().ok())
+fn property_expression(property: &str) -> gtk::PropertyExpression {
+    gtk::PropertyExpression::new(ProcessObject::static_type(), None::<&gtk::Expression>, property)
+}
+
+/// Bind a cell with `render`, and render it again whenever its object emits
+/// `signal` ("updated", or "notify::<property>" for property columns). Rows
+/// under an expanded parent aren't rebound by the models when only their
+/// values change.
+fn connect_live_bind(
+    factory: &gtk::SignalListItemFactory,
+    signal: &'static str,
+    render: impl Fn(&gtk::ListItem, &ProcessObject) + 'static,
+) {
+    let render = Rc::new(render);
+    let handlers: Rc<RefCell<HashMap<gtk::ListItem, (ProcessObject, glib::SignalHandlerId)>>> =
+        Rc::new(RefCell::new(HashMap::new()));
+    let handlers_for_bind = handlers.clone();
+    factory.connect_bind(move |_, item| {
+        let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+        let obj = get_process_obj(item);
+        render(item, &obj);
+        let render = render.clone();
+        let item_weak = item.downgrade();
+        let handler = obj.connect_local(signal, false, move |values| {
+            if let (Some(item), Ok(obj)) = (item_weak.upgrade(), values[0].get::<ProcessObject>()) {
+                render(&item, &obj);
+            }
+            None
+        });
+        if let Some((old_obj, old_handler)) = handlers_for_bind.borrow_mut().insert(item.clone(), (obj, handler)) {
+            old_obj.disconnect(old_handler);
+        }
+    });
+    factory.connect_unbind(move |_, item| {
+        let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+        if let Some((obj, handler)) = handlers.borrow_mut().remove(item) {
+            obj.disconnect(handler);
+        }
+    });
+}
+
+/// Helper to get the first selected ProcessObject (with TreeListRow unwrapping)
+fn selected_process(sel: &gtk::MultiSelection) -> Option<ProcessObject> {
+    selected_processes(sel).into_iter().next()
+}
+
+/// Helper to get every selected ProcessObject (with TreeListRow unwrapping)
+fn selected_processes(sel: &gtk::MultiSelection) -> Vec<ProcessObject> {
+    let bitset = sel.selection();
+    (0..bitset.size() as u32)
+        .filter_map(|i| sel.item(bitset.nth(i)))
+        .filter_map(|i| i.downcast::<gtk::TreeListRow>().ok())
+        .filter_map(|row| row.item())
+        .filter_map(|i| i.downcast::<ProcessObject>().ok())
+        .collect()
+}
+
+/// One entry of the "Top" strip: the heaviest app for a resource, which is
+/// selected in the list when clicked.
+struct TopEntry {
+    button: gtk::Button,
+    label: gtk::Label,
+    title: &'static str,
+    pid: Rc<Cell<i32>>,
+}
+
+impl TopEntry {
+    fn new(title: &'static str) -> Self {
+        let label = gtk::Label::new(Some(&format!("{}: —", title)));
+        label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+        label.set_max_width_chars(32);
+        let button = gtk::Button::new();
+        button.set_child(Some(&label));
+        button.add_css_class("flat");
+        button.set_sensitive(false);
+        Self { button, label, title, pid: Rc::new(Cell::new(0)) }
+    }
+
+    /// Show `group` with its formatted value, or a dash when nothing uses the resource.
+    fn set(&self, group: Option<&AppGroup>, value: impl Fn(&AppGroup) -> String) {
+        match group {
+            Some(group) => {
+                self.label.set_text(&format!("{}: {} ({})", self.title, group.display_name(), value(group)));
+                self.button.set_tooltip_text(Some(&format!("Select {} (PID {})", group.display_name(), group.leader.pid)));
+                self.pid.set(group.leader.pid);
+            }
+            None => {
+                self.label.set_text(&format!("{}: —", self.title));
+                self.button.set_tooltip_text(None);
+                self.pid.set(0);
+            }
+        }
+        self.button.set_sensitive(group.is_some());
+    }
+}
+
+/// Select and scroll to the visible row of `pid`. Returns false when the row
+/// is filtered out or inside a collapsed group.
+fn select_pid(selection: &gtk::MultiSelection, column_view: &gtk::ColumnView, pid: i32) -> bool {
+    let position = (0..selection.n_items()).find(|&i| {
+        selection
+            .item(i)
+            .and_downcast::<gtk::TreeListRow>()
+            .and_then(|row| row.item())
+            .and_downcast::<ProcessObject>()
+            .is_some_and(|obj| obj.pid() == pid)
+    });
+    match position {
+        Some(position) => {
+            column_view.scroll_to(position, None, gtk::ListScrollFlags::FOCUS | gtk::ListScrollFlags::SELECT, None);
+            true
+        }
+        None => false,
+    }
 }
 
 pub struct ProcessTab {
@@ -150,11 +381,36 @@ pub struct ProcessTab {
     scroll: gtk::ScrolledWindow,
     // Cache for group children data
     children_cache: Rc<RefCell<HashMap<i32, Vec<crate::model::ProcessInfo>>>>,
+    // Real PPID children of every process, whatever the view mode
+    process_tree: Rc<RefCell<HashMap<i32, Vec<i32>>>>,
+    // Collector flag for reading smaps_rollup
+    measure_pss: Arc<AtomicBool>,
     child_stores: Rc<RefCell<HashMap<i32, gio::ListStore>>>,
+    // Logical core count from the latest snapshot (for the affinity dialog)
+    core_count: Rc<Cell<usize>>,
+    // Physical RAM from the latest snapshot (for memory column coloring)
+    total_memory: Rc<Cell<u64>>,
+    paused: Rc<Cell<bool>>,
+    query: Rc<RefCell<ProcessQuery>>,
+    active_only: Rc<Cell<bool>>,
+    filter: gtk::CustomFilter,
+    view: Rc<Cell<ProcessView>>,
+    kernel_threads: Cell<KernelThreads>,
+    // Show CPU as a share of all cores rather than of one
+    cpu_of_total: Cell<bool>,
+    force_update: Rc<Cell<bool>>,
+    view_buttons: Vec<(ProcessView, gtk::ToggleButton)>,
+    // Heaviest app by CPU, memory and disk
+    top_entries: [TopEntry; 3],
 }
 
 impl ProcessTab {
-    pub fn new() -> Self {
+    pub fn new(
+        config: Rc<RefCell<Config>>,
+        priority_rules: PriorityRules,
+        measure_pss: Arc<AtomicBool>,
+        latest_snapshot: Rc<RefCell<Option<SystemSnapshot>>>,
+    ) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 0);
         widget.add_css_class("process-view");
 
@@ -163,7 +419,8 @@ impl ProcessTab {
         css_provider.load_from_string(
             ".resource-medium { color: @warning_color; }
              .resource-high { color: orange; }
-             .resource-critical { color: @error_color; font-weight: bold; }"
+             .resource-critical { color: @error_color; font-weight: bold; }
+             .process-zombie { color: @error_color; font-weight: bold; }"
         );
         gtk::style_context_add_provider_for_display(
             &gtk::gdk::Display::default().unwrap(),
@@ -174,8 +431,99 @@ impl ProcessTab {
         // Search bar
         let search_entry = gtk::SearchEntry::new();
         search_entry.set_placeholder_text(Some("Search processes..."));
+        search_entry.set_tooltip_text(Some(
+            "Filter by name, PID or path.\n\
+             Fields: name: user: path: cmd: state: container: pid:\n\
+             Compare: cpu>50  mem>=1G  threads>100  nice<0\n\
+             Environment (your processes): env:DISPLAY=:1  env:KEY\n\
+             Regex: /pattern/",
+        ));
         search_entry.add_css_class("search-bar");
-        widget.append(&search_entry);
+        search_entry.set_hexpand(true);
+
+        // Pause toggle: freezes the list (values and ordering) while the
+        // collector keeps running; resuming applies the latest snapshot.
+        let paused = Rc::new(Cell::new(false));
+        let pause_button = gtk::ToggleButton::new();
+        pause_button.set_icon_name("media-playback-pause-symbolic");
+        pause_button.set_tooltip_text(Some("Pause updates"));
+        pause_button.set_valign(gtk::Align::Center);
+        pause_button.set_margin_end(8);
+        let paused_for_toggle = paused.clone();
+        pause_button.connect_toggled(move |btn| {
+            let active = btn.is_active();
+            paused_for_toggle.set(active);
+            if active {
+                btn.set_icon_name("media-playback-start-symbolic");
+                btn.set_tooltip_text(Some("Resume updates"));
+            } else {
+                btn.set_icon_name("media-playback-pause-symbolic");
+                btn.set_tooltip_text(Some("Pause updates"));
+            }
+        });
+
+        // Watched-only toggle: show just the starred processes
+        let watched_only = Rc::new(Cell::new(false));
+        let watched_button = gtk::ToggleButton::new();
+        watched_button.set_icon_name("starred-symbolic");
+        watched_button.set_tooltip_text(Some("Watched only"));
+        watched_button.set_valign(gtk::Align::Center);
+        watched_button.set_margin_end(4);
+
+        // Active-only toggle: hide processes that are doing nothing right now
+        let active_only = Rc::new(Cell::new(false));
+        let active_button = gtk::ToggleButton::with_label("Active");
+        active_button.set_tooltip_text(Some("Hide idle processes (no CPU, disk or network activity)"));
+        active_button.set_valign(gtk::Align::Center);
+        active_button.set_margin_end(4);
+
+        // Apps (heuristic app groups) / Units (systemd units) / Tree (PID/PPID hierarchy) switch
+        let view = Rc::new(Cell::new(config.borrow().process_view));
+        let force_update = Rc::new(Cell::new(false));
+        let mode_box = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+        mode_box.add_css_class("linked");
+        mode_box.set_valign(gtk::Align::Center);
+        mode_box.set_margin_end(4);
+        let view_buttons: Vec<(ProcessView, gtk::ToggleButton)> = [
+            (ProcessView::Apps, "Apps", "Group processes by application"),
+            (ProcessView::Units, "Units", "Group processes by systemd service or scope"),
+            (ProcessView::Tree, "Tree", "Show the parent/child process tree"),
+        ]
+        .into_iter()
+        .map(|(mode, label, tooltip)| {
+            let btn = gtk::ToggleButton::with_label(label);
+            btn.set_tooltip_text(Some(tooltip));
+            (mode, btn)
+        })
+        .collect();
+        for (i, (mode, btn)) in view_buttons.iter().enumerate() {
+            if i > 0 {
+                btn.set_group(Some(&view_buttons[0].1));
+            }
+            btn.set_active(*mode == view.get());
+            mode_box.append(btn);
+        }
+
+        let toolbar = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+        toolbar.append(&search_entry);
+        toolbar.append(&mode_box);
+        toolbar.append(&active_button);
+        toolbar.append(&watched_button);
+        toolbar.append(&pause_button);
+        widget.append(&toolbar);
+
+        // "Top" strip: a quick look at what's busiest, filled in by update()
+        let top_entries = [TopEntry::new("CPU"), TopEntry::new("Memory"), TopEntry::new("Disk")];
+        let top_strip = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+        top_strip.set_margin_start(6);
+        top_strip.set_margin_end(6);
+        let top_title = gtk::Label::new(Some("Top"));
+        top_title.add_css_class("dim-label");
+        top_strip.append(&top_title);
+        for entry in &top_entries {
+            top_strip.append(&entry.button);
+        }
+        widget.append(&top_strip);
 
         // List store for process objects
         let store = gio::ListStore::new::<ProcessObject>();
@@ -184,6 +532,28 @@ impl ProcessTab {
         let child_stores: Rc<RefCell<HashMap<i32, gio::ListStore>>> =
             Rc::new(RefCell::new(HashMap::new()));
 
+        // Switching modes starts from empty stores, since rows of one mode
+        // can't be updated in place into the other
+        for (mode, btn) in &view_buttons {
+            let mode = *mode;
+            let view_for_toggle = view.clone();
+            let force_update_for_toggle = force_update.clone();
+            let store_for_toggle = store.clone();
+            let child_stores_for_toggle = child_stores.clone();
+            let config_for_toggle = config.clone();
+            btn.connect_toggled(move |btn| {
+                if !btn.is_active() || view_for_toggle.replace(mode) == mode {
+                    return;
+                }
+                store_for_toggle.remove_all();
+                child_stores_for_toggle.borrow_mut().clear();
+                force_update_for_toggle.set(true);
+                let mut cfg = config_for_toggle.borrow_mut();
+                cfg.process_view = mode;
+                cfg.save();
+            });
+        }
+
         // TreeListModel wrapping the root store
         let child_stores_for_tree = child_stores.clone();
         let tree_model = gtk::TreeListModel::new(
@@ -201,47 +571,138 @@ impl ProcessTab {
             },
         );
 
-        // Filter model for search (operates on TreeListRow items)
-        let filter = gtk::CustomFilter::new(glib::clone!(
-            #[weak] search_entry,
-            #[upgrade_or] false,
-            move |obj| {
-                let text = search_entry.text().to_string().to_lowercase();
-                if text.is_empty() {
-                    return true;
-                }
-                if let Some(row) = obj.downcast_ref::<gtk::TreeListRow>() {
-                    if let Some(proc_obj) = row.item().and_then(|i| i.downcast::<ProcessObject>().ok()) {
-                        let name = proc_obj.display_name().to_lowercase();
-                        let pid = proc_obj.pid().to_string();
-                        let path = proc_obj.exe_path().to_lowercase();
-                        return name.contains(&text) || pid.contains(&text) || path.contains(&text);
+        // Filter model for search (operates on TreeListRow items).
+        // The query is parsed once per change, not once per row.
+        let query = Rc::new(RefCell::new(ProcessQuery::parse("")));
+        let query_for_filter = query.clone();
+        let watched_only_for_filter = watched_only.clone();
+        let active_only_for_filter = active_only.clone();
+        let config_for_filter = config.clone();
+        let filter = gtk::CustomFilter::new(move |obj| {
+            let query = query_for_filter.borrow();
+            let watched_only = watched_only_for_filter.get();
+            let active_only = active_only_for_filter.get();
+            if query.is_empty() && !watched_only && !active_only {
+                return true;
+            }
+            if let Some(row) = obj.downcast_ref::<gtk::TreeListRow>() {
+                if let Some(proc_obj) = row.item().and_then(|i| i.downcast::<ProcessObject>().ok()) {
+                    if watched_only && !is_watched(&config_for_filter.borrow(), &proc_obj) {
+                        return false;
+                    }
+                    if active_only && !proc_obj.is_active() {
+                        return false;
                     }
+                    return query.matches(&proc_obj);
                 }
-                true
             }
-        ));
+            true
+        });
         let filter_model = gtk::FilterListModel::new(Some(tree_model), Some(filter.clone()));
 
-        // Re-filter on search text change
-        search_entry.connect_search_changed(move |_| {
-            filter.changed(gtk::FilterChange::Different);
+        // Re-parse and re-filter on search text change
+        let query_for_search = query.clone();
+        let filter_for_search = filter.clone();
+        search_entry.connect_search_changed(move |entry| {
+            *query_for_search.borrow_mut() = ProcessQuery::parse(&entry.text());
+            filter_for_search.changed(gtk::FilterChange::Different);
+            let filter = filter_for_search.clone();
+            query_for_search.borrow().lookup_env(move || filter.changed(gtk::FilterChange::Different));
+        });
+
+        let watched_only_for_toggle = watched_only.clone();
+        let filter_for_toggle = filter.clone();
+        watched_button.connect_toggled(move |btn| {
+            watched_only_for_toggle.set(btn.is_active());
+            filter_for_toggle.changed(gtk::FilterChange::Different);
+        });
+
+        let active_only_for_toggle = active_only.clone();
+        let filter_for_active = filter.clone();
+        active_button.connect_toggled(move |btn| {
+            active_only_for_toggle.set(btn.is_active());
+            filter_for_active.changed(gtk::FilterChange::Different);
         });
 
         // Sort model (sorter set after columns are built)
         let sort_model = gtk::SortListModel::new(Some(filter_model), None::<gtk::Sorter>);
 
-        // Selection model
-        let selection = gtk::SingleSelection::new(Some(sort_model.clone()));
-        selection.set_autoselect(false);
+        // Selection model (Ctrl/Shift-click to select several processes)
+        let selection = gtk::MultiSelection::new(Some(sort_model.clone()));
 
         // ColumnView
         let column_view = gtk::ColumnView::new(Some(selection.clone()));
         column_view.set_show_column_separators(true);
         column_view.set_show_row_separators(false);
+        if config.borrow().compact_rows {
+            column_view.add_css_class("compact");
+        }
 
         // --- Columns ---
 
+        // Watch (star) column: toggles watchlist membership, persisted by exe path
+        let watch_factory = gtk::SignalListItemFactory::new();
+        let config_for_watch = config.clone();
+        let watched_only_for_watch = watched_only.clone();
+        let filter_for_watch = filter.clone();
+        watch_factory.connect_setup(move |_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let button = gtk::Button::new();
+            button.set_has_frame(false);
+            button.set_focusable(false);
+            button.set_valign(gtk::Align::Center);
+            button.add_css_class("flat");
+            button.add_css_class("circular");
+            let item_weak = item.downgrade();
+            let config = config_for_watch.clone();
+            let watched_only = watched_only_for_watch.clone();
+            let filter = filter_for_watch.clone();
+            button.connect_clicked(move |btn| {
+                let Some(item) = item_weak.upgrade() else {
+                    return;
+                };
+                let obj = get_process_obj(&item);
+                let key = watch_key(&obj);
+                let watched = {
+                    let mut cfg = config.borrow_mut();
+                    let watched = if let Some(pos) = cfg.watched_processes.iter().position(|k| *k == key) {
+                        cfg.watched_processes.remove(pos);
+                        false
+                    } else {
+                        cfg.watched_processes.push(key);
+                        true
+                    };
+                    cfg.save();
+                    watched
+                };
+                set_watch_icon(btn, watched);
+                if watched_only.get() {
+                    filter.changed(gtk::FilterChange::Different);
+                }
+            });
+            item.set_child(Some(&button));
+        });
+        let config_for_watch_bind = config.clone();
+        watch_factory.connect_bind(move |_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let obj = get_process_obj(item);
+            let button = item.child().and_downcast::<gtk::Button>().unwrap();
+            set_watch_icon(&button, is_watched(&config_for_watch_bind.borrow(), &obj));
+        });
+        let watch_col = gtk::ColumnViewColumn::new(None, Some(watch_factory));
+        watch_col.set_id(Some("watched"));
+        watch_col.set_fixed_width(36);
+        let config_for_watch_sort = config.clone();
+        let watch_sorter = gtk::CustomSorter::new(move |a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            let cfg = config_for_watch_sort.borrow();
+            // Watched first
+            is_watched(&cfg, pb).cmp(&is_watched(&cfg, pa)).into()
+        });
+        watch_col.set_sorter(Some(&watch_sorter));
+        column_view.append_column(&watch_col);
+
         // Name column (with TreeExpander for expand/collapse)
         let name_factory = gtk::SignalListItemFactory::new();
         name_factory.connect_setup(|_, item| {
@@ -282,7 +743,11 @@ impl ProcessTab {
             let is_expandable = row.is_expandable();
             if is_expandable {
                 arrow_btn.set_visible(true);
-                label.set_text(&format!("{} ({})", name, obj.child_count() + 1));
+                if obj.is_tree_node() {
+                    label.set_text(&name);
+                } else {
+                    label.set_text(&format!("{} ({})", name, obj.child_count() + 1));
+                }
                 // Click arrow to toggle expansion
                 let row_for_click = row.clone();
                 arrow_btn.connect_clicked(move |_| {
@@ -315,15 +780,12 @@ impl ProcessTab {
             }
         });
         let name_col = gtk::ColumnViewColumn::new(Some("Name"), Some(name_factory));
+        name_col.set_id(Some("name"));
         name_col.set_expand(true);
         name_col.set_resizable(true);
 
         // Name sorter
-        let name_sorter = gtk::CustomSorter::new(|a, b| {
-            let pa = a.downcast_ref::<ProcessObject>().unwrap();
-            let pb = b.downcast_ref::<ProcessObject>().unwrap();
-            pa.display_name().to_lowercase().cmp(&pb.display_name().to_lowercase()).into()
-        });
+        let name_sorter = gtk::StringSorter::new(Some(property_expression("display-name")));
         name_col.set_sorter(Some(&name_sorter));
         column_view.append_column(&name_col);
 
@@ -335,13 +797,12 @@ impl ProcessTab {
             label.set_halign(gtk::Align::End);
             item.set_child(Some(&label));
         });
-        pid_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&pid_factory, "updated", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
             label.set_text(&obj.pid().to_string());
         });
         let pid_col = gtk::ColumnViewColumn::new(Some("PID"), Some(pid_factory));
+        pid_col.set_id(Some("pid"));
         pid_col.set_fixed_width(80);
         pid_col.set_resizable(true);
         let pid_sorter = gtk::CustomSorter::new(|a, b| {
@@ -360,11 +821,9 @@ impl ProcessTab {
             label.set_halign(gtk::Align::End);
             item.set_child(Some(&label));
         });
-        cpu_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&cpu_factory, "notify::cpu-percent", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
-            let cpu = obj.cpu_percent();
+            let cpu = obj.shown_cpu_percent();
             label.set_text(&util::format_percent(cpu));
 
             // Remove previous level classes
@@ -383,16 +842,38 @@ impl ProcessTab {
             }
         });
         let cpu_col = gtk::ColumnViewColumn::new(Some("CPU"), Some(cpu_factory));
+        cpu_col.set_id(Some("cpu"));
         cpu_col.set_fixed_width(80);
         cpu_col.set_resizable(true);
-        let cpu_sorter = gtk::CustomSorter::new(|a, b| {
-            let pa = a.downcast_ref::<ProcessObject>().unwrap();
-            let pb = b.downcast_ref::<ProcessObject>().unwrap();
-            pa.cpu_percent().partial_cmp(&pb.cpu_percent()).unwrap_or(std::cmp::Ordering::Equal).into()
-        });
+        let cpu_sorter = gtk::NumericSorter::new(Some(property_expression("cpu-percent")));
         cpu_col.set_sorter(Some(&cpu_sorter));
         column_view.append_column(&cpu_col);
 
+        // CPU history sparkline, from the per-app history
+        let spark_factory = gtk::SignalListItemFactory::new();
+        spark_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let area = gtk::DrawingArea::new();
+            area.set_content_width(80);
+            area.set_content_height(18);
+            // Draws whatever row the list item is currently bound to
+            let item_weak = item.downgrade();
+            area.set_draw_func(move |_, cr, w, h| {
+                if let Some(item) = item_weak.upgrade().filter(|i| i.item().is_some()) {
+                    draw_sparkline(cr, w as f64, h as f64, &get_process_obj(&item).shown_cpu_history());
+                }
+            });
+            item.set_child(Some(&area));
+        });
+        connect_live_bind(&spark_factory, "updated", |item, _| {
+            item.child().and_downcast::<gtk::DrawingArea>().unwrap().queue_draw();
+        });
+        let spark_col = gtk::ColumnViewColumn::new(Some("CPU History"), Some(spark_factory));
+        spark_col.set_id(Some("cpu_history"));
+        spark_col.set_fixed_width(90);
+        spark_col.set_resizable(true);
+        column_view.append_column(&spark_col);
+
         // Memory column
         let mem_factory = gtk::SignalListItemFactory::new();
         mem_factory.connect_setup(|_, item| {
@@ -401,17 +882,20 @@ impl ProcessTab {
             label.set_halign(gtk::Align::End);
             item.set_child(Some(&label));
         });
-        mem_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        let total_memory: Rc<Cell<u64>> = Rc::new(Cell::new(0));
+        let total_memory_for_bind = total_memory.clone();
+        connect_live_bind(&mem_factory, "notify::memory-bytes", move |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
             let memory_bytes = obj.memory_bytes();
             label.set_text(&util::format_bytes(memory_bytes));
 
-            // Calculate memory percentage (assume 16GB system total for coloring)
-            // This is approximate - ideally should get from SystemSnapshot
-            let total_memory_bytes = 16u64 * 1024 * 1024 * 1024; // 16GB
-            let memory_percent = (memory_bytes as f64 / total_memory_bytes as f64) * 100.0;
+            // Share of physical RAM (no coloring until the first snapshot arrives)
+            let total_memory_bytes = total_memory_for_bind.get();
+            let memory_percent = if total_memory_bytes > 0 {
+                (memory_bytes as f64 / total_memory_bytes as f64) * 100.0
+            } else {
+                0.0
+            };
 
             // Remove previous level classes
             label.remove_css_class("resource-low");
@@ -420,24 +904,70 @@ impl ProcessTab {
             label.remove_css_class("resource-critical");
 
             // Add class based on memory usage
-            if memory_percent > 6.25 { // > 1GB
+            if memory_percent > 6.25 { // 1 GB of 16 GB
                 label.add_css_class("resource-critical");
-            } else if memory_percent > 3.125 { // > 512MB
+            } else if memory_percent > 3.125 { // 512 MB of 16 GB
                 label.add_css_class("resource-high");
-            } else if memory_percent > 1.25 { // > 200MB
+            } else if memory_percent > 1.25 { // 200 MB of 16 GB
                 label.add_css_class("resource-medium");
             }
         });
         let mem_col = gtk::ColumnViewColumn::new(Some("Memory"), Some(mem_factory));
+        mem_col.set_id(Some("memory"));
         mem_col.set_fixed_width(100);
         mem_col.set_resizable(true);
-        let mem_sorter = gtk::CustomSorter::new(|a, b| {
+        let mem_sorter = gtk::NumericSorter::new(Some(property_expression("memory-bytes")));
+        mem_col.set_sorter(Some(&mem_sorter));
+        column_view.append_column(&mem_col);
+
+        // PSS column (shared pages split between the processes mapping them)
+        let pss_factory = gtk::SignalListItemFactory::new();
+        pss_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&pss_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&column_text(&obj, "PSS"));
+        });
+        let pss_col = gtk::ColumnViewColumn::new(Some("PSS"), Some(pss_factory));
+        pss_col.set_id(Some("pss"));
+        pss_col.set_visible(config.borrow().measure_pss);
+        pss_col.set_fixed_width(100);
+        pss_col.set_resizable(true);
+        let pss_sorter = gtk::CustomSorter::new(|a, b| {
             let pa = a.downcast_ref::<ProcessObject>().unwrap();
             let pb = b.downcast_ref::<ProcessObject>().unwrap();
-            pa.memory_bytes().cmp(&pb.memory_bytes()).into()
+            pa.pss_bytes().cmp(&pb.pss_bytes()).into()
         });
-        mem_col.set_sorter(Some(&mem_sorter));
-        column_view.append_column(&mem_col);
+        pss_col.set_sorter(Some(&pss_sorter));
+        column_view.append_column(&pss_col);
+
+        // Swap column
+        let swap_factory = gtk::SignalListItemFactory::new();
+        swap_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&swap_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&column_text(&obj, "Swap"));
+        });
+        let swap_col = gtk::ColumnViewColumn::new(Some("Swap"), Some(swap_factory));
+        swap_col.set_id(Some("swap"));
+        swap_col.set_fixed_width(90);
+        swap_col.set_resizable(true);
+        let swap_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.swap_bytes().cmp(&pb.swap_bytes()).into()
+        });
+        swap_col.set_sorter(Some(&swap_sorter));
+        column_view.append_column(&swap_col);
 
         // VRAM column
         let vram_factory = gtk::SignalListItemFactory::new();
@@ -447,9 +977,7 @@ impl ProcessTab {
             label.set_halign(gtk::Align::End);
             item.set_child(Some(&label));
         });
-        vram_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&vram_factory, "updated", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
             let vram = obj.vram_bytes();
             if vram > 0 {
@@ -459,6 +987,7 @@ impl ProcessTab {
             }
         });
         let vram_col = gtk::ColumnViewColumn::new(Some("VRAM"), Some(vram_factory));
+        vram_col.set_id(Some("vram"));
         vram_col.set_fixed_width(90);
         vram_col.set_resizable(true);
         let vram_sorter = gtk::CustomSorter::new(|a, b| {
@@ -477,13 +1006,12 @@ impl ProcessTab {
             label.set_halign(gtk::Align::End);
             item.set_child(Some(&label));
         });
-        dr_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&dr_factory, "updated", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
             label.set_text(&util::format_bytes_rate(obj.disk_read_rate()));
         });
         let dr_col = gtk::ColumnViewColumn::new(Some("Disk Read"), Some(dr_factory));
+        dr_col.set_id(Some("disk_read"));
         dr_col.set_fixed_width(100);
         dr_col.set_resizable(true);
         let dr_sorter = gtk::CustomSorter::new(|a, b| {
@@ -502,13 +1030,12 @@ impl ProcessTab {
             label.set_halign(gtk::Align::End);
             item.set_child(Some(&label));
         });
-        dw_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&dw_factory, "updated", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
             label.set_text(&util::format_bytes_rate(obj.disk_write_rate()));
         });
         let dw_col = gtk::ColumnViewColumn::new(Some("Disk Write"), Some(dw_factory));
+        dw_col.set_id(Some("disk_write"));
         dw_col.set_fixed_width(100);
         dw_col.set_resizable(true);
         let dw_sorter = gtk::CustomSorter::new(|a, b| {
@@ -519,6 +1046,151 @@ impl ProcessTab {
         dw_col.set_sorter(Some(&dw_sorter));
         column_view.append_column(&dw_col);
 
+        // Disk column (read + write), what the Disk activity view ranks by
+        let disk_factory = gtk::SignalListItemFactory::new();
+        disk_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&disk_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&util::format_bytes_rate(obj.disk_rate()));
+        });
+        let disk_col = gtk::ColumnViewColumn::new(Some("Disk"), Some(disk_factory));
+        disk_col.set_id(Some("disk"));
+        disk_col.set_fixed_width(100);
+        disk_col.set_resizable(true);
+        let disk_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.disk_rate().partial_cmp(&pb.disk_rate()).unwrap_or(std::cmp::Ordering::Equal).into()
+        });
+        disk_col.set_sorter(Some(&disk_sorter));
+        column_view.append_column(&disk_col);
+
+        // Net Down column
+        let nrx_factory = gtk::SignalListItemFactory::new();
+        nrx_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&nrx_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&util::format_bytes_rate(obj.net_rx_rate()));
+        });
+        let nrx_col = gtk::ColumnViewColumn::new(Some("Net Down"), Some(nrx_factory));
+        nrx_col.set_id(Some("net_down"));
+        nrx_col.set_fixed_width(100);
+        nrx_col.set_resizable(true);
+        let nrx_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.net_rx_rate().partial_cmp(&pb.net_rx_rate()).unwrap_or(std::cmp::Ordering::Equal).into()
+        });
+        nrx_col.set_sorter(Some(&nrx_sorter));
+        column_view.append_column(&nrx_col);
+
+        // Net Up column
+        let ntx_factory = gtk::SignalListItemFactory::new();
+        ntx_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&ntx_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&util::format_bytes_rate(obj.net_tx_rate()));
+        });
+        let ntx_col = gtk::ColumnViewColumn::new(Some("Net Up"), Some(ntx_factory));
+        ntx_col.set_id(Some("net_up"));
+        ntx_col.set_fixed_width(100);
+        ntx_col.set_resizable(true);
+        let ntx_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.net_tx_rate().partial_cmp(&pb.net_tx_rate()).unwrap_or(std::cmp::Ordering::Equal).into()
+        });
+        ntx_col.set_sorter(Some(&ntx_sorter));
+        column_view.append_column(&ntx_col);
+
+        // Connections column (open TCP/UDP sockets)
+        let conn_factory = gtk::SignalListItemFactory::new();
+        conn_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&conn_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&obj.connection_count().to_string());
+        });
+        let conn_col = gtk::ColumnViewColumn::new(Some("Connections"), Some(conn_factory));
+        conn_col.set_id(Some("connections"));
+        conn_col.set_fixed_width(90);
+        conn_col.set_resizable(true);
+        let conn_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.connection_count().cmp(&pb.connection_count()).into()
+        });
+        conn_col.set_sorter(Some(&conn_sorter));
+        column_view.append_column(&conn_col);
+
+        // Threads column
+        let threads_factory = gtk::SignalListItemFactory::new();
+        threads_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&threads_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&obj.threads().to_string());
+        });
+        let threads_col = gtk::ColumnViewColumn::new(Some("Threads"), Some(threads_factory));
+        threads_col.set_id(Some("threads"));
+        threads_col.set_fixed_width(80);
+        threads_col.set_resizable(true);
+        let threads_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.threads().cmp(&pb.threads()).into()
+        });
+        threads_col.set_sorter(Some(&threads_sorter));
+        column_view.append_column(&threads_col);
+
+        // FDs column (open file descriptors; a steady climb hints at a leak)
+        let fds_factory = gtk::SignalListItemFactory::new();
+        fds_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&fds_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            // Other users' fd directories aren't readable
+            label.set_text(&if obj.fd_count() > 0 { obj.fd_count().to_string() } else { "—".to_string() });
+        });
+        let fds_col = gtk::ColumnViewColumn::new(Some("FDs"), Some(fds_factory));
+        fds_col.set_id(Some("fds"));
+        fds_col.set_fixed_width(70);
+        fds_col.set_resizable(true);
+        let fds_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.fd_count().cmp(&pb.fd_count()).into()
+        });
+        fds_col.set_sorter(Some(&fds_sorter));
+        column_view.append_column(&fds_col);
+
         // State column
         let state_factory = gtk::SignalListItemFactory::new();
         state_factory.connect_setup(|_, item| {
@@ -527,23 +1199,79 @@ impl ProcessTab {
             label.set_halign(gtk::Align::Center);
             item.set_child(Some(&label));
         });
-        state_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&state_factory, "notify::state", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
-            label.set_text(&obj.state());
+            let state = obj.state();
+            label.set_text(util::process_state_label(&state));
+            // A pile of zombies usually means a parent that never reaps them
+            if state == "Z" {
+                label.add_css_class("process-zombie");
+            } else {
+                label.remove_css_class("process-zombie");
+            }
         });
         let state_col = gtk::ColumnViewColumn::new(Some("State"), Some(state_factory));
-        state_col.set_fixed_width(60);
+        state_col.set_id(Some("state"));
+        state_col.set_fixed_width(90);
         state_col.set_resizable(true);
         let state_sorter = gtk::CustomSorter::new(|a, b| {
             let pa = a.downcast_ref::<ProcessObject>().unwrap();
             let pb = b.downcast_ref::<ProcessObject>().unwrap();
-            pa.state().cmp(&pb.state()).into()
+            util::process_state_label(&pa.state()).cmp(util::process_state_label(&pb.state())).into()
         });
         state_col.set_sorter(Some(&state_sorter));
         column_view.append_column(&state_col);
 
+        // Elapsed (running time) column
+        let elapsed_factory = gtk::SignalListItemFactory::new();
+        elapsed_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::End);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&elapsed_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&elapsed_text(obj.started_at()));
+            label.set_tooltip_text(Some(&format!("Started {}", start_time_text(obj.started_at()))));
+        });
+        let elapsed_col = gtk::ColumnViewColumn::new(Some("Elapsed"), Some(elapsed_factory));
+        elapsed_col.set_id(Some("elapsed"));
+        elapsed_col.set_fixed_width(90);
+        elapsed_col.set_resizable(true);
+        // Most recently started first when ascending
+        let elapsed_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pb.started_at().cmp(&pa.started_at()).into()
+        });
+        elapsed_col.set_sorter(Some(&elapsed_sorter));
+        column_view.append_column(&elapsed_col);
+
+        // I/O priority column
+        let io_factory = gtk::SignalListItemFactory::new();
+        io_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::Start);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&io_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            label.set_text(&obj.io_priority_text());
+        });
+        let io_col = gtk::ColumnViewColumn::new(Some("I/O Priority"), Some(io_factory));
+        io_col.set_id(Some("io_priority"));
+        io_col.set_fixed_width(110);
+        io_col.set_resizable(true);
+        let io_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.io_priority_text().cmp(&pb.io_priority_text()).into()
+        });
+        io_col.set_sorter(Some(&io_sorter));
+        column_view.append_column(&io_col);
+
         // Path column
         let path_factory = gtk::SignalListItemFactory::new();
         path_factory.connect_setup(|_, item| {
@@ -553,13 +1281,12 @@ impl ProcessTab {
             label.set_ellipsize(gtk::pango::EllipsizeMode::Middle);
             item.set_child(Some(&label));
         });
-        path_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&path_factory, "updated", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
             label.set_text(&obj.exe_path());
         });
         let path_col = gtk::ColumnViewColumn::new(Some("Path"), Some(path_factory));
+        path_col.set_id(Some("path"));
         path_col.set_fixed_width(200);
         path_col.set_resizable(true);
         let path_sorter = gtk::CustomSorter::new(|a, b| {
@@ -570,27 +1297,61 @@ impl ProcessTab {
         path_col.set_sorter(Some(&path_sorter));
         column_view.append_column(&path_col);
 
+        // Command column: full argv, to tell apart many python/java processes
+        let command_factory = gtk::SignalListItemFactory::new();
+        command_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::Start);
+            label.set_ellipsize(gtk::pango::EllipsizeMode::Middle);
+            item.set_child(Some(&label));
+        });
+        connect_live_bind(&command_factory, "updated", |item, obj| {
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+            let command = obj.command();
+            if command.is_empty() {
+                label.set_text("—");
+                label.set_tooltip_text(None);
+            } else {
+                label.set_text(&command);
+                label.set_tooltip_text(Some(&command));
+            }
+        });
+        let command_col = gtk::ColumnViewColumn::new(Some("Command"), Some(command_factory));
+        command_col.set_id(Some("command"));
+        command_col.set_fixed_width(260);
+        command_col.set_resizable(true);
+        let command_sorter = gtk::CustomSorter::new(|a, b| {
+            let pa = a.downcast_ref::<ProcessObject>().unwrap();
+            let pb = b.downcast_ref::<ProcessObject>().unwrap();
+            pa.command().cmp(&pb.command()).into()
+        });
+        command_col.set_sorter(Some(&command_sorter));
+        column_view.append_column(&command_col);
+
         // Container column
         let container_factory = gtk::SignalListItemFactory::new();
         container_factory.connect_setup(|_, item| {
             let item = item.downcast_ref::<gtk::ListItem>().unwrap();
             let label = gtk::Label::new(None);
             label.set_halign(gtk::Align::Start);
+            label.set_ellipsize(gtk::pango::EllipsizeMode::End);
             item.set_child(Some(&label));
         });
-        container_factory.connect_bind(|_, item| {
-            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
-            let obj = get_process_obj(item);
+        connect_live_bind(&container_factory, "updated", |item, obj| {
             let label = item.child().and_downcast::<gtk::Label>().unwrap();
             let ct = obj.container_type();
             if ct.is_empty() {
                 label.set_text("—");
+                label.set_tooltip_text(None);
             } else {
                 label.set_text(&ct);
+                label.set_tooltip_text(Some(&ct));
             }
         });
         let container_col = gtk::ColumnViewColumn::new(Some("Container"), Some(container_factory));
-        container_col.set_fixed_width(90);
+        container_col.set_id(Some("container"));
+        container_col.set_fixed_width(150);
         container_col.set_resizable(true);
         let container_sorter = gtk::CustomSorter::new(|a, b| {
             let pa = a.downcast_ref::<ProcessObject>().unwrap();
@@ -606,6 +1367,19 @@ impl ProcessTab {
             sort_model.set_sorter(Some(&tree_sorter));
         }
 
+        // Restore the last sort and remember changes
+        let saved_sort = {
+            let cfg = config.borrow();
+            TableSort { column: cfg.sort_column.clone(), ascending: cfg.sort_ascending }
+        };
+        let config_for_sort = config.clone();
+        column_sort::restore_and_track(&column_view, Some(saved_sort), move |sort| {
+            let mut cfg = config_for_sort.borrow_mut();
+            cfg.sort_column = sort.column;
+            cfg.sort_ascending = sort.ascending;
+            cfg.save();
+        });
+
         // Scroll window
         let scroll = gtk::ScrolledWindow::builder()
             .vexpand(true)
@@ -618,12 +1392,19 @@ impl ProcessTab {
         // Context menu
         let children_cache: Rc<RefCell<HashMap<i32, Vec<crate::model::ProcessInfo>>>> =
             Rc::new(RefCell::new(HashMap::new()));
+        let process_tree: Rc<RefCell<HashMap<i32, Vec<i32>>>> = Rc::new(RefCell::new(HashMap::new()));
 
         let menu = gio::Menu::new();
         menu.append(Some("End Task"), Some("process.kill-term"));
         menu.append(Some("Force Kill"), Some("process.kill-force"));
+        menu.append(Some("Restart"), Some("process.restart"));
+        menu.append(Some("Kill Process Tree"), Some("process.kill-tree"));
         menu.append(Some("End Group"), Some("process.kill-group"));
+        menu.append(Some("Pause Group"), Some("process.pause-group"));
+        menu.append(Some("Resume Group"), Some("process.resume-group"));
         menu.append(Some("Open File Location"), Some("process.open-location"));
+        menu.append(Some("Open in Terminal at CWD"), Some("process.open-terminal"));
+        menu.append(Some("Open /proc Directory"), Some("process.open-proc"));
 
         let nice_menu = gio::Menu::new();
         nice_menu.append(Some("Very High (-20)"), Some("process.nice-neg20"));
@@ -631,8 +1412,26 @@ impl ProcessTab {
         nice_menu.append(Some("Normal (0)"), Some("process.nice-0"));
         nice_menu.append(Some("Low (10)"), Some("process.nice-10"));
         nice_menu.append(Some("Very Low (19)"), Some("process.nice-19"));
+        let remember_section = gio::Menu::new();
+        remember_section.append(Some("Remember for This Executable"), Some("process.nice-remember"));
+        nice_menu.append_section(None, &remember_section);
         menu.append_submenu(Some("Set Priority"), &nice_menu);
 
+        let io_menu = gio::Menu::new();
+        io_menu.append(Some("Realtime"), Some("process.ioprio-rt"));
+        io_menu.append(Some("Best-effort: High (0)"), Some("process.ioprio-be-high"));
+        io_menu.append(Some("Best-effort: Normal (4)"), Some("process.ioprio-be-normal"));
+        io_menu.append(Some("Best-effort: Low (7)"), Some("process.ioprio-be-low"));
+        io_menu.append(Some("Idle"), Some("process.ioprio-idle"));
+        menu.append_submenu(Some("IO Priority"), &io_menu);
+
+        // Create "OOM Killer" submenu
+        let oom_menu = gio::Menu::new();
+        oom_menu.append(Some("Protect from OOM (-1000)"), Some("process.oom-protect"));
+        oom_menu.append(Some("Default (0)"), Some("process.oom-default"));
+        oom_menu.append(Some("Sacrifice First (1000)"), Some("process.oom-sacrifice"));
+        menu.append_submenu(Some("OOM Killer"), &oom_menu);
+
         // Create "Send Signal" submenu
         let signal_menu = gio::Menu::new();
         signal_menu.append(Some("SIGSTOP (Pause)"), Some("process.signal-stop"));
@@ -641,39 +1440,74 @@ impl ProcessTab {
         signal_menu.append(Some("SIGINT (Interrupt)"), Some("process.signal-int"));
         signal_menu.append(Some("SIGUSR1"), Some("process.signal-usr1"));
         signal_menu.append(Some("SIGUSR2"), Some("process.signal-usr2"));
+        signal_menu.append(Some("Reap Zombie (SIGCHLD to Parent)"), Some("process.reap-zombie"));
         menu.append_submenu(Some("Send Signal"), &signal_menu);
 
+        let affinity_menu = gio::Menu::new();
+        affinity_menu.append(Some("Choose Cores…"), Some("process.affinity-choose"));
+        affinity_menu.append(Some("All Cores"), Some("process.affinity-all"));
+        menu.append_submenu(Some("Set CPU Affinity"), &affinity_menu);
+
+        let copy_menu = gio::Menu::new();
+        copy_menu.append(Some("Copy PID"), Some("process.copy-pid"));
+        copy_menu.append(Some("Copy Name"), Some("process.copy-name"));
+        copy_menu.append(Some("Copy Command Line"), Some("process.copy-cmdline"));
+        copy_menu.append(Some("Copy /proc Path"), Some("process.copy-proc-path"));
+        copy_menu.append(Some("Copy as Row"), Some("process.copy-row"));
+        menu.append_submenu(Some("Copy"), &copy_menu);
+
         let popover = gtk::PopoverMenu::from_model(Some(&menu));
         popover.set_parent(&column_view);
         popover.set_has_arrow(false);
 
         // Action group
         let action_group = gio::SimpleActionGroup::new();
+        let kill_prefs = KillPrefs { config: config.clone(), skip_confirm: Rc::new(Cell::new(false)) };
 
         let sel_clone = selection.clone();
         let cv_ref = column_view.clone();
+        let prefs_for_term = kill_prefs.clone();
         let kill_term = gio::SimpleAction::new("kill-term", None);
         kill_term.connect_activate(move |_, _| {
-            if let Some(obj) = selected_process(&sel_clone) {
-                kill_process(obj.pid(), obj.display_name(), nix::sys::signal::Signal::SIGTERM, &cv_ref);
-            }
+            kill_processes(&selected_processes(&sel_clone), nix::sys::signal::Signal::SIGTERM, &cv_ref, &prefs_for_term);
         });
         action_group.add_action(&kill_term);
 
         let sel_clone2 = selection.clone();
         let cv_ref2 = column_view.clone();
+        let prefs_for_force = kill_prefs.clone();
         let kill_force = gio::SimpleAction::new("kill-force", None);
         kill_force.connect_activate(move |_, _| {
-            if let Some(obj) = selected_process(&sel_clone2) {
-                kill_process(obj.pid(), obj.display_name(), nix::sys::signal::Signal::SIGKILL, &cv_ref2);
-            }
+            kill_processes(&selected_processes(&sel_clone2), nix::sys::signal::Signal::SIGKILL, &cv_ref2, &prefs_for_force);
         });
         action_group.add_action(&kill_force);
 
-        let sel_clone3 = selection.clone();
-        let open_loc = gio::SimpleAction::new("open-location", None);
-        open_loc.connect_activate(move |_, _| {
-            if let Some(obj) = selected_process(&sel_clone3) {
+        let sel_for_restart = selection.clone();
+        let cv_for_restart = column_view.clone();
+        let restart = gio::SimpleAction::new("restart", None);
+        restart.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_for_restart) {
+                restart_process(obj.pid(), obj.display_name(), &cv_for_restart);
+            }
+        });
+        action_group.add_action(&restart);
+
+        let sel_for_tree = selection.clone();
+        let cv_for_tree = column_view.clone();
+        let tree_for_kill = process_tree.clone();
+        let prefs_for_tree = kill_prefs.clone();
+        let kill_tree = gio::SimpleAction::new("kill-tree", None);
+        kill_tree.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_for_tree) {
+                kill_process_tree(obj.pid(), obj.display_name(), &tree_for_kill.borrow(), &cv_for_tree, &prefs_for_tree);
+            }
+        });
+        action_group.add_action(&kill_tree);
+
+        let sel_clone3 = selection.clone();
+        let open_loc = gio::SimpleAction::new("open-location", None);
+        open_loc.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_clone3) {
                 let path = obj.exe_path();
                 if let Some(dir) = std::path::Path::new(&path).parent() {
                     let _ = std::process::Command::new("xdg-open")
@@ -684,14 +1518,112 @@ impl ProcessTab {
         });
         action_group.add_action(&open_loc);
 
-        // Nice actions
+        let sel_for_term = selection.clone();
+        let cv_for_term = column_view.clone();
+        let open_term = gio::SimpleAction::new("open-terminal", None);
+        open_term.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_for_term) {
+                open_terminal_at_cwd(obj.pid(), &obj.display_name(), &cv_for_term);
+            }
+        });
+        action_group.add_action(&open_term);
+
+        let sel_for_proc = selection.clone();
+        let open_proc = gio::SimpleAction::new("open-proc", None);
+        open_proc.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_for_proc) {
+                let _ = std::process::Command::new("xdg-open")
+                    .arg(format!("/proc/{}", obj.pid()))
+                    .spawn();
+            }
+        });
+        action_group.add_action(&open_proc);
+
+        // Nice actions. A remembered executable's rule follows the new value.
         for (suffix, value) in [("neg20", -20), ("neg10", -10), ("0", 0), ("10", 10), ("19", 19)] {
             let sel_c = selection.clone();
             let cv_c = column_view.clone();
+            let config_c = config.clone();
+            let rules_c = priority_rules.clone();
             let action = gio::SimpleAction::new(&format!("nice-{}", suffix), None);
             action.connect_activate(move |_, _| {
                 if let Some(obj) = selected_process(&sel_c) {
-                    set_priority(obj.pid(), obj.display_name(), value, &cv_c);
+                    let exe = obj.exe_path();
+                    if set_priority(obj.pid(), obj.display_name(), value, &cv_c)
+                        && config_c.borrow().priority_rules.contains_key(&exe)
+                    {
+                        save_priority_rule(&config_c, &rules_c, &exe, Some(value));
+                    }
+                }
+            });
+            action_group.add_action(&action);
+        }
+
+        // Remember the current nice value for every future process of the executable
+        let remember_action = gio::SimpleAction::new_stateful("nice-remember", None, &false.to_variant());
+        {
+            let sel_c = selection.clone();
+            let config_c = config.clone();
+            let rules_c = priority_rules.clone();
+            remember_action.connect_change_state(move |action, value| {
+                let Some(remember) = value.and_then(|v| v.get::<bool>()) else {
+                    return;
+                };
+                let Some(obj) = selected_process(&sel_c) else {
+                    return;
+                };
+                let exe = obj.exe_path();
+                if exe.is_empty() {
+                    return;
+                }
+                save_priority_rule(&config_c, &rules_c, &exe, remember.then(|| obj.nice()));
+                action.set_state(&remember.to_variant());
+            });
+        }
+        action_group.add_action(&remember_action);
+        {
+            let config_c = config.clone();
+            selection.connect_selection_changed(move |sel, _, _| {
+                let selected = selected_process(sel);
+                let exe = selected.map(|obj| obj.exe_path()).unwrap_or_default();
+                remember_action.set_enabled(!exe.is_empty());
+                remember_action.set_state(&config_c.borrow().priority_rules.contains_key(&exe).to_variant());
+            });
+        }
+
+        // I/O priority actions
+        let io_actions = [
+            ("rt", ioprio::IOPRIO_CLASS_RT, 4),
+            ("be-high", ioprio::IOPRIO_CLASS_BE, 0),
+            ("be-normal", ioprio::IOPRIO_CLASS_BE, 4),
+            ("be-low", ioprio::IOPRIO_CLASS_BE, 7),
+            ("idle", ioprio::IOPRIO_CLASS_IDLE, 0),
+        ];
+        for (suffix, class, level) in io_actions {
+            let sel_c = selection.clone();
+            let cv_c = column_view.clone();
+            let action = gio::SimpleAction::new(&format!("ioprio-{}", suffix), None);
+            action.connect_activate(move |_, _| {
+                if let Some(obj) = selected_process(&sel_c) {
+                    set_io_priority(obj.pid(), obj.display_name(), class, level, &cv_c);
+                }
+            });
+            action_group.add_action(&action);
+        }
+
+        // OOM score adjustment actions
+        let oom_actions = [
+            ("protect", oom::OOM_SCORE_ADJ_MIN),
+            ("default", 0),
+            ("sacrifice", oom::OOM_SCORE_ADJ_MAX),
+        ];
+        for (suffix, adj) in oom_actions {
+            let sel_c = selection.clone();
+            let cv_c = column_view.clone();
+            let action = gio::SimpleAction::new(&format!("oom-{}", suffix), None);
+            action.connect_activate(move |_, _| {
+                if let Some(obj) = selected_process(&sel_c) {
+                    set_oom_score_adj(obj.pid(), obj.display_name(), adj, &cv_c);
                 }
             });
             action_group.add_action(&action);
@@ -710,59 +1642,119 @@ impl ProcessTab {
         for (name, sig) in signal_actions {
             let sel_c = selection.clone();
             let cv_c = column_view.clone();
+            let prefs_c = kill_prefs.clone();
             let action = gio::SimpleAction::new(&format!("signal-{}", name), None);
             action.connect_activate(move |_, _| {
                 if let Some(obj) = selected_process(&sel_c) {
-                    send_signal(obj.pid(), obj.display_name(), sig, &cv_c);
+                    send_signal(obj.pid(), obj.display_name(), sig, &cv_c, &prefs_c);
                 }
             });
             action_group.add_action(&action);
         }
 
-        // Kill Group action
-        let children_cache_for_kill = children_cache.clone();
-        let sel_for_kill_group = selection.clone();
-        let cv_for_kill_group = column_view.clone();
-        let kill_group = gio::SimpleAction::new("kill-group", None);
-        kill_group.set_enabled(false);
-        kill_group.connect_activate(move |_, _| {
-            if let Some(obj) = selected_process(&sel_for_kill_group) {
-                if obj.is_group() && obj.child_count() > 0 {
-                    let leader_pid = obj.pid();
-                    let name = obj.display_name();
-                    if is_critical_process(leader_pid) {
-                        show_error_dialog(&cv_for_kill_group,
-                            &format!("Cannot kill group \"{}\" — leader (PID {}) is a critical system process.",
-                                name, leader_pid));
-                        return;
-                    }
-                    let cache = children_cache_for_kill.borrow();
-                    if let Some(children) = cache.get(&leader_pid) {
-                        // Kill children first (reverse order), then leader
-                        for child in children.iter().rev() {
-                            let _ = nix::sys::signal::kill(
-                                nix::unistd::Pid::from_raw(child.pid),
-                                nix::sys::signal::Signal::SIGKILL,
-                            );
+        let sel_for_reap = selection.clone();
+        let cv_for_reap = column_view.clone();
+        let reap = gio::SimpleAction::new("reap-zombie", None);
+        reap.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_for_reap) {
+                reap_zombie(&obj, &cv_for_reap);
+            }
+        });
+        action_group.add_action(&reap);
+
+        // CPU affinity actions
+        let core_count: Rc<Cell<usize>> = Rc::new(Cell::new(0));
+
+        let sel_for_aff = selection.clone();
+        let cv_for_aff = column_view.clone();
+        let cores_for_aff = core_count.clone();
+        let affinity_choose = gio::SimpleAction::new("affinity-choose", None);
+        affinity_choose.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_for_aff) {
+                show_affinity_dialog(&cv_for_aff, &obj, cores_for_aff.get());
+            }
+        });
+        action_group.add_action(&affinity_choose);
+
+        let sel_for_aff_all = selection.clone();
+        let cv_for_aff_all = column_view.clone();
+        let cores_for_aff_all = core_count.clone();
+        let affinity_all = gio::SimpleAction::new("affinity-all", None);
+        affinity_all.connect_activate(move |_, _| {
+            if let Some(obj) = selected_process(&sel_for_aff_all) {
+                let cores: Vec<usize> = (0..cores_for_aff_all.get().max(1)).collect();
+                set_affinity(obj.pid(), obj.display_name(), &cores, &cv_for_aff_all);
+            }
+        });
+        action_group.add_action(&affinity_all);
+
+        // Copy actions (one line per selected process)
+        let copy_actions: [(&str, fn(&ProcessObject, &gtk::ColumnView) -> String); 5] = [
+            ("pid", |obj, _| obj.pid().to_string()),
+            ("name", |obj, _| obj.display_name()),
+            ("cmdline", |obj, _| obj.command()),
+            ("proc-path", |obj, _| format!("/proc/{}", obj.pid())),
+            ("row", row_as_text),
+        ];
+
+        for (name, to_text) in copy_actions {
+            let sel_c = selection.clone();
+            let cv_c = column_view.clone();
+            let action = gio::SimpleAction::new(&format!("copy-{}", name), None);
+            action.connect_activate(move |_, _| {
+                let lines: Vec<String> = selected_processes(&sel_c)
+                    .iter()
+                    .map(|obj| to_text(obj, &cv_c))
+                    .collect();
+                if !lines.is_empty() {
+                    cv_c.display().clipboard().set_text(&lines.join("\n"));
+                }
+            });
+            action_group.add_action(&action);
+        }
+
+        // End/Pause/Resume Group actions: signal the leader and every descendant
+        let mut group_actions = Vec::new();
+        for (name, signal, verb) in [
+            ("kill-group", nix::sys::signal::Signal::SIGKILL, "kill"),
+            ("pause-group", nix::sys::signal::Signal::SIGSTOP, "pause"),
+            ("resume-group", nix::sys::signal::Signal::SIGCONT, "resume"),
+        ] {
+            let children_cache_for_group = children_cache.clone();
+            let sel_for_group = selection.clone();
+            let cv_for_group = column_view.clone();
+            let action = gio::SimpleAction::new(name, None);
+            action.set_enabled(false);
+            action.connect_activate(move |_, _| {
+                if let Some(obj) = selected_process(&sel_for_group) {
+                    if obj.is_group() && obj.child_count() > 0 {
+                        let leader_pid = obj.pid();
+                        let name = obj.display_name();
+                        if is_critical_process(leader_pid) {
+                            show_error_dialog(&cv_for_group,
+                                &format!("Cannot {} group \"{}\" — leader (PID {}) is a critical system process.",
+                                    verb, name, leader_pid));
+                            return;
                         }
+                        let count = signal_group(&children_cache_for_group.borrow(), leader_pid, signal);
+                        log::info!("Sent {} to group '{}' (leader PID {}, {} processes)",
+                            signal, name, leader_pid, count);
                     }
-                    let _ = nix::sys::signal::kill(
-                        nix::unistd::Pid::from_raw(leader_pid),
-                        nix::sys::signal::Signal::SIGKILL,
-                    );
-                    log::info!("Killed group '{}' (leader PID {})", name, leader_pid);
                 }
-            }
-        });
-        action_group.add_action(&kill_group);
+            });
+            action_group.add_action(&action);
+            group_actions.push(action);
+        }
 
-        // Dynamically enable/disable kill-group based on selection
-        let kill_group_for_sel = kill_group.clone();
-        selection.connect_notify_local(Some("selected"), move |sel, _| {
-            let enabled = selected_process(sel)
-                .map(|obj| obj.is_group() && obj.child_count() > 0)
-                .unwrap_or(false);
-            kill_group_for_sel.set_enabled(enabled);
+        // Group actions only apply to a single group leader with children
+        selection.connect_selection_changed(move |sel, _, _| {
+            let selected = selected_processes(sel);
+            let enabled = selected.len() == 1
+                && selected[0].is_group()
+                && selected[0].child_count() > 0;
+            for action in &group_actions {
+                action.set_enabled(enabled);
+            }
         });
 
         column_view.insert_action_group("process", Some(&action_group));
@@ -778,11 +1770,23 @@ impl ProcessTab {
         });
         column_view.add_controller(gesture);
 
+        for entry in &top_entries {
+            let pid = entry.pid.clone();
+            let sel_for_top = selection.clone();
+            let cv_for_top = column_view.clone();
+            entry.button.connect_clicked(move |_| {
+                if !select_pid(&sel_for_top, &cv_for_top, pid.get()) {
+                    log::debug!("PID {} is not visible in the process list", pid.get());
+                }
+            });
+        }
+
         // Keyboard shortcuts
         let key_controller = gtk::EventControllerKey::new();
         let search_entry_clone = search_entry.clone();
         let sel_for_keys = selection.clone();
         let cv_for_keys = column_view.clone();
+        let prefs_for_keys = kill_prefs.clone();
         key_controller.connect_key_pressed(move |_, key, _, modifier| {
             match (key, modifier) {
                 (gtk::gdk::Key::f, gtk::gdk::ModifierType::CONTROL_MASK) => {
@@ -790,9 +1794,7 @@ impl ProcessTab {
                     glib::Propagation::Stop
                 }
                 (gtk::gdk::Key::Delete, _) => {
-                    if let Some(obj) = selected_process(&sel_for_keys) {
-                        kill_process(obj.pid(), obj.display_name(), nix::sys::signal::Signal::SIGTERM, &cv_for_keys);
-                    }
+                    kill_processes(&selected_processes(&sel_for_keys), nix::sys::signal::Signal::SIGTERM, &cv_for_keys, &prefs_for_keys);
                     glib::Propagation::Stop
                 }
                 _ => glib::Propagation::Proceed,
@@ -805,10 +1807,13 @@ impl ProcessTab {
         dbl_gesture.set_button(1);
         let sel_for_dbl = selection.clone();
         let cv_for_dbl = column_view.clone();
+        let snapshot_for_dbl = latest_snapshot.clone();
+        let config_for_dbl = config.clone();
         dbl_gesture.connect_released(move |gesture, n_press, _, _| {
             if n_press == 2 {
                 if let Some(obj) = selected_process(&sel_for_dbl) {
-                    show_process_details(&cv_for_dbl, &obj);
+                    let graph_settings = GraphSettings::from_config(&config_for_dbl.borrow());
+                    show_process_details(&cv_for_dbl, &obj, &snapshot_for_dbl, &graph_settings);
                 }
                 gesture.set_state(gtk::EventSequenceState::Claimed);
             }
@@ -823,29 +1828,196 @@ impl ProcessTab {
             sort_model,
             scroll: scroll_ref,
             children_cache,
+            process_tree,
+            measure_pss,
             child_stores,
+            core_count,
+            total_memory,
+            paused,
+            query,
+            active_only,
+            filter,
+            view,
+            kernel_threads: Cell::new(config.borrow().kernel_threads),
+            cpu_of_total: Cell::new(config.borrow().cpu_of_total),
+            force_update,
+            view_buttons,
+            top_entries,
+        }
+    }
+
+    /// Switch between app groups, unit groups and the process tree, as the
+    /// toolbar buttons do.
+    pub fn set_view(&self, view: ProcessView) {
+        if let Some((_, btn)) = self.view_buttons.iter().find(|(mode, _)| *mode == view) {
+            btn.set_active(true);
         }
     }
 
+    /// A view or setting change is waiting to be applied, which shouldn't
+    /// wait for the next snapshot.
+    pub fn needs_update(&self) -> bool {
+        self.force_update.get()
+    }
+
+    /// Group kernel threads, list them one by one, or hide them. Applies on
+    /// the next update, even while paused.
+    pub fn set_kernel_threads(&self, mode: KernelThreads) {
+        self.kernel_threads.set(mode);
+        self.force_update.set(true);
+    }
+
+    /// Show process CPU as a share of all cores (a fully busy system reads
+    /// 100%) or of one core. Applies on the next update, even while paused.
+    pub fn set_cpu_of_total(&self, of_total: bool) {
+        self.cpu_of_total.set(of_total);
+        self.force_update.set(true);
+    }
+
+    /// What CPU values are divided by for display, per `cpu_of_total`
+    fn cpu_scale(&self) -> f64 {
+        if self.cpu_of_total.get() { self.core_count.get().max(1) as f64 } else { 1.0 }
+    }
+
+    /// Disk activity view: only processes reading or writing right now,
+    /// busiest first.
+    pub fn show_disk_activity(&self) {
+        self.search_entry.set_text("disk>0");
+        let columns = self.column_view.columns();
+        let disk_col = (0..columns.n_items())
+            .filter_map(|i| columns.item(i).and_downcast::<gtk::ColumnViewColumn>())
+            .find(|col| col.id().as_deref() == Some("disk"));
+        if let Some(col) = disk_col {
+            col.set_visible(true);
+            self.column_view.sort_by_column(Some(&col), gtk::SortType::Descending);
+        }
+        self.search_entry.grab_focus();
+    }
+
+    /// Replace the search with `user:<name>` to list that user's processes.
+    pub fn show_user_processes(&self, username: &str) {
+        self.search_entry.set_text(&format!("user:{}", username));
+        self.search_entry.grab_focus();
+    }
+
+    /// Start or stop collecting PSS, showing the PSS column only while it is.
+    pub fn set_measure_pss(&self, measure: bool) {
+        self.measure_pss.store(measure, Ordering::Relaxed);
+        let columns = self.column_view.columns();
+        let pss_col = (0..columns.n_items())
+            .filter_map(|i| columns.item(i).and_downcast::<gtk::ColumnViewColumn>())
+            .find(|col| col.id().as_deref() == Some("pss"));
+        if let Some(col) = pss_col {
+            col.set_visible(measure);
+        }
+    }
+
+    /// Smaller rows and text, to fit more processes on screen.
+    pub fn set_compact(&self, compact: bool) {
+        if compact {
+            self.column_view.add_css_class("compact");
+        } else {
+            self.column_view.remove_css_class("compact");
+        }
+    }
+
+    fn update_top_entries(&self, groups: &[AppGroup]) {
+        let heaviest = |key: fn(&AppGroup) -> f64| {
+            groups
+                .iter()
+                .filter(|g| key(g) > 0.0)
+                .max_by(|a, b| key(a).total_cmp(&key(b)))
+        };
+        let disk = |g: &AppGroup| g.total_disk_read_rate + g.total_disk_write_rate;
+        let [cpu_entry, memory_entry, disk_entry] = &self.top_entries;
+        let cpu_scale = self.cpu_scale();
+        cpu_entry.set(heaviest(|g| g.total_cpu), |g| util::format_percent(g.total_cpu / cpu_scale));
+        memory_entry.set(heaviest(|g| g.total_memory as f64), |g| util::format_bytes(g.total_memory));
+        disk_entry.set(heaviest(disk), |g| util::format_bytes_rate(disk(g)));
+    }
+
     pub fn update(&mut self, snapshot: &SystemSnapshot) {
+        {
+            let mut tree = self.process_tree.borrow_mut();
+            tree.clear();
+            for proc in &snapshot.processes {
+                if proc.ppid != proc.pid {
+                    tree.entry(proc.ppid).or_default().push(proc.pid);
+                }
+            }
+        }
+
+        // A view mode switch rebuilds the list once even while paused
+        let forced = self.force_update.replace(false);
+        if self.paused.get() && !forced {
+            return;
+        }
+
+        self.core_count.set(snapshot.cpu.core_count);
+        self.total_memory.set(snapshot.memory.total);
+
+        let kernel_threads = self.kernel_threads.get();
+        match self.view.get() {
+            ProcessView::Apps => {
+                let groups = arrange_kernel_threads(&snapshot.app_groups, kernel_threads);
+                self.update_top_entries(&groups);
+                self.update_groups(&groups, &snapshot.app_histories);
+            }
+            ProcessView::Units => {
+                let unit_groups = build_unit_groups(&snapshot.processes);
+                let groups = arrange_kernel_threads(&unit_groups, kernel_threads);
+                self.update_top_entries(&groups);
+                self.update_groups(&groups, &HashMap::new());
+            }
+            ProcessView::Tree => {
+                self.update_top_entries(&snapshot.app_groups);
+                self.update_tree(snapshot, kernel_threads == KernelThreads::Hidden);
+            }
+        }
+
+        // Save scroll position before triggering re-sort
+        let vadj = self.scroll.vadjustment();
+        let scroll_pos = vadj.value();
+
+        // Re-run the filter, since terms like cpu>50 and the active-only
+        // toggle depend on live values
+        if !self.query.borrow().is_empty() || self.active_only.get() {
+            self.filter.changed(gtk::FilterChange::Different);
+            let filter = self.filter.clone();
+            self.query.borrow().lookup_env(move || filter.changed(gtk::FilterChange::Different));
+        }
+
+        // Trigger re-sort so columns reflect updated values
+        if let Some(sorter) = self.sort_model.sorter() {
+            sorter.changed(gtk::SorterChange::Different);
+        }
+
+        // Restore scroll position
+        vadj.set_value(scroll_pos);
+    }
+
+    /// App-group mode: one root row per AppGroup, its processes one level below
+    fn update_groups(&mut self, groups: &[AppGroup], histories: &HashMap<String, AppHistory>) {
         // 1. Update children cache (keep for kill-group)
         {
             let mut cache = self.children_cache.borrow_mut();
             cache.clear();
-            for group in &snapshot.app_groups {
+            for group in groups {
                 if !group.children.is_empty() {
                     cache.insert(group.leader.pid, group.children.clone());
                 }
             }
         }
 
+        let cpu_scale = self.cpu_scale();
+
         // 2. Populate/update child_stores BEFORE updating root store
         //    (root store changes can trigger create_func calls)
         {
             let mut stores = self.child_stores.borrow_mut();
             let mut active_pids: std::collections::HashSet<i32> = std::collections::HashSet::new();
 
-            for group in &snapshot.app_groups {
+            for group in groups {
                 if group.children.is_empty() {
                     continue;
                 }
@@ -860,11 +2032,12 @@ impl ProcessTab {
                 for (i, child) in group.children.iter().enumerate() {
                     if i < old_count {
                         if let Some(obj) = child_store.item(i as u32).and_then(|o| o.downcast::<ProcessObject>().ok()) {
-                            obj.set_from_process(child);
+                            obj.set_from_process(child, cpu_scale);
+                            obj.notify_updated();
                         }
                     } else {
                         let obj = ProcessObject::new();
-                        obj.set_from_process(child);
+                        obj.set_from_process(child, cpu_scale);
                         child_store.append(&obj);
                     }
                 }
@@ -879,107 +2052,448 @@ impl ProcessTab {
         }
 
         // 3. PID-stable update of root store
-        //    TreeListModel caches create_func results per position, so we must
-        //    keep items at stable positions (matched by PID) to preserve expansion state.
-        //    Items that are new get appended; items that disappeared get removed.
-        let new_groups: Vec<&crate::model::AppGroup> = snapshot.app_groups.iter().collect();
-
-        // Build PID->position map of current store
-        let mut current_pids: HashMap<i32, u32> = HashMap::new();
-        for i in 0..self.store.n_items() {
-            if let Some(obj) = self.store.item(i).and_then(|o| o.downcast::<ProcessObject>().ok()) {
-                current_pids.insert(obj.pid(), i);
+        sync_store(&self.store, groups, |g| g.leader.pid, |obj, g| {
+            obj.set_from_group(g, cpu_scale);
+            let history = histories.get(g.display_name()).map(|h| &h.cpu_samples);
+            let recent = history.map_or(Vec::new(), |s| s.iter().skip(s.len().saturating_sub(SPARKLINE_SAMPLES)).copied().collect());
+            obj.set_cpu_history(recent);
+        });
+    }
+
+    /// Tree mode: the real PID/PPID hierarchy. Roots are processes whose
+    /// parent isn't in the snapshot (init, kthreadd, processes from other
+    /// PID namespaces); every process with children can be expanded.
+    fn update_tree(&mut self, snapshot: &SystemSnapshot, hide_kernel_threads: bool) {
+        let pids: std::collections::HashSet<i32> = snapshot.processes.iter().map(|p| p.pid).collect();
+        let mut children: HashMap<i32, Vec<&crate::model::ProcessInfo>> = HashMap::new();
+        let mut roots: Vec<&crate::model::ProcessInfo> = Vec::new();
+        for proc in snapshot.processes.iter().filter(|p| !(hide_kernel_threads && is_kernel_thread(p))) {
+            if proc.ppid != proc.pid && pids.contains(&proc.ppid) {
+                children.entry(proc.ppid).or_default().push(proc);
+            } else {
+                roots.push(proc);
             }
         }
+        let child_count = |pid: i32| children.get(&pid).map_or(0, |c| c.len());
+        let cpu_scale = self.cpu_scale();
 
-        // Build set of new PIDs
-        let new_pid_set: std::collections::HashSet<i32> = new_groups.iter().map(|g| g.leader.pid).collect();
+        // 1. Direct children per PID; kill-group walks these recursively
+        {
+            let mut cache = self.children_cache.borrow_mut();
+            cache.clear();
+            for (pid, kids) in &children {
+                cache.insert(*pid, kids.iter().map(|p| (*p).clone()).collect());
+            }
+        }
 
-        // Remove items no longer present (iterate in reverse to keep indices stable)
-        let mut removed_positions: Vec<u32> = current_pids.iter()
-            .filter(|(pid, _)| !new_pid_set.contains(pid))
-            .map(|(_, &pos)| pos)
-            .collect();
-        removed_positions.sort_unstable_by(|a, b| b.cmp(a)); // reverse order
-        for pos in &removed_positions {
-            self.store.remove(*pos);
+        // 2. Make sure every parent has a child store before any store changes
+        //    (item changes can trigger create_func, which looks stores up by PID)
+        let stores: Vec<(i32, gio::ListStore)> = {
+            let mut stores = self.child_stores.borrow_mut();
+            stores.retain(|pid, _| children.contains_key(pid));
+            children.keys()
+                .map(|pid| {
+                    let store = stores.entry(*pid).or_insert_with(|| gio::ListStore::new::<ProcessObject>());
+                    (*pid, store.clone())
+                })
+                .collect()
+        };
+        for (pid, store) in &stores {
+            sync_store(store, &children[pid], |p| p.pid, |obj, p| obj.set_from_tree_node(p, child_count(p.pid), cpu_scale));
         }
 
-        // Rebuild PID->position map after removals
-        let mut current_pids: HashMap<i32, u32> = HashMap::new();
-        for i in 0..self.store.n_items() {
-            if let Some(obj) = self.store.item(i).and_then(|o| o.downcast::<ProcessObject>().ok()) {
-                current_pids.insert(obj.pid(), i);
+        // 3. PID-stable update of root store
+        sync_store(&self.store, &roots, |p| p.pid, |obj, p| obj.set_from_tree_node(p, child_count(p.pid), cpu_scale));
+    }
+}
+
+/// Apply the kernel threads setting to groups from the collector, whose
+/// kernel threads are all in the PID 0 "Kernel" group.
+fn arrange_kernel_threads(groups: &[AppGroup], mode: KernelThreads) -> Cow<'_, [AppGroup]> {
+    let is_kernel = |g: &AppGroup| g.leader.pid == 0;
+    match mode {
+        KernelThreads::Grouped => Cow::Borrowed(groups),
+        KernelThreads::Hidden => Cow::Owned(groups.iter().filter(|g| !is_kernel(g)).cloned().collect()),
+        KernelThreads::Separate => Cow::Owned(
+            groups
+                .iter()
+                .flat_map(|g| {
+                    if is_kernel(g) {
+                        g.children.iter().map(|t| AppGroup::new(t.clone())).collect()
+                    } else {
+                        vec![g.clone()]
+                    }
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// PIDs below `pid` in the children cache, parents before their children.
+/// In app-group mode this is just the group's members; in tree mode it is
+/// the whole subtree.
+fn collect_descendants(cache: &HashMap<i32, Vec<crate::model::ProcessInfo>>, pid: i32) -> Vec<i32> {
+    let mut result = Vec::new();
+    let mut seen = std::collections::HashSet::from([pid]);
+    let mut queue = std::collections::VecDeque::from([pid]);
+    while let Some(parent) = queue.pop_front() {
+        for child in cache.get(&parent).into_iter().flatten() {
+            if seen.insert(child.pid) {
+                result.push(child.pid);
+                queue.push_back(child.pid);
             }
         }
+    }
+    result
+}
 
-        // Update existing items in-place, append new ones
-        // Track which positions need TreeListModel invalidation (expandability changed)
-        let mut needs_invalidation: Vec<u32> = Vec::new();
-        for group in &new_groups {
-            if let Some(&pos) = current_pids.get(&group.leader.pid) {
-                // Existing item: update in-place
-                if let Some(obj) = self.store.item(pos).and_then(|o| o.downcast::<ProcessObject>().ok()) {
-                    let was_expandable = obj.is_group() && obj.child_count() > 0;
-                    obj.set_from_group(group);
-                    let now_expandable = obj.is_group() && obj.child_count() > 0;
-                    if was_expandable != now_expandable {
-                        needs_invalidation.push(pos);
-                    }
-                }
-            } else {
-                // New item: append (TreeListModel will call create_func)
-                let obj = ProcessObject::new();
-                obj.set_from_group(group);
-                self.store.append(&obj);
+/// Send `signal` to a group leader and all its descendants; returns how many
+/// processes were signalled. SIGSTOP goes parents-first so nothing can spawn
+/// replacements for already-stopped children; everything else goes
+/// deepest-first with the leader last.
+fn signal_group(
+    cache: &HashMap<i32, Vec<crate::model::ProcessInfo>>,
+    leader_pid: i32,
+    signal: nix::sys::signal::Signal,
+) -> usize {
+    let mut pids = vec![leader_pid];
+    pids.extend(collect_descendants(cache, leader_pid));
+    if signal != nix::sys::signal::Signal::SIGSTOP {
+        pids.reverse();
+    }
+    pids.iter()
+        .filter(|&&pid| nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid), signal).is_ok())
+        .count()
+}
+
+/// `pid` and all its descendants by PPID, children before their parents.
+fn process_tree_leaf_first(tree: &HashMap<i32, Vec<i32>>, pid: i32) -> Vec<i32> {
+    let mut order = vec![pid];
+    let mut seen = std::collections::HashSet::from([pid]);
+    let mut i = 0;
+    while i < order.len() {
+        for &child in tree.get(&order[i]).into_iter().flatten() {
+            if seen.insert(child) {
+                order.push(child);
+            }
+        }
+        i += 1;
+    }
+    order.reverse();
+    order
+}
+
+/// SIGKILL a process and its real descendants, leaf-first, after confirming.
+fn kill_process_tree(pid: i32, name: String, tree: &HashMap<i32, Vec<i32>>, widget: &gtk::ColumnView, prefs: &KillPrefs) {
+    let pids = process_tree_leaf_first(tree, pid);
+    let critical: Vec<i32> = pids.iter().copied().filter(|&p| is_critical_process(p)).collect();
+    if !critical.is_empty() {
+        show_error_dialog(widget, &format!(
+            "Cannot kill the tree of \"{}\" (PID {}) — it contains critical system processes (PID {}).",
+            name,
+            pid,
+            critical.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(", ")
+        ));
+        return;
+    }
+
+    if pids.len() == 1 {
+        kill_process(pid, name, Signal::SIGKILL, widget, prefs);
+        return;
+    }
+    if prefs.confirm_needed(false, true) {
+        let msg = format!(
+            "Force kill \"{}\" (PID {}) and its {} descendant process(es)?",
+            name,
+            pid,
+            pids.len() - 1
+        );
+        show_confirm_dialog(widget, &msg, &pids, Signal::SIGKILL, prefs, true);
+    } else {
+        for &p in &pids {
+            let comm = std::fs::read_to_string(format!("/proc/{}/comm", p)).unwrap_or_default();
+            do_kill(p, comm.trim(), Signal::SIGKILL, widget, prefs);
+        }
+    }
+}
+
+/// PID-stable update of a list store.
+/// TreeListModel caches create_func results per position, so we must
+/// keep items at stable positions (matched by PID) to preserve expansion state.
+/// Items that are new get appended; items that disappeared get removed.
+fn sync_store<T>(
+    store: &gio::ListStore,
+    items: &[T],
+    pid_of: impl Fn(&T) -> i32,
+    apply: impl Fn(&ProcessObject, &T),
+) {
+    // Remove items no longer present (iterate in reverse to keep indices stable)
+    let new_pid_set: std::collections::HashSet<i32> = items.iter().map(&pid_of).collect();
+    for pos in (0..store.n_items()).rev() {
+        if let Some(obj) = store.item(pos).and_then(|o| o.downcast::<ProcessObject>().ok()) {
+            if !new_pid_set.contains(&obj.pid()) {
+                store.remove(pos);
             }
         }
+    }
 
-        // Invalidate items whose expandability changed by remove+re-add
-        // (TreeListModel only calls create_func for new items)
-        // Process in reverse order to keep positions stable
-        needs_invalidation.sort_unstable_by(|a, b| b.cmp(a));
-        for pos in needs_invalidation {
-            if let Some(obj) = self.store.item(pos).and_then(|o| o.downcast::<ProcessObject>().ok()) {
-                self.store.remove(pos);
-                self.store.insert(pos, &obj);
+    // Build PID->position map after removals
+    let mut current_pids: HashMap<i32, u32> = HashMap::new();
+    for i in 0..store.n_items() {
+        if let Some(obj) = store.item(i).and_then(|o| o.downcast::<ProcessObject>().ok()) {
+            current_pids.insert(obj.pid(), i);
+        }
+    }
+
+    // Update existing items in-place, append new ones
+    // Track which positions need TreeListModel invalidation (expandability changed)
+    let mut needs_invalidation: Vec<u32> = Vec::new();
+    for item in items {
+        if let Some(&pos) = current_pids.get(&pid_of(item)) {
+            // Existing item: update in-place
+            if let Some(obj) = store.item(pos).and_then(|o| o.downcast::<ProcessObject>().ok()) {
+                let was_expandable = obj.is_group() && obj.child_count() > 0;
+                apply(&obj, item);
+                obj.notify_updated();
+                let now_expandable = obj.is_group() && obj.child_count() > 0;
+                if was_expandable != now_expandable {
+                    needs_invalidation.push(pos);
+                }
             }
+        } else {
+            // New item: append (TreeListModel will call create_func)
+            let obj = ProcessObject::new();
+            apply(&obj, item);
+            store.append(&obj);
         }
+    }
 
-        // Save scroll position before triggering re-sort
-        let vadj = self.scroll.vadjustment();
-        let scroll_pos = vadj.value();
+    // Invalidate items whose expandability changed by remove+re-add
+    // (TreeListModel only calls create_func for new items)
+    // Process in reverse order to keep positions stable
+    needs_invalidation.sort_unstable_by(|a, b| b.cmp(a));
+    for pos in needs_invalidation {
+        if let Some(obj) = store.item(pos).and_then(|o| o.downcast::<ProcessObject>().ok()) {
+            store.remove(pos);
+            store.insert(pos, &obj);
+        }
+    }
+}
 
-        // Trigger re-sort so columns reflect updated values
-        if let Some(sorter) = self.sort_model.sorter() {
-            sorter.changed(gtk::SorterChange::Different);
+/// End every selected process. A single selection behaves like `kill_process`;
+/// several selected processes get one summary confirmation, with critical
+/// system processes left out of the batch.
+fn kill_processes(targets: &[ProcessObject], signal: nix::sys::signal::Signal, widget: &gtk::ColumnView, prefs: &KillPrefs) {
+    match targets {
+        [] => {}
+        [obj] => kill_process(obj.pid(), obj.display_name(), signal, widget, prefs),
+        _ => {
+            let (critical, killable): (Vec<&ProcessObject>, Vec<&ProcessObject>) =
+                targets.iter().partition(|obj| is_critical_process(obj.pid()));
+            let action = if signal == nix::sys::signal::Signal::SIGKILL { "Force kill" } else { "End" };
+
+            if killable.is_empty() {
+                show_error_dialog(widget, &format!(
+                    "All {} selected processes are critical system processes.\n\nNothing was ended.",
+                    targets.len()
+                ));
+                return;
+            }
+
+            let names: Vec<String> = killable.iter()
+                .take(10)
+                .map(|obj| format!("  {} (PID {})", obj.display_name(), obj.pid()))
+                .collect();
+            let mut msg = format!("{} {} processes?\n\n{}", action, killable.len(), names.join("\n"));
+            if killable.len() > names.len() {
+                msg.push_str(&format!("\n  … and {} more", killable.len() - names.len()));
+            }
+            if !critical.is_empty() {
+                let skipped: Vec<String> = critical.iter()
+                    .map(|obj| format!("{} (PID {})", obj.display_name(), obj.pid()))
+                    .collect();
+                msg.push_str(&format!(
+                    "\n\n{} critical system process(es) will be skipped: {}",
+                    critical.len(),
+                    skipped.join(", ")
+                ));
+            }
+
+            let pids: Vec<i32> = killable.iter().map(|obj| obj.pid()).collect();
+            if prefs.confirm_needed(false, true) {
+                show_confirm_dialog(widget, &msg, &pids, signal, prefs, true);
+            } else {
+                for obj in &killable {
+                    do_kill(obj.pid(), &obj.display_name(), signal, widget, prefs);
+                }
+            }
         }
+    }
+}
 
-        // Restore scroll position
-        vadj.set_value(scroll_pos);
+/// Watchlist key: the exe path, or the name for processes without one (kernel threads)
+fn watch_key(obj: &ProcessObject) -> String {
+    let path = obj.exe_path();
+    if path.is_empty() { obj.display_name() } else { path }
+}
+
+fn is_watched(config: &Config, obj: &ProcessObject) -> bool {
+    !config.watched_processes.is_empty() && config.watched_processes.contains(&watch_key(obj))
+}
+
+fn set_watch_icon(button: &gtk::Button, watched: bool) {
+    if watched {
+        button.set_icon_name("starred-symbolic");
+        button.set_tooltip_text(Some("Remove from watchlist"));
+    } else {
+        button.set_icon_name("non-starred-symbolic");
+        button.set_tooltip_text(Some("Add to watchlist"));
     }
 }
 
-fn kill_process(pid: i32, name: String, signal: nix::sys::signal::Signal, widget: &gtk::ColumnView) {
-    if is_critical_process(pid) {
+/// Tab-separated values of the currently visible columns, in display order
+fn row_as_text(obj: &ProcessObject, column_view: &gtk::ColumnView) -> String {
+    let columns = column_view.columns();
+    (0..columns.n_items())
+        .filter_map(|i| columns.item(i).and_downcast::<gtk::ColumnViewColumn>())
+        .filter(|col| col.is_visible())
+        .filter_map(|col| col.title()) // Skip the untitled star column
+        .map(|title| column_text(obj, &title))
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// Text shown for a process in the column with the given title
+fn column_text(obj: &ProcessObject, title: &str) -> String {
+    match title {
+        "Name" => obj.display_name(),
+        "PID" => obj.pid().to_string(),
+        "CPU" => util::format_percent(obj.shown_cpu_percent()),
+        "Memory" => util::format_bytes(obj.memory_bytes()),
+        // Other users' smaps_rollup isn't readable
+        "PSS" if obj.pss_bytes() > 0 => util::format_bytes(obj.pss_bytes()),
+        "PSS" => "—".to_string(),
+        "Swap" if obj.swap_bytes() > 0 => util::format_bytes(obj.swap_bytes()),
+        "Swap" => "—".to_string(),
+        "VRAM" if obj.vram_bytes() > 0 => util::format_bytes(obj.vram_bytes()),
+        "VRAM" => "—".to_string(),
+        "Disk Read" => util::format_bytes_rate(obj.disk_read_rate()),
+        "Disk Write" => util::format_bytes_rate(obj.disk_write_rate()),
+        "Disk" => util::format_bytes_rate(obj.disk_rate()),
+        "Net Down" => util::format_bytes_rate(obj.net_rx_rate()),
+        "Net Up" => util::format_bytes_rate(obj.net_tx_rate()),
+        "Connections" => obj.connection_count().to_string(),
+        "Threads" => obj.threads().to_string(),
+        "FDs" if obj.fd_count() > 0 => obj.fd_count().to_string(),
+        "FDs" => "—".to_string(),
+        "State" => util::process_state_label(&obj.state()).to_string(),
+        "Elapsed" => elapsed_text(obj.started_at()),
+        "I/O Priority" => obj.io_priority_text(),
+        "Path" => obj.exe_path(),
+        "Command" => obj.command(),
+        "Container" if obj.container_type().is_empty() => "—".to_string(),
+        "Container" => obj.container_type(),
+        _ => String::new(),
+    }
+}
+
+/// Samples in the CPU History sparkline (a minute at the default refresh rate)
+const SPARKLINE_SAMPLES: usize = 60;
+
+/// Tiny CPU graph for a table cell, scaled to 100% (one core, or all of
+/// them with "CPU as % of total") or the peak if higher.
+/// Fewer samples than `SPARKLINE_SAMPLES` are right-aligned, like the big graphs.
+fn draw_sparkline(cr: &gtk::cairo::Context, w: f64, h: f64, samples: &[f64]) {
+    if samples.len() < 2 {
+        return;
+    }
+    let max = samples.iter().copied().fold(100.0, f64::max);
+    let step = w / (SPARKLINE_SAMPLES - 1) as f64;
+    let x0 = w - (samples.len() - 1) as f64 * step;
+    let y = |v: f64| h - 1.0 - (v / max) * (h - 2.0);
+
+    cr.move_to(x0, h);
+    for (i, &v) in samples.iter().enumerate() {
+        cr.line_to(x0 + i as f64 * step, y(v));
+    }
+    cr.line_to(w, h);
+    cr.close_path();
+    cr.set_source_rgba(0.2, 0.6, 1.0, 0.25);
+    let _ = cr.fill();
+
+    cr.move_to(x0, y(samples[0]));
+    for (i, &v) in samples.iter().enumerate().skip(1) {
+        cr.line_to(x0 + i as f64 * step, y(v));
+    }
+    cr.set_source_rgba(0.2, 0.6, 1.0, 0.9);
+    cr.set_line_width(1.0);
+    let _ = cr.stroke();
+}
+
+/// Lowest CPU usage (100 = one core) that counts as active for the
+/// "Active" toggle
+const ACTIVE_CPU_PERCENT: f64 = 0.5;
+
+/// What the kill and signal actions need to decide whether to ask first
+#[derive(Clone)]
+struct KillPrefs {
+    config: Rc<RefCell<Config>>,
+    /// Set by "Don't ask again this session"; silences non-critical confirmations
+    skip_confirm: Rc<Cell<bool>>,
+}
+
+impl KillPrefs {
+    /// Whether ending or signalling a process (or a batch, when `critical` is
+    /// false) should ask first.
+    fn confirm_needed(&self, critical: bool, batch: bool) -> bool {
+        match self.config.borrow().confirm_kill {
+            ConfirmKill::Never => false,
+            _ if critical => true,
+            ConfirmKill::Always => !self.skip_confirm.get(),
+            _ if batch => !self.skip_confirm.get(),
+            _ => false,
+        }
+    }
+}
+
+/// How long a process gets to handle SIGTERM before End Task escalates to SIGKILL
+const ESCALATE_AFTER_MS: u64 = 3000;
+
+fn kill_process(pid: i32, name: String, signal: nix::sys::signal::Signal, widget: &gtk::ColumnView, prefs: &KillPrefs) {
+    let critical = is_critical_process(pid);
+    if prefs.confirm_needed(critical, false) {
         let action = if signal == nix::sys::signal::Signal::SIGKILL { "force kill" } else { "end" };
-        let msg = format!(
-            "\"{}\" (PID {}) is a critical system process.\n\nKilling it will crash your system.\n\nAre you sure you want to {} it?",
-            name, pid, action
-        );
-        show_confirm_dialog(widget, &msg, pid, signal);
+        let msg = if critical {
+            format!(
+                "\"{}\" (PID {}) is a critical system process.\n\nKilling it will crash your system.\n\nAre you sure you want to {} it?",
+                name, pid, action
+            )
+        } else {
+            format!("Are you sure you want to {} \"{}\" (PID {})?", action, name, pid)
+        };
+        show_confirm_dialog(widget, &msg, &[pid], signal, prefs, !critical);
         return;
     }
 
-    do_kill(pid, &name, signal, widget);
+    do_kill(pid, &name, signal, widget, prefs);
 }
 
-fn do_kill(pid: i32, name: &str, signal: nix::sys::signal::Signal, widget: &gtk::ColumnView) {
+fn do_kill(pid: i32, name: &str, signal: nix::sys::signal::Signal, widget: &gtk::ColumnView, prefs: &KillPrefs) {
     use nix::sys::signal;
     use nix::unistd::Pid;
 
+    // Remember which process this is, so a reused PID isn't force killed later
+    let escalate_from = (signal == Signal::SIGTERM && prefs.config.borrow().escalate_end_task)
+        .then(|| process_restart::read_stat(pid))
+        .flatten();
+
     match signal::kill(Pid::from_raw(pid), signal) {
-        Ok(_) => log::info!("Sent {:?} to PID {} ({})", signal, pid, name),
+        Ok(_) => {
+            log::info!("Sent {:?} to PID {} ({})", signal, pid, name);
+            if let Some((_, start_time)) = escalate_from {
+                escalate_if_running(pid, name, start_time, widget, prefs);
+            }
+        }
         Err(e) => {
             log::error!("Failed to send {:?} to PID {} ({}): {}", signal, pid, name, e);
             let msg = format!(
@@ -994,7 +2508,116 @@ fn do_kill(pid: i32, name: &str, signal: nix::sys::signal::Signal, widget: &gtk:
     }
 }
 
-fn is_critical_process(pid: i32) -> bool {
+/// Send SIGKILL if the process that got SIGTERM is still alive (and not a
+/// zombie or a new process reusing its PID) after `ESCALATE_AFTER_MS`.
+fn escalate_if_running(pid: i32, name: &str, start_time: u64, widget: &gtk::ColumnView, prefs: &KillPrefs) {
+    let name = name.to_string();
+    let widget = widget.clone();
+    let prefs = prefs.clone();
+    glib::timeout_add_local_once(std::time::Duration::from_millis(ESCALATE_AFTER_MS), move || {
+        let alive = signal::kill(Pid::from_raw(pid), None).is_ok()
+            && matches!(process_restart::read_stat(pid), Some((state, start)) if state != 'Z' && start == start_time);
+        if alive {
+            log::warn!("PID {} ({}) ignored SIGTERM for {} ms, sending SIGKILL", pid, name, ESCALATE_AFTER_MS);
+            do_kill(pid, &name, Signal::SIGKILL, &widget, &prefs);
+        }
+    });
+}
+
+/// How long a restarted process gets to exit after SIGTERM before we give up.
+const RESTART_EXIT_TIMEOUT_MS: u64 = 5000;
+
+/// End a process with SIGTERM and start the same command again, after
+/// confirming with the user.
+fn restart_process(pid: i32, name: String, widget: &gtk::ColumnView) {
+    if is_critical_process(pid) {
+        show_error_dialog(widget, &format!(
+            "\"{}\" (PID {}) is a critical system process and cannot be restarted from here.",
+            name, pid
+        ));
+        return;
+    }
+
+    let spec = match process_restart::capture(pid) {
+        Ok(spec) => spec,
+        Err(e) => {
+            log::error!("Cannot restart PID {} ({}): {}", pid, name, e);
+            show_error_dialog(widget, &format!(
+                "Cannot restart \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
+                name, pid, e
+            ));
+            return;
+        }
+    };
+
+    let window = widget.root()
+        .and_then(|r| r.downcast::<gtk::Window>().ok());
+    let command: Vec<String> = spec.argv.iter().map(|a| a.to_string_lossy().into_owned()).collect();
+    let dialog = gtk::MessageDialog::new(
+        window.as_ref(),
+        gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+        gtk::MessageType::Warning,
+        gtk::ButtonsType::None,
+        &format!(
+            "Restart \"{}\" (PID {})?\n\n{}\n\nIt will be ended and started again with the same command line, \
+             working directory and environment, but as your user and detached from its terminal and parent. \
+             Permissions and behaviour may differ from the original launch.",
+            name, pid, command.join(" ")
+        ),
+    );
+    dialog.add_button("Cancel", gtk::ResponseType::Cancel);
+    let restart_btn = dialog.add_button("Restart", gtk::ResponseType::Accept);
+    restart_btn.add_css_class("destructive-action");
+
+    let widget_clone = widget.clone();
+    let spec = Rc::new(spec);
+    dialog.connect_response(move |d, response| {
+        d.close();
+        if response != gtk::ResponseType::Accept {
+            return;
+        }
+        if let Err(e) = signal::kill(Pid::from_raw(pid), Signal::SIGTERM) {
+            log::error!("Failed to send SIGTERM to PID {} ({}): {}", pid, name, e);
+            show_error_dialog(&widget_clone, &format!(
+                "Failed to end \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
+                name, pid, e
+            ));
+            return;
+        }
+        log::info!("Sent SIGTERM to PID {} ({}) for restart", pid, name);
+
+        // Wait for it to exit without blocking the UI, then relaunch
+        let spec = spec.clone();
+        let name = name.clone();
+        let widget = widget_clone.clone();
+        let waited = Cell::new(0u64);
+        glib::timeout_add_local(std::time::Duration::from_millis(100), move || {
+            if process_restart::is_running(&spec) {
+                waited.set(waited.get() + 100);
+                if waited.get() < RESTART_EXIT_TIMEOUT_MS {
+                    return glib::ControlFlow::Continue;
+                }
+                log::warn!("PID {} ({}) did not exit; not restarting", pid, name);
+                show_error_dialog(&widget, &format!(
+                    "\"{}\" (PID {}) did not exit within {} seconds, so it was not restarted.",
+                    name, pid, RESTART_EXIT_TIMEOUT_MS / 1000
+                ));
+                return glib::ControlFlow::Break;
+            }
+            match process_restart::spawn(&spec) {
+                Ok(new_pid) => log::info!("Restarted {} as PID {}", name, new_pid),
+                Err(e) => {
+                    log::error!("Failed to restart {}: {}", name, e);
+                    show_error_dialog(&widget, &format!("Failed to restart \"{}\"\n\n{}", name, e));
+                }
+            }
+            glib::ControlFlow::Break
+        });
+    });
+    dialog.present();
+}
+
+pub(crate) fn is_critical_process(pid: i32) -> bool {
     if pid <= 2 {
         return true; // PID 1 (init/systemd), PID 2 (kthreadd)
     }
@@ -1010,16 +2633,169 @@ fn is_critical_process(pid: i32) -> bool {
     )
 }
 
-fn set_priority(pid: i32, name: String, nice: i32, widget: &gtk::ColumnView) {
+/// Returns whether the new priority was applied.
+fn set_priority(pid: i32, name: String, nice: i32, widget: &gtk::ColumnView) -> bool {
+    unsafe {
+        let result = libc::setpriority(libc::PRIO_PROCESS, pid as u32, nice);
+        if result == 0 {
+            log::info!("Set PID {} ({}) priority to {}", pid, name, nice);
+            true
+        } else {
+            let err = std::io::Error::last_os_error();
+            log::error!("Failed to set PID {} ({}) priority: {}", pid, name, err);
+            let msg = format!(
+                "Failed to set priority for \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
+                name, pid, err
+            );
+            show_error_dialog(widget, &msg);
+            false
+        }
+    }
+}
+
+/// Store (Some) or drop (None) the priority rule for `exe`, in the config and
+/// in the collector's copy.
+fn save_priority_rule(config: &Rc<RefCell<Config>>, rules: &PriorityRules, exe: &str, nice: Option<i32>) {
+    let mut cfg = config.borrow_mut();
+    match nice {
+        Some(nice) => {
+            cfg.priority_rules.insert(exe.to_string(), nice);
+            log::info!("Remembering priority {} for {}", nice, exe);
+        }
+        None => {
+            cfg.priority_rules.remove(exe);
+            log::info!("Forgot remembered priority for {}", exe);
+        }
+    }
+    if let Ok(mut shared) = rules.lock() {
+        *shared = cfg.priority_rules.clone();
+    }
+    cfg.save();
+}
+
+/// Terminal emulators tried when $TERMINAL is unset, with the flag each one
+/// uses for its starting directory (None: inherits the working directory)
+const TERMINALS: &[(&str, Option<&str>)] = &[
+    ("x-terminal-emulator", None),
+    ("gnome-terminal", Some("--working-directory=")),
+    ("kgx", Some("--working-directory=")),
+    ("konsole", Some("--workdir=")),
+    ("xfce4-terminal", Some("--working-directory=")),
+    ("tilix", Some("--working-directory=")),
+    ("alacritty", Some("--working-directory=")),
+    ("kitty", Some("--directory=")),
+    ("foot", Some("--working-directory=")),
+    ("wezterm", None),
+    ("xterm", None),
+];
+
+fn open_terminal_at_cwd(pid: i32, name: &str, widget: &gtk::ColumnView) {
+    let cwd = match std::fs::read_link(format!("/proc/{}/cwd", pid)) {
+        Ok(cwd) => cwd,
+        Err(err) => {
+            log::error!("Failed to read working directory of PID {} ({}): {}", pid, name, err);
+            let msg = format!(
+                "Cannot read the working directory of \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
+                name, pid, err
+            );
+            show_error_dialog(widget, &msg);
+            return;
+        }
+    };
+
+    if let Err(e) = spawn_terminal(&cwd) {
+        log::error!("Failed to open terminal in {}: {}", cwd.display(), e);
+        show_error_dialog(widget, &format!("Failed to open a terminal in {}\n\n{}", cwd.display(), e));
+    }
+}
+
+/// Launch $TERMINAL (or the first known emulator found in PATH) in `dir`.
+fn spawn_terminal(dir: &std::path::Path) -> Result<(), String> {
+    let mut candidates: Vec<(String, Vec<String>)> = Vec::new();
+    if let Ok(terminal) = std::env::var("TERMINAL") {
+        let mut parts = terminal.split_whitespace().map(String::from);
+        if let Some(program) = parts.next() {
+            candidates.push((program, parts.collect()));
+        }
+    }
+    for (program, dir_flag) in TERMINALS {
+        let args = match dir_flag {
+            Some(flag) => vec![format!("{}{}", flag, dir.display())],
+            None => Vec::new(),
+        };
+        candidates.push((program.to_string(), args));
+    }
+
+    for (program, args) in candidates {
+        if glib::find_program_in_path(&program).is_none() {
+            continue;
+        }
+        return std::process::Command::new(&program)
+            .args(&args)
+            .current_dir(dir)
+            .spawn()
+            .map(|_| log::info!("Opened {} in {}", program, dir.display()))
+            .map_err(|e| format!("{}: {}", program, e));
+    }
+    Err("No terminal emulator found. Set the TERMINAL environment variable.".to_string())
+}
+
+fn set_io_priority(pid: i32, name: String, class: u32, level: i32, widget: &gtk::ColumnView) {
+    match ioprio::set(pid, class, level) {
+        Ok(()) => {
+            log::info!("Set PID {} ({}) I/O priority to {} {}", pid, name, ioprio::class_name(class), level);
+        }
+        Err(err) => {
+            log::error!("Failed to set PID {} ({}) I/O priority: {}", pid, name, err);
+            let msg = format!(
+                "Failed to set I/O priority for \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
+                name, pid, err
+            );
+            show_error_dialog(widget, &msg);
+        }
+    }
+}
+
+fn set_oom_score_adj(pid: i32, name: String, adj: i32, widget: &gtk::ColumnView) {
+    match oom::set_adj(pid, adj) {
+        Ok(()) => log::info!("Set PID {} ({}) OOM score adjustment to {}", pid, name, adj),
+        Err(err) => {
+            log::error!("Failed to set PID {} ({}) OOM score adjustment: {}", pid, name, err);
+            let msg = format!(
+                "Failed to set OOM score adjustment for \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
+                name, pid, err
+            );
+            show_error_dialog(widget, &msg);
+        }
+    }
+}
+
+/// Read the CPU affinity mask of `pid` as a list of allowed core indices.
+fn get_affinity(pid: i32) -> Result<Vec<usize>, std::io::Error> {
     unsafe {
-        let result = libc::setpriority(libc::PRIO_PROCESS, pid as u32, nice);
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(pid, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok((0..libc::CPU_SETSIZE as usize).filter(|&i| libc::CPU_ISSET(i, &set)).collect())
+    }
+}
+
+fn set_affinity(pid: i32, name: String, cores: &[usize], widget: &gtk::ColumnView) {
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_ZERO(&mut set);
+        for &core in cores {
+            libc::CPU_SET(core, &mut set);
+        }
+        let result = libc::sched_setaffinity(pid, std::mem::size_of::<libc::cpu_set_t>(), &set);
         if result == 0 {
-            log::info!("Set PID {} ({}) priority to {}", pid, name, nice);
+            log::info!("Set PID {} ({}) CPU affinity to {:?}", pid, name, cores);
         } else {
             let err = std::io::Error::last_os_error();
-            log::error!("Failed to set PID {} ({}) priority: {}", pid, name, err);
+            log::error!("Failed to set PID {} ({}) CPU affinity: {}", pid, name, err);
             let msg = format!(
-                "Failed to set priority for \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
+                "Failed to set CPU affinity for \"{}\" (PID {})\n\n{}\n\nTry launching Task Manager with elevated privileges.",
                 name, pid, err
             );
             show_error_dialog(widget, &msg);
@@ -1027,19 +2803,149 @@ fn set_priority(pid: i32, name: String, nice: i32, widget: &gtk::ColumnView) {
     }
 }
 
-fn send_signal(pid: i32, name: String, sig: Signal, widget: &gtk::ColumnView) {
-    if is_critical_process(pid) {
-        let msg = format!(
-            "\"{}\" (PID {}) is a critical system process.\n\nSending signal {:?} may crash your system.\n\nAre you sure?",
-            name, pid, sig
-        );
-        show_confirm_dialog(widget, &msg, pid, sig);
+fn show_affinity_dialog(widget: &gtk::ColumnView, obj: &ProcessObject, core_count: usize) {
+    let window = widget.root()
+        .and_then(|r| r.downcast::<gtk::Window>().ok());
+
+    let pid = obj.pid();
+    let name = obj.display_name();
+
+    let current = match get_affinity(pid) {
+        Ok(cores) => cores,
+        Err(e) => {
+            show_error_dialog(widget, &format!(
+                "Failed to read CPU affinity for \"{}\" (PID {})\n\n{}",
+                name, pid, e
+            ));
+            return;
+        }
+    };
+    let core_count = if core_count == 0 {
+        current.iter().max().map(|&c| c + 1).unwrap_or(1)
+    } else {
+        core_count
+    };
+
+    let dialog = gtk::Window::builder()
+        .title(&format!("{} (PID {}) — CPU Affinity", name, pid))
+        .default_width(360)
+        .modal(true)
+        .build();
+
+    if let Some(win) = &window {
+        dialog.set_transient_for(Some(win));
+    }
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_margin_top(12);
+    vbox.set_margin_start(12);
+    vbox.set_margin_end(12);
+    vbox.set_margin_bottom(12);
+
+    let hint = gtk::Label::new(Some("Select the cores this process is allowed to run on:"));
+    hint.set_halign(gtk::Align::Start);
+    hint.set_wrap(true);
+    vbox.append(&hint);
+
+    let grid = gtk::Grid::new();
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+    let checks: Vec<gtk::CheckButton> = (0..core_count)
+        .map(|core| {
+            let check = gtk::CheckButton::with_label(&format!("CPU {}", core));
+            check.set_active(current.contains(&core));
+            grid.attach(&check, (core % 4) as i32, (core / 4) as i32, 1, 1);
+            check
+        })
+        .collect();
+    vbox.append(&gtk::ScrolledWindow::builder()
+        .child(&grid)
+        .propagate_natural_height(true)
+        .max_content_height(400)
+        .build());
+
+    let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    button_box.set_halign(gtk::Align::End);
+    let cancel_btn = gtk::Button::with_label("Cancel");
+    let apply_btn = gtk::Button::with_label("Apply");
+    apply_btn.add_css_class("suggested-action");
+    button_box.append(&cancel_btn);
+    button_box.append(&apply_btn);
+    vbox.append(&button_box);
+
+    // At least one core must stay selected
+    let update_sensitive = {
+        let checks = checks.clone();
+        let apply_btn = apply_btn.clone();
+        move || apply_btn.set_sensitive(checks.iter().any(|c| c.is_active()))
+    };
+    update_sensitive();
+    for check in &checks {
+        let update = update_sensitive.clone();
+        check.connect_toggled(move |_| update());
+    }
+
+    let dialog_for_cancel = dialog.clone();
+    cancel_btn.connect_clicked(move |_| dialog_for_cancel.close());
+
+    let dialog_for_apply = dialog.clone();
+    let widget_clone = widget.clone();
+    apply_btn.connect_clicked(move |_| {
+        let cores: Vec<usize> = checks.iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_active())
+            .map(|(i, _)| i)
+            .collect();
+        set_affinity(pid, name.clone(), &cores, &widget_clone);
+        dialog_for_apply.close();
+    });
+
+    dialog.set_child(Some(&vbox));
+    dialog.present();
+}
+
+fn send_signal(pid: i32, name: String, sig: Signal, widget: &gtk::ColumnView, prefs: &KillPrefs) {
+    let critical = is_critical_process(pid);
+    if prefs.confirm_needed(critical, false) {
+        let msg = if critical {
+            format!(
+                "\"{}\" (PID {}) is a critical system process.\n\nSending signal {:?} may crash your system.\n\nAre you sure?",
+                name, pid, sig
+            )
+        } else {
+            format!("Send {:?} to \"{}\" (PID {})?", sig, name, pid)
+        };
+        show_confirm_dialog(widget, &msg, &[pid], sig, prefs, !critical);
         return;
     }
 
     do_signal(pid, &name, sig, widget);
 }
 
+/// Zombies can't be killed; nudge the parent with SIGCHLD so it collects them.
+fn reap_zombie(obj: &ProcessObject, widget: &gtk::ColumnView) {
+    if obj.state() != "Z" {
+        show_error_dialog(widget, &format!(
+            "\"{}\" (PID {}) is not a zombie process.",
+            obj.display_name(), obj.pid()
+        ));
+        return;
+    }
+    let ppid = obj.ppid();
+    if ppid <= 1 {
+        show_error_dialog(widget, &format!(
+            "Zombie PID {} belongs to init, which reaps it on its own.",
+            obj.pid()
+        ));
+        return;
+    }
+
+    let parent = std::fs::read_to_string(format!("/proc/{}/comm", ppid))
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+    do_signal(ppid, &parent, Signal::SIGCHLD, widget);
+}
+
 fn do_signal(pid: i32, name: &str, sig: Signal, widget: &gtk::ColumnView) {
     match signal::kill(Pid::from_raw(pid), sig) {
         Ok(_) => log::info!("Sent {:?} to PID {} ({})", sig, pid, name),
@@ -1057,7 +2963,9 @@ fn do_signal(pid: i32, name: &str, sig: Signal, widget: &gtk::ColumnView) {
     }
 }
 
-fn show_confirm_dialog(widget: &gtk::ColumnView, message: &str, pid: i32, signal: Signal) {
+/// `offer_skip` adds "Don't ask again this session"; critical processes never get it.
+fn show_confirm_dialog(widget: &gtk::ColumnView, message: &str, pids: &[i32], signal: Signal, prefs: &KillPrefs, offer_skip: bool) {
+    let prefs = prefs.clone();
     let window = widget.root()
         .and_then(|r| r.downcast::<gtk::Window>().ok());
     let widget_clone = widget.clone();
@@ -1071,26 +2979,43 @@ fn show_confirm_dialog(widget: &gtk::ColumnView, message: &str, pid: i32, signal
     );
     dialog.add_button("Cancel", gtk::ResponseType::Cancel);
 
-    // Choose button label based on signal type
-    let button_label = if signal == Signal::SIGKILL || signal == Signal::SIGTERM {
-        "Kill Anyway"
-    } else {
-        "Send Anyway"
+    // Choose button label based on signal type; "Anyway" only for critical processes
+    let button_label = match (signal == Signal::SIGKILL || signal == Signal::SIGTERM, offer_skip) {
+        (true, false) => "Kill Anyway",
+        (false, false) => "Send Anyway",
+        (true, true) => "Kill",
+        (false, true) => "Send",
     };
     let action_btn = dialog.add_button(button_label, gtk::ResponseType::Accept);
     action_btn.add_css_class("destructive-action");
 
-    let name = std::fs::read_to_string(format!("/proc/{}/comm", pid))
-        .unwrap_or_else(|_| "unknown".to_string())
-        .trim()
-        .to_string();
+    let skip_check = gtk::CheckButton::with_label("Don't ask again this session");
+    skip_check.set_visible(offer_skip);
+    if let Ok(area) = dialog.message_area().downcast::<gtk::Box>() {
+        area.append(&skip_check);
+    }
+
+    let targets: Vec<(i32, String)> = pids.iter()
+        .map(|&pid| {
+            let name = std::fs::read_to_string(format!("/proc/{}/comm", pid))
+                .unwrap_or_else(|_| "unknown".to_string())
+                .trim()
+                .to_string();
+            (pid, name)
+        })
+        .collect();
 
     dialog.connect_response(move |d, response| {
         if response == gtk::ResponseType::Accept {
-            if signal == Signal::SIGKILL || signal == Signal::SIGTERM {
-                do_kill(pid, &name, signal, &widget_clone);
-            } else {
-                do_signal(pid, &name, signal, &widget_clone);
+            if skip_check.is_active() {
+                prefs.skip_confirm.set(true);
+            }
+            for (pid, name) in &targets {
+                if signal == Signal::SIGKILL || signal == Signal::SIGTERM {
+                    do_kill(*pid, name, signal, &widget_clone, &prefs);
+                } else {
+                    do_signal(*pid, name, signal, &widget_clone);
+                }
             }
         }
         d.close();
@@ -1098,7 +3023,7 @@ fn show_confirm_dialog(widget: &gtk::ColumnView, message: &str, pid: i32, signal
     dialog.present();
 }
 
-fn show_error_dialog(widget: &gtk::ColumnView, message: &str) {
+fn show_error_dialog(widget: &impl IsA<gtk::Widget>, message: &str) {
     let window = widget.root()
         .and_then(|r| r.downcast::<gtk::Window>().ok());
 
@@ -1115,7 +3040,12 @@ fn show_error_dialog(widget: &gtk::ColumnView, message: &str) {
 
 // ── Process Details Panel (Feature 6) ────────────────────
 
-fn show_process_details(widget: &gtk::ColumnView, obj: &ProcessObject) {
+fn show_process_details(
+    widget: &gtk::ColumnView,
+    obj: &ProcessObject,
+    snapshot: &Rc<RefCell<Option<SystemSnapshot>>>,
+    graph_settings: &GraphSettings,
+) {
     let window = widget.root()
         .and_then(|r| r.downcast::<gtk::Window>().ok());
 
@@ -1138,6 +3068,15 @@ fn show_process_details(widget: &gtk::ColumnView, obj: &ProcessObject) {
     // General tab
     notebook.append_page(&build_general_tab(obj), Some(&gtk::Label::new(Some("General"))));
 
+    // History tab (app groups only)
+    let has_history = snapshot.borrow().as_ref().is_some_and(|s| s.app_histories.contains_key(&name));
+    if obj.is_group() && has_history {
+        notebook.append_page(&build_history_tab(&name, snapshot, graph_settings), Some(&gtk::Label::new(Some("History"))));
+    }
+
+    // Threads tab
+    notebook.append_page(&build_threads_tab(pid), Some(&gtk::Label::new(Some("Threads"))));
+
     // Environment tab
     notebook.append_page(&build_environ_tab(pid), Some(&gtk::Label::new(Some("Environment"))));
 
@@ -1151,12 +3090,104 @@ fn show_process_details(widget: &gtk::ColumnView, obj: &ProcessObject) {
     notebook.append_page(&build_network_tab(pid), Some(&gtk::Label::new(Some("Network"))));
 
     // Cgroup tab
-    notebook.append_page(&build_cgroup_tab(pid), Some(&gtk::Label::new(Some("Cgroup"))));
+    notebook.append_page(&build_cgroup_tab(pid, &name), Some(&gtk::Label::new(Some("Cgroup"))));
+
+    let save_btn = gtk::Button::with_label("Save Report…");
+    save_btn.set_halign(gtk::Align::End);
+    save_btn.set_margin_top(6);
+    save_btn.set_margin_end(8);
+    save_btn.set_margin_bottom(8);
+    let obj_for_save = obj.clone();
+    let dialog_for_save = dialog.clone();
+    save_btn.connect_clicked(move |_| show_save_report_dialog(&dialog_for_save, &obj_for_save));
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    notebook.set_vexpand(true);
+    vbox.append(&notebook);
+    vbox.append(&save_btn);
+
+    dialog.set_child(Some(&vbox));
+    dialog.present();
+}
+
+/// Save everything the details dialog shows as one text or JSON file.
+fn show_save_report_dialog(parent: &gtk::Window, obj: &ProcessObject) {
+    // Snapshot the /proc entries now, not when the file name is picked
+    let mut general: Vec<(String, String)> = general_rows(obj)
+        .into_iter()
+        .map(|(label, value)| (label.to_string(), value))
+        .collect();
+    general.push((
+        "Kernel Stack".to_string(),
+        read_kernel_stack(obj.pid()).unwrap_or_else(|reason| reason),
+    ));
+    let report = ProcessReport::collect(obj.pid(), general);
+
+    let dialog = gtk::FileChooserDialog::new(
+        Some("Save Report"),
+        Some(parent),
+        gtk::FileChooserAction::Save,
+        &[("Cancel", gtk::ResponseType::Cancel), ("Save", gtk::ResponseType::Accept)],
+    );
+    dialog.set_modal(true);
+    let file_stem = obj.display_name().replace(['/', ' '], "_");
+    dialog.set_current_name(&format!("{}-{}-report.txt", file_stem, obj.pid()));
+    dialog.add_choice("format", "Format", &[("text", "Text"), ("json", "JSON")]);
+    dialog.set_choice("format", "text");
+
+    let parent = parent.clone();
+    dialog.connect_response(move |d, response| {
+        if response != gtk::ResponseType::Accept {
+            d.close();
+            return;
+        }
+        let Some(mut path) = d.file().and_then(|f| f.path()) else {
+            d.close();
+            return;
+        };
+        let json = d.choice("format").is_some_and(|c| c == "json");
+        d.close();
+
+        // Match the extension to the format unless the user typed another one
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if json && (ext.is_empty() || ext == "txt") {
+            path.set_extension("json");
+        } else if ext.is_empty() {
+            path.set_extension("txt");
+        }
 
-    dialog.set_child(Some(&notebook));
+        let result = if json { report.save_json(&path) } else { report.save_text(&path) };
+        match result {
+            Ok(()) => log::info!("Saved report for PID {} to {}", report.pid, path.display()),
+            Err(e) => {
+                log::error!("Saving report to {} failed: {}", path.display(), e);
+                show_error_dialog(&parent, &format!("Could not save the report:\n\n{}", e));
+            }
+        }
+    });
     dialog.present();
 }
 
+/// Local date and time for a Unix start time ("—" when unknown).
+fn start_time_text(started_at: u64) -> String {
+    if started_at == 0 {
+        return "—".to_string();
+    }
+    glib::DateTime::from_unix_local(started_at as i64)
+        .and_then(|dt| dt.format("%Y-%m-%d %H:%M:%S"))
+        .map(|s| s.to_string())
+        .unwrap_or_else(|_| "—".to_string())
+}
+
+/// Time since a Unix start time ("—" when unknown).
+fn elapsed_text(started_at: u64) -> String {
+    if started_at == 0 {
+        return "—".to_string();
+    }
+    let now = glib::real_time() as u64 / 1_000_000;
+    util::format_elapsed(now.saturating_sub(started_at))
+}
+
 fn build_general_tab(obj: &ProcessObject) -> gtk::ScrolledWindow {
     let grid = gtk::Grid::new();
     grid.set_row_spacing(6);
@@ -1166,21 +3197,7 @@ fn build_general_tab(obj: &ProcessObject) -> gtk::ScrolledWindow {
     grid.set_margin_end(12);
     grid.set_margin_bottom(12);
 
-    let rows: Vec<(&str, String)> = vec![
-        ("PID", obj.pid().to_string()),
-        ("Parent PID", obj.ppid().to_string()),
-        ("Name", obj.display_name()),
-        ("User", obj.user()),
-        ("State", obj.state()),
-        ("Nice", obj.nice().to_string()),
-        ("Threads", obj.threads().to_string()),
-        ("CPU %", util::format_percent(obj.cpu_percent())),
-        ("Memory", util::format_bytes(obj.memory_bytes())),
-        ("Container", if obj.container_type().is_empty() { "None".to_string() } else { obj.container_type() }),
-        ("Exe Path", obj.exe_path()),
-        ("Command", obj.command()),
-    ];
-
+    let rows = general_rows(obj);
     for (i, (label, value)) in rows.iter().enumerate() {
         let key = gtk::Label::new(Some(label));
         key.set_halign(gtk::Align::Start);
@@ -1193,69 +3210,200 @@ fn build_general_tab(obj: &ProcessObject) -> gtk::ScrolledWindow {
         grid.attach(&val, 1, i as i32, 1, 1);
     }
 
+    // Where a sleeping (especially D-state) process is parked in the kernel
+    let stack_key = gtk::Label::new(Some("Kernel Stack"));
+    stack_key.set_halign(gtk::Align::Start);
+    stack_key.set_valign(gtk::Align::Start);
+    stack_key.add_css_class("dim-label");
+    let stack_val = match read_kernel_stack(obj.pid()) {
+        Ok(stack) => {
+            let label = gtk::Label::new(Some(&stack));
+            label.add_css_class("monospace");
+            label
+        }
+        Err(reason) => {
+            let label = gtk::Label::new(Some(&reason));
+            label.add_css_class("dim-label");
+            label
+        }
+    };
+    stack_val.set_halign(gtk::Align::Start);
+    stack_val.set_selectable(true);
+    stack_val.set_wrap(true);
+    grid.attach(&stack_key, 0, rows.len() as i32, 1, 1);
+    grid.attach(&stack_val, 1, rows.len() as i32, 1, 1);
+
     gtk::ScrolledWindow::builder()
         .child(&grid)
         .vexpand(true)
         .build()
 }
 
-fn build_environ_tab(pid: i32) -> gtk::ScrolledWindow {
-    let list_box = gtk::ListBox::new();
-    list_box.set_selection_mode(gtk::SelectionMode::None);
+/// Label/value rows of the General tab (also used by the saved report).
+fn general_rows(obj: &ProcessObject) -> Vec<(&'static str, String)> {
+    // Groups only have the collected totals; a single process is read on demand
+    let (pss, shared, private) = if obj.is_group() || obj.pss_bytes() > 0 {
+        (obj.pss_bytes(), obj.shared_bytes(), obj.private_bytes())
+    } else {
+        read_smaps_rollup(obj.pid()).unwrap_or_default()
+    };
+    let mut rows = vec![
+        ("PID", obj.pid().to_string()),
+        ("Parent PID", obj.ppid().to_string()),
+        ("Name", obj.display_name()),
+        ("User", obj.user()),
+        ("State", format!("{} ({})", util::process_state_label(&obj.state()), obj.state())),
+        ("Wait Channel", wchan_text(obj.pid())),
+        ("Started", start_time_text(obj.started_at())),
+        ("Elapsed", elapsed_text(obj.started_at())),
+        ("Nice", obj.nice().to_string()),
+        ("I/O Priority", obj.io_priority_text()),
+        ("OOM Score", oom_text(obj.pid())),
+        ("Threads", obj.threads().to_string()),
+        ("Open FDs", obj.fd_count().to_string()),
+        ("CPU %", util::format_percent(obj.shown_cpu_percent())),
+        ("Memory (RSS)", util::format_bytes(obj.memory_bytes())),
+        ("Memory (PSS)", rollup_text(pss)),
+        ("Shared Memory", rollup_text(shared)),
+        ("Private Memory", rollup_text(private)),
+        ("Swap", util::format_bytes(obj.swap_bytes())),
+        ("Container", if obj.container_type().is_empty() { "None".to_string() } else { obj.container_type() }),
+        ("Exe Path", obj.exe_path()),
+        ("Command", obj.command()),
+    ];
+    rows.extend(security_rows(obj));
+    rows
+}
 
-    if let Ok(environ) = std::fs::read_to_string(format!("/proc/{}/environ", pid)) {
-        let mut vars: Vec<&str> = environ.split('\0').filter(|s| !s.is_empty()).collect();
-        vars.sort();
-        for var in vars {
-            let label = gtk::Label::new(Some(var));
-            label.set_halign(gtk::Align::Start);
-            label.set_selectable(true);
-            label.set_wrap(true);
-            label.set_margin_top(2);
-            label.set_margin_bottom(2);
-            label.set_margin_start(8);
-            list_box.append(&label);
-        }
+fn oom_text(pid: i32) -> String {
+    match oom::read(pid) {
+        Some((score, adj)) => format!("{} (adjustment {})", score, adj),
+        None => "Unknown".to_string(),
+    }
+}
+
+/// A smaps_rollup value, which reads as 0 when the file wasn't readable.
+fn rollup_text(bytes: u64) -> String {
+    if bytes > 0 {
+        util::format_bytes(bytes)
     } else {
-        let label = gtk::Label::new(Some("Unable to read environment (permission denied?)"));
-        label.set_margin_top(12);
-        list_box.append(&label);
+        "Not available (permission denied?)".to_string()
     }
+}
 
-    gtk::ScrolledWindow::builder()
-        .child(&list_box)
-        .vexpand(true)
-        .build()
+/// Capabilities, seccomp and LSM context, for container/sandbox debugging.
+fn security_rows(obj: &ProcessObject) -> Vec<(&'static str, String)> {
+    let label = obj.security_label();
+    let mut rows = vec![("Security Label", if label.is_empty() { "None".to_string() } else { label })];
+    match capabilities::read_status(obj.pid()) {
+        Some(status) => rows.extend([
+            ("Capabilities (Effective)", capabilities::describe(status.cap_effective)),
+            ("Capabilities (Permitted)", capabilities::describe(status.cap_permitted)),
+            ("Seccomp", capabilities::seccomp_mode(&status)),
+            ("No New Privileges", if status.no_new_privs { "Yes" } else { "No" }.to_string()),
+        ]),
+        None => rows.push(("Capabilities", "Unavailable (process exited?)".to_string())),
+    }
+    rows
+}
+
+/// Kernel function the process is sleeping in, from /proc/<pid>/wchan.
+fn wchan_text(pid: i32) -> String {
+    match std::fs::read_to_string(format!("/proc/{}/wchan", pid)) {
+        // "0" means the process isn't blocked (running, or wchan hidden by kptr_restrict)
+        Ok(wchan) if wchan.trim().is_empty() || wchan.trim() == "0" => "— (not waiting)".to_string(),
+        Ok(wchan) => wchan.trim().to_string(),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => "Permission denied".to_string(),
+        Err(_) => "Unavailable".to_string(),
+    }
+}
+
+/// Kernel call stack from /proc/<pid>/stack with the addresses stripped, or
+/// why it can't be shown (reading it needs root).
+fn read_kernel_stack(pid: i32) -> Result<String, String> {
+    let stack = std::fs::read_to_string(format!("/proc/{}/stack", pid)).map_err(|e| match e.kind() {
+        std::io::ErrorKind::PermissionDenied => "Not permitted (reading the kernel stack requires root)".to_string(),
+        std::io::ErrorKind::NotFound => "Not available (kernel built without CONFIG_STACKTRACE)".to_string(),
+        _ => format!("Unavailable: {}", e),
+    })?;
+    // Lines look like "[<0>] do_sys_poll+0x3d2/0x5a0"
+    let frames: Vec<&str> = stack
+        .lines()
+        .map(|line| line.split_once("] ").map_or(line, |(_, frame)| frame).trim())
+        .filter(|frame| !frame.is_empty())
+        .collect();
+    if frames.is_empty() {
+        Err("— (empty; the process is running in user space)".to_string())
+    } else {
+        Ok(frames.join("\n"))
+    }
 }
 
-fn build_files_tab(pid: i32) -> gtk::ScrolledWindow {
+fn build_threads_tab(pid: i32) -> gtk::ScrolledWindow {
     let list_box = gtk::ListBox::new();
     list_box.set_selection_mode(gtk::SelectionMode::None);
 
-    let fd_dir = format!("/proc/{}/fd", pid);
-    if let Ok(entries) = std::fs::read_dir(&fd_dir) {
-        let mut fds: Vec<(String, String)> = Vec::new();
+    let task_dir = format!("/proc/{}/task", pid);
+    if let Ok(entries) = std::fs::read_dir(&task_dir) {
+        let ticks_per_sec = unsafe { libc::sysconf(libc::_SC_CLK_TCK) }.max(1) as f64;
+
+        // (tid, name, state, cpu ticks, priority, nice)
+        let mut threads: Vec<(i32, String, String, u64, i64, i64)> = Vec::new();
         for entry in entries.flatten() {
-            let fd_name = entry.file_name().to_string_lossy().to_string();
-            let target = std::fs::read_link(entry.path())
-                .map(|p| p.to_string_lossy().to_string())
-                .unwrap_or_else(|_| "?".to_string());
-            fds.push((fd_name, target));
+            let tid: i32 = match entry.file_name().to_string_lossy().parse() {
+                Ok(t) => t,
+                Err(_) => continue,
+            };
+            let stat = match std::fs::read_to_string(entry.path().join("stat")) {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+            // Skip past the comm field, which may contain spaces and parens
+            let fields: Vec<&str> = match stat.rfind(')') {
+                Some(end) if end + 2 <= stat.len() => stat[end + 2..].split_whitespace().collect(),
+                _ => continue,
+            };
+            if fields.len() < 17 {
+                continue;
+            }
+            let name = std::fs::read_to_string(entry.path().join("comm"))
+                .map(|c| c.trim().to_string())
+                .unwrap_or_default();
+            let utime: u64 = fields[11].parse().unwrap_or(0);
+            let stime: u64 = fields[12].parse().unwrap_or(0);
+            let priority: i64 = fields[15].parse().unwrap_or(0);
+            let nice: i64 = fields[16].parse().unwrap_or(0);
+            threads.push((tid, name, fields[0].to_string(), utime + stime, priority, nice));
         }
-        fds.sort_by(|a, b| {
-            a.0.parse::<u32>().unwrap_or(0).cmp(&b.0.parse::<u32>().unwrap_or(0))
-        });
-        for (fd, target) in &fds {
-            let label = gtk::Label::new(Some(&format!("fd {} → {}", fd, target)));
+
+        // Busiest threads first
+        threads.sort_by(|a, b| b.3.cmp(&a.3).then(a.0.cmp(&b.0)));
+
+        let header = gtk::Label::new(Some("TID      Name             State  CPU Time     Prio  Nice"));
+        header.set_halign(gtk::Align::Start);
+        header.add_css_class("monospace");
+        header.add_css_class("dim-label");
+        header.set_margin_start(8);
+        header.set_margin_top(4);
+        list_box.append(&header);
+
+        for (tid, name, state, ticks, priority, nice) in &threads {
+            let cpu_secs = *ticks as f64 / ticks_per_sec;
+            let text = format!(
+                "{:<8} {:<16} {:<6} {:<12} {:<5} {}",
+                tid, name, state, format!("{:.2}s", cpu_secs), priority, nice
+            );
+            let label = gtk::Label::new(Some(&text));
             label.set_halign(gtk::Align::Start);
             label.set_selectable(true);
-            label.set_margin_top(2);
-            label.set_margin_bottom(2);
+            label.add_css_class("monospace");
             label.set_margin_start(8);
+            label.set_margin_top(1);
+            label.set_margin_bottom(1);
             list_box.append(&label);
         }
     } else {
-        let label = gtk::Label::new(Some("Unable to read file descriptors (permission denied?)"));
+        let label = gtk::Label::new(Some("Unable to read threads (process exited?)"));
         label.set_margin_top(12);
         list_box.append(&label);
     }
@@ -1266,31 +3414,161 @@ fn build_files_tab(pid: i32) -> gtk::ScrolledWindow {
         .build()
 }
 
-fn build_maps_tab(pid: i32) -> gtk::ScrolledWindow {
-    let list_box = gtk::ListBox::new();
-    list_box.set_selection_mode(gtk::SelectionMode::None);
+fn build_environ_tab(pid: i32) -> gtk::Box {
+    build_lazy_list_tab(
+        None,
+        move || process_report::read_environment(pid),
+        |var| var.clone(),
+        |label| {
+            label.set_wrap(true);
+            label.set_margin_top(2);
+            label.set_margin_bottom(2);
+        },
+    )
+}
 
-    if let Ok(maps) = std::fs::read_to_string(format!("/proc/{}/maps", pid)) {
-        for line in maps.lines().take(500) {
-            let label = gtk::Label::new(Some(line));
-            label.set_halign(gtk::Align::Start);
-            label.set_selectable(true);
+fn build_files_tab(pid: i32) -> gtk::Box {
+    build_lazy_list_tab(
+        Some("Filter files…"),
+        move || process_report::read_open_files(pid),
+        |file| format!("fd {} → {}", file.fd, file.target),
+        |label| {
+            label.set_margin_top(2);
+            label.set_margin_bottom(2);
+        },
+    )
+}
+
+fn build_maps_tab(pid: i32) -> gtk::Box {
+    build_lazy_list_tab(
+        Some("Filter mappings…"),
+        move || {
+            std::fs::read_to_string(format!("/proc/{}/maps", pid))
+                .map(|maps| maps.lines().map(String::from).collect())
+                .map_err(|_| "Unable to read memory maps (permission denied?)".to_string())
+        },
+        |line| line.clone(),
+        |label| {
             label.set_margin_top(1);
             label.set_margin_bottom(1);
-            label.set_margin_start(8);
             label.add_css_class("monospace");
-            list_box.append(&label);
-        }
-    } else {
-        let label = gtk::Label::new(Some("Unable to read memory maps (permission denied?)"));
-        label.set_margin_top(12);
-        list_box.append(&label);
-    }
+        },
+    )
+}
 
-    gtk::ScrolledWindow::builder()
-        .child(&list_box)
+/// A list tab whose items are read on a worker thread, with a spinner until
+/// they arrive. The lines go into a ListView, which only creates rows for what
+/// is on screen, so a process with tens of thousands of mappings or
+/// descriptors stays responsive. With a `search_placeholder`, a search entry
+/// above the list filters its lines.
+fn build_lazy_list_tab<T: Send + 'static>(
+    search_placeholder: Option<&str>,
+    load: impl FnOnce() -> Result<Vec<T>, String> + Send + 'static,
+    to_text: fn(&T) -> String,
+    style_label: fn(&gtk::Label),
+) -> gtk::Box {
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    let lines = gtk::StringList::new(&[]);
+
+    let filter = gtk::StringFilter::new(Some(gtk::PropertyExpression::new(
+        gtk::StringObject::static_type(),
+        None::<gtk::Expression>,
+        "string",
+    )));
+    filter.set_ignore_case(true);
+    filter.set_match_mode(gtk::StringFilterMatchMode::Substring);
+    if let Some(placeholder) = search_placeholder {
+        let search_entry = gtk::SearchEntry::new();
+        search_entry.set_placeholder_text(Some(placeholder));
+        search_entry.set_margin_top(6);
+        search_entry.set_margin_bottom(6);
+        search_entry.set_margin_start(6);
+        search_entry.set_margin_end(6);
+        vbox.append(&search_entry);
+
+        let filter_for_search = filter.clone();
+        search_entry.connect_search_changed(move |entry| {
+            let query = entry.text();
+            filter_for_search.set_search(if query.is_empty() { None } else { Some(query.as_str()) });
+        });
+    }
+    let filter_model = gtk::FilterListModel::new(Some(lines.clone()), Some(filter));
+
+    let factory = gtk::SignalListItemFactory::new();
+    factory.connect_setup(move |_, item| {
+        let Some(item) = item.downcast_ref::<gtk::ListItem>() else {
+            return;
+        };
+        let label = gtk::Label::new(None);
+        label.set_halign(gtk::Align::Start);
+        label.set_xalign(0.0);
+        label.set_selectable(true);
+        label.set_margin_start(8);
+        style_label(&label);
+        item.set_child(Some(&label));
+    });
+    factory.connect_bind(|_, item| {
+        let Some(item) = item.downcast_ref::<gtk::ListItem>() else {
+            return;
+        };
+        let text = item.item().and_downcast::<gtk::StringObject>().map(|s| s.string());
+        if let (Some(label), Some(text)) = (item.child().and_downcast::<gtk::Label>(), text) {
+            label.set_text(&text);
+        }
+    });
+    let list_view = gtk::ListView::new(Some(gtk::NoSelection::new(Some(filter_model))), Some(factory));
+    let scroll = gtk::ScrolledWindow::builder()
+        .child(&list_view)
         .vexpand(true)
-        .build()
+        .build();
+
+    let spinner = gtk::Spinner::new();
+    spinner.set_spinning(true);
+    spinner.set_size_request(32, 32);
+    spinner.set_halign(gtk::Align::Center);
+    spinner.set_valign(gtk::Align::Center);
+
+    let error_label = gtk::Label::new(None);
+    error_label.set_valign(gtk::Align::Start);
+    error_label.set_margin_top(12);
+
+    let stack = gtk::Stack::new();
+    stack.add_named(&spinner, Some("loading"));
+    stack.add_named(&scroll, Some("list"));
+    stack.add_named(&error_label, Some("error"));
+    stack.set_visible_child_name("loading");
+    stack.set_vexpand(true);
+    vbox.append(&stack);
+
+    // Only weak references cross the await, so closing the dialog drops the result
+    let stack_weak = stack.downgrade();
+    let lines_weak = lines.downgrade();
+    let error_weak = error_label.downgrade();
+    glib::spawn_future_local(async move {
+        let result = gio::spawn_blocking(move || load().map(|items| items.iter().map(to_text).collect::<Vec<_>>()))
+            .await
+            .unwrap_or_else(|_| Err("Failed to read process details".to_string()));
+        let Some(stack) = stack_weak.upgrade() else {
+            return;
+        };
+        match result {
+            Ok(items) => {
+                if let Some(lines) = lines_weak.upgrade() {
+                    let items: Vec<&str> = items.iter().map(String::as_str).collect();
+                    lines.splice(0, 0, &items);
+                }
+                stack.set_visible_child_name("list");
+            }
+            Err(e) => {
+                if let Some(error_label) = error_weak.upgrade() {
+                    error_label.set_text(&e);
+                }
+                stack.set_visible_child_name("error");
+            }
+        }
+    });
+
+    vbox
 }
 
 fn build_network_tab(pid: i32) -> gtk::ScrolledWindow {
@@ -1337,28 +3615,214 @@ fn build_network_tab(pid: i32) -> gtk::ScrolledWindow {
         .build()
 }
 
-fn build_cgroup_tab(pid: i32) -> gtk::ScrolledWindow {
+fn build_cgroup_tab(pid: i32, name: &str) -> gtk::Box {
     let list_box = gtk::ListBox::new();
     list_box.set_selection_mode(gtk::SelectionMode::None);
 
-    if let Ok(cgroup) = std::fs::read_to_string(format!("/proc/{}/cgroup", pid)) {
-        for line in cgroup.lines() {
-            let label = gtk::Label::new(Some(line));
-            label.set_halign(gtk::Align::Start);
-            label.set_selectable(true);
-            label.set_margin_top(2);
-            label.set_margin_bottom(2);
-            label.set_margin_start(8);
+    match process_report::read_cgroup(pid) {
+        Ok(lines) => {
+            for line in &lines {
+                let label = gtk::Label::new(Some(line));
+                label.set_halign(gtk::Align::Start);
+                label.set_selectable(true);
+                label.set_margin_top(2);
+                label.set_margin_bottom(2);
+                label.set_margin_start(8);
+                list_box.append(&label);
+            }
+        }
+        Err(e) => {
+            let label = gtk::Label::new(Some(&e));
+            label.set_margin_top(12);
             list_box.append(&label);
         }
-    } else {
-        let label = gtk::Label::new(Some("Unable to read cgroup info"));
-        label.set_margin_top(12);
-        list_box.append(&label);
     }
 
-    gtk::ScrolledWindow::builder()
+    let limits_btn = gtk::Button::with_label("Set Limits…");
+    limits_btn.set_halign(gtk::Align::End);
+    limits_btn.set_margin_top(6);
+    limits_btn.set_margin_end(8);
+    limits_btn.set_margin_bottom(8);
+    let name = name.to_string();
+    limits_btn.connect_clicked(move |btn| show_cgroup_limits_dialog(btn, pid, &name));
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    vbox.append(&gtk::ScrolledWindow::builder()
         .child(&list_box)
         .vexpand(true)
-        .build()
+        .build());
+    vbox.append(&limits_btn);
+    vbox
+}
+
+/// Plot the CPU and memory history the collector keeps for an app group,
+/// one sample per refresh at the interval in `graph_settings`.
+fn build_history_tab(name: &str, snapshot: &Rc<RefCell<Option<SystemSnapshot>>>, graph_settings: &GraphSettings) -> gtk::Box {
+    let interval_ms = graph_settings.sample_interval_ms();
+    let span_secs = history::MAX_SAMPLES as u64 * interval_ms / 1000;
+    let graph = Rc::new(GraphWidget::new(600, 200, graph_settings));
+    graph.set_title(name);
+    graph.set_series_count(2, vec![
+        GraphColor::new(0.2, 0.6, 1.0), // CPU
+        GraphColor::new(0.6, 0.2, 0.8), // Memory
+    ]);
+    graph.set_labels(&["CPU", "Memory"]);
+    graph.set_series_format(0, util::format_percent);
+    graph.set_series_format(1, |b| util::format_bytes(b as u64));
+    graph.set_time_window(span_secs as usize);
+    graph.set_time_selector_visible(false);
+
+    let refresh = {
+        let graph = graph.clone();
+        let snapshot = snapshot.clone();
+        let name = name.to_string();
+        move || {
+            let snapshot = snapshot.borrow();
+            let Some(history) = snapshot.as_ref().and_then(|s| s.app_histories.get(&name)) else {
+                return;
+            };
+            graph.clear();
+            for (&cpu, &mem) in history.cpu_samples.iter().zip(&history.mem_samples) {
+                graph.push_values(&[cpu, mem]);
+            }
+            let peak_cpu = history.cpu_samples.iter().copied().fold(0.0, f64::max);
+            let peak_mem = history.mem_samples.iter().copied().fold(0.0, f64::max);
+            graph.set_series_max(0, peak_cpu.max(100.0));
+            graph.set_series_max(1, (peak_mem * 1.25).max(1.0));
+        }
+    };
+    refresh();
+
+    // Follow new snapshots until the dialog is closed
+    let weak = graph.widget.downgrade();
+    glib::timeout_add_local(std::time::Duration::from_millis(interval_ms), move || {
+        match weak.upgrade() {
+            Some(widget) if widget.root().is_some() => {
+                refresh();
+                glib::ControlFlow::Continue
+            }
+            _ => glib::ControlFlow::Break,
+        }
+    });
+
+    let caption = gtk::Label::new(Some(&format!(
+        "CPU and memory of all the app's processes over the last {}",
+        util::format_elapsed(span_secs)
+    )));
+    caption.set_halign(gtk::Align::Start);
+    caption.add_css_class("dim-label");
+    caption.add_css_class("caption");
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_margin_top(8);
+    vbox.set_margin_bottom(8);
+    vbox.set_margin_start(8);
+    vbox.set_margin_end(8);
+    graph.widget.set_vexpand(true);
+    vbox.append(&graph.widget);
+    vbox.append(&caption);
+    vbox
+}
+
+/// Edit memory.max and cpu.max of the process's cgroup (v2 only).
+fn show_cgroup_limits_dialog(widget: &gtk::Button, pid: i32, name: &str) {
+    let limits = match cgroup::read_limits(pid) {
+        Ok(limits) => limits,
+        Err(e) => {
+            show_error_dialog(widget, &format!("Cannot set limits for \"{}\" (PID {})\n\n{}", name, pid, e));
+            return;
+        }
+    };
+    if !limits.writable {
+        show_error_dialog(widget, &format!(
+            "The cgroup of \"{}\" (PID {}) is read-only for you:\n{}\n\n\
+             Groups in your own user slice can usually be limited; others need elevated privileges.",
+            name, pid, limits.path
+        ));
+        return;
+    }
+
+    let window = widget.root()
+        .and_then(|r| r.downcast::<gtk::Window>().ok());
+    let dialog = gtk::Window::builder()
+        .title(&format!("{} (PID {}) — Cgroup Limits", name, pid))
+        .default_width(420)
+        .modal(true)
+        .build();
+    if let Some(win) = &window {
+        dialog.set_transient_for(Some(win));
+    }
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_margin_top(12);
+    vbox.set_margin_start(12);
+    vbox.set_margin_end(12);
+    vbox.set_margin_bottom(12);
+
+    let hint = gtk::Label::new(Some(&format!(
+        "{}\n\nLimits apply to every process in this cgroup.",
+        limits.path
+    )));
+    hint.set_halign(gtk::Align::Start);
+    hint.set_wrap(true);
+    hint.set_selectable(true);
+    vbox.append(&hint);
+
+    let grid = gtk::Grid::new();
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+
+    let memory_check = gtk::CheckButton::with_label("Limit memory (MiB)");
+    memory_check.set_active(limits.memory_max.is_some());
+    let memory_spin = gtk::SpinButton::with_range(16.0, 4_194_304.0, 64.0);
+    memory_spin.set_value(limits.memory_max.map_or(1024.0, |b| (b / (1024 * 1024)) as f64));
+    grid.attach(&memory_check, 0, 0, 1, 1);
+    grid.attach(&memory_spin, 1, 0, 1, 1);
+
+    let cpu_check = gtk::CheckButton::with_label("Limit CPU (% of one core)");
+    cpu_check.set_active(limits.cpu_quota_us.is_some());
+    let cpu_spin = gtk::SpinButton::with_range(1.0, 25_600.0, 10.0);
+    cpu_spin.set_value(limits.cpu_percent().unwrap_or(100.0).round());
+    grid.attach(&cpu_check, 0, 1, 1, 1);
+    grid.attach(&cpu_spin, 1, 1, 1, 1);
+
+    memory_check.bind_property("active", &memory_spin, "sensitive").sync_create().build();
+    cpu_check.bind_property("active", &cpu_spin, "sensitive").sync_create().build();
+    vbox.append(&grid);
+
+    let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    button_box.set_halign(gtk::Align::End);
+    let cancel_btn = gtk::Button::with_label("Cancel");
+    let apply_btn = gtk::Button::with_label("Apply");
+    apply_btn.add_css_class("suggested-action");
+    button_box.append(&cancel_btn);
+    button_box.append(&apply_btn);
+    vbox.append(&button_box);
+
+    let dialog_for_cancel = dialog.clone();
+    cancel_btn.connect_clicked(move |_| dialog_for_cancel.close());
+
+    let dialog_for_apply = dialog.clone();
+    let widget_clone = widget.clone();
+    let name = name.to_string();
+    apply_btn.connect_clicked(move |_| {
+        let memory = memory_check.is_active().then(|| memory_spin.value() as u64 * 1024 * 1024);
+        let cpu = cpu_check.is_active().then(|| cpu_spin.value());
+        let result = cgroup::set_memory_max(&limits, memory)
+            .and_then(|_| cgroup::set_cpu_percent(&limits, cpu));
+        match result {
+            Ok(()) => log::info!("Set cgroup limits of {} (PID {}): memory {:?}, cpu {:?}%", name, pid, memory, cpu),
+            Err(e) => {
+                log::error!("Failed to set cgroup limits of {} (PID {}): {}", name, pid, e);
+                show_error_dialog(&widget_clone, &format!(
+                    "Failed to set limits for \"{}\" (PID {})\n\n{}",
+                    name, pid, e
+                ));
+            }
+        }
+        dialog_for_apply.close();
+    });
+
+    dialog.set_child(Some(&vbox));
+    dialog.present();
 }
@@ -0,0 +1,147 @@
+use gtk4 as gtk;
+use gtk::prelude::*;
+use gtk::glib;
+
+use crate::backend::launcher;
+use std::path::PathBuf;
+
+/// "Run new task": start a command line, optionally as root through pkexec.
+pub fn show_run_dialog(parent: &impl IsA<gtk::Window>) {
+    let dialog = gtk::Window::builder()
+        .title("Run New Task")
+        .default_width(480)
+        .modal(true)
+        .resizable(false)
+        .transient_for(parent)
+        .build();
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_margin_top(12);
+    vbox.set_margin_start(12);
+    vbox.set_margin_end(12);
+    vbox.set_margin_bottom(12);
+
+    let hint = gtk::Label::new(Some("Type the name of a program and its arguments, as in a terminal."));
+    hint.set_halign(gtk::Align::Start);
+    hint.set_wrap(true);
+    vbox.append(&hint);
+
+    let grid = gtk::Grid::new();
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+
+    let command_entry = gtk::Entry::new();
+    command_entry.set_placeholder_text(Some("e.g. firefox --private-window"));
+    command_entry.set_hexpand(true);
+
+    let dir_entry = gtk::Entry::new();
+    dir_entry.set_placeholder_text(Some("Home folder"));
+    dir_entry.set_hexpand(true);
+    let browse_btn = gtk::Button::from_icon_name("folder-open-symbolic");
+    browse_btn.set_tooltip_text(Some("Choose folder"));
+
+    let rows: [(&str, &gtk::Widget); 2] = [
+        ("Command", command_entry.upcast_ref()),
+        ("Working directory", dir_entry.upcast_ref()),
+    ];
+    for (i, (label, widget)) in rows.iter().enumerate() {
+        let key = gtk::Label::new(Some(label));
+        key.set_halign(gtk::Align::Start);
+        key.add_css_class("dim-label");
+        grid.attach(&key, 0, i as i32, 1, 1);
+        grid.attach(*widget, 1, i as i32, 1, 1);
+    }
+    grid.attach(&browse_btn, 2, 1, 1, 1);
+    vbox.append(&grid);
+
+    let admin_check = gtk::CheckButton::with_label("Run as administrator (asks for your password)");
+    vbox.append(&admin_check);
+
+    let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    button_box.set_halign(gtk::Align::End);
+    let cancel_btn = gtk::Button::with_label("Cancel");
+    let run_btn = gtk::Button::with_label("Run");
+    run_btn.add_css_class("suggested-action");
+    run_btn.set_sensitive(false);
+    button_box.append(&cancel_btn);
+    button_box.append(&run_btn);
+    vbox.append(&button_box);
+
+    let run_for_entry = run_btn.clone();
+    command_entry.connect_changed(move |entry| {
+        run_for_entry.set_sensitive(!entry.text().trim().is_empty());
+    });
+    // Enter in the command field runs it
+    let run_for_activate = run_btn.clone();
+    command_entry.connect_activate(move |_| {
+        if run_for_activate.is_sensitive() {
+            run_for_activate.emit_clicked();
+        }
+    });
+
+    let dialog_for_browse = dialog.clone();
+    let dir_for_browse = dir_entry.clone();
+    browse_btn.connect_clicked(move |_| {
+        let chooser = gtk::FileChooserDialog::new(
+            Some("Working Directory"),
+            Some(&dialog_for_browse),
+            gtk::FileChooserAction::SelectFolder,
+            &[("Cancel", gtk::ResponseType::Cancel), ("Select", gtk::ResponseType::Accept)],
+        );
+        chooser.set_modal(true);
+        let dir_entry = dir_for_browse.clone();
+        chooser.connect_response(move |d, response| {
+            if response == gtk::ResponseType::Accept {
+                if let Some(path) = d.file().and_then(|f| f.path()) {
+                    dir_entry.set_text(&path.to_string_lossy());
+                }
+            }
+            d.close();
+        });
+        chooser.present();
+    });
+
+    let dialog_for_cancel = dialog.clone();
+    cancel_btn.connect_clicked(move |_| dialog_for_cancel.close());
+
+    let dialog_for_run = dialog.clone();
+    run_btn.connect_clicked(move |_| {
+        let argv = match glib::shell_parse_argv(command_entry.text().trim()) {
+            Ok(argv) => argv,
+            Err(e) => {
+                show_error(&dialog_for_run, &format!("Invalid command: {}", e.message()));
+                return;
+            }
+        };
+        let dir = dir_entry.text().trim().to_string();
+        let cwd = if dir.is_empty() { dirs::home_dir() } else { Some(PathBuf::from(dir)) };
+        if let Some(cwd) = cwd.as_ref().filter(|cwd| !cwd.is_dir()) {
+            show_error(&dialog_for_run, &format!("{} is not a folder", cwd.display()));
+            return;
+        }
+
+        match launcher::launch(&argv, cwd.as_deref(), admin_check.is_active()) {
+            Ok(()) => {
+                log::info!("Started {}", command_entry.text());
+                dialog_for_run.close();
+            }
+            // Keep the dialog open so the command can be corrected
+            Err(e) => show_error(&dialog_for_run, &e),
+        }
+    });
+
+    dialog.set_child(Some(&vbox));
+    dialog.present();
+}
+
+fn show_error(parent: &gtk::Window, message: &str) {
+    let error = gtk::MessageDialog::new(
+        Some(parent),
+        gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+        gtk::MessageType::Error,
+        gtk::ButtonsType::Ok,
+        message,
+    );
+    error.connect_response(|d, _| d.close());
+    error.present();
+}
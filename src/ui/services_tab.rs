@@ -9,6 +9,9 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::backend::services::{ServicesCollector, is_systemd_available};
+use crate::config::Config;
+use crate::ui::column_sort;
+use crate::ui::timers_view::TimersView;
 
 // ---------------------------------------------------------------------------
 // ServiceObject - GObject wrapper for a systemd service entry
@@ -86,10 +89,11 @@ pub struct ServicesTab {
     filter: gtk::CustomFilter,
     content_box: gtk::Box,
     status_page: adw::StatusPage,
+    timers: Rc<TimersView>,
 }
 
 impl ServicesTab {
-    pub fn new() -> Self {
+    pub fn new(config: Rc<RefCell<Config>>) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 0);
         widget.add_css_class("services-view");
 
@@ -101,6 +105,14 @@ impl ServicesTab {
         toolbar.set_margin_top(6);
         toolbar.set_margin_bottom(6);
 
+        // Services / Timers page switcher
+        let pages = gtk::Stack::new();
+        pages.set_vexpand(true);
+        pages.set_hexpand(true);
+        let switcher = gtk::StackSwitcher::new();
+        switcher.set_stack(Some(&pages));
+        toolbar.append(&switcher);
+
         let search_entry = gtk::SearchEntry::new();
         search_entry.set_placeholder_text(Some("Search services..."));
         search_entry.set_hexpand(true);
@@ -115,7 +127,7 @@ impl ServicesTab {
 
         // Refresh button
         let refresh_button = gtk::Button::from_icon_name("view-refresh-symbolic");
-        refresh_button.set_tooltip_text(Some("Refresh services"));
+        refresh_button.set_tooltip_text(Some("Refresh services and timers"));
         toolbar.append(&refresh_button);
 
         widget.append(&toolbar);
@@ -348,6 +360,7 @@ impl ServicesTab {
         if let Some(s) = cv_sorter {
             sort_model.set_sorter(Some(&s));
         }
+        column_sort::persist(&column_view, &config, "services");
 
         // --- Scroll window ---
         let scroll = gtk::ScrolledWindow::builder()
@@ -370,10 +383,22 @@ impl ServicesTab {
         content_box.set_vexpand(true);
         content_box.set_hexpand(true);
 
+        // systemd timers share the search field; the status filter only applies to services
+        let timers = Rc::new(TimersView::new(&search_entry, &config));
+        pages.add_titled(&scroll, Some("services"), "Services");
+        pages.add_titled(&timers.widget, Some("timers"), "Timers");
+        {
+            let dropdown = filter_dropdown.clone();
+            pages.connect_visible_child_name_notify(move |pages| {
+                dropdown.set_visible(pages.visible_child_name().as_deref() != Some("timers"));
+            });
+        }
+
         // Decide which to show based on systemd availability
         if is_systemd_available() {
-            content_box.append(&scroll);
+            content_box.append(&pages);
         } else {
+            switcher.set_visible(false);
             content_box.append(&status_page);
         }
 
@@ -386,6 +411,13 @@ impl ServicesTab {
         menu.append(Some("Restart"), Some("service.restart"));
         menu.append(Some("Enable"), Some("service.enable"));
         menu.append(Some("Disable"), Some("service.disable"));
+        menu.append(Some("Mask"), Some("service.mask"));
+        menu.append(Some("Unmask"), Some("service.unmask"));
+        menu.append(Some("View Logs"), Some("service.logs"));
+        menu.append(Some("Edit Unit File…"), Some("service.edit"));
+        let daemon_section = gio::Menu::new();
+        daemon_section.append(Some("Reload Daemon"), Some("service.daemon-reload"));
+        menu.append_section(None, &daemon_section);
 
         let popover = gtk::PopoverMenu::from_model(Some(&menu));
         popover.set_parent(&column_view);
@@ -459,6 +491,55 @@ impl ServicesTab {
             &selection,
             &column_view,
         ));
+        // Masking links the unit to /dev/null so nothing can start it, not even dependencies
+        action_group.add_action(&make_service_action(
+            "mask",
+            "mask",
+            true,
+            &selection,
+            &column_view,
+        ));
+        action_group.add_action(&make_service_action(
+            "unmask",
+            "unmask",
+            false,
+            &selection,
+            &column_view,
+        ));
+
+        // Reload unit files after editing them; doesn't depend on the selection
+        let cv_for_reload = column_view.clone();
+        let daemon_reload_action = gio::SimpleAction::new("daemon-reload", None);
+        daemon_reload_action.connect_activate(move |_, _| {
+            run_service_action(&cv_for_reload, "", "daemon-reload");
+        });
+        action_group.add_action(&daemon_reload_action);
+
+        let sel_for_logs = selection.clone();
+        let cv_for_logs = column_view.clone();
+        let logs_action = gio::SimpleAction::new("logs", None);
+        logs_action.connect_activate(move |_, _| {
+            if let Some(obj) = sel_for_logs
+                .selected_item()
+                .and_then(|i| i.downcast::<ServiceObject>().ok())
+            {
+                show_logs_dialog(&cv_for_logs, &obj.name());
+            }
+        });
+        action_group.add_action(&logs_action);
+
+        let sel_for_edit = selection.clone();
+        let cv_for_edit = column_view.clone();
+        let edit_action = gio::SimpleAction::new("edit", None);
+        edit_action.connect_activate(move |_, _| {
+            if let Some(obj) = sel_for_edit
+                .selected_item()
+                .and_then(|i| i.downcast::<ServiceObject>().ok())
+            {
+                crate::ui::unit_editor::show_unit_editor(&cv_for_edit, &obj.name(), false);
+            }
+        });
+        action_group.add_action(&edit_action);
 
         column_view.insert_action_group("service", Some(&action_group));
 
@@ -492,10 +573,12 @@ impl ServicesTab {
             Rc::new(RefCell::new(Some(store.clone())));
         {
             let store_rc_clone = store_rc.clone();
+            let timers_ref = timers.clone();
             refresh_button.connect_clicked(move |_| {
                 if let Some(ref s) = *store_rc_clone.borrow() {
                     populate_store(s);
                 }
+                timers_ref.load();
             });
         }
 
@@ -507,6 +590,7 @@ impl ServicesTab {
             filter,
             content_box,
             status_page,
+            timers,
         }
     }
 
@@ -514,6 +598,7 @@ impl ServicesTab {
     pub fn load(&mut self) {
         if is_systemd_available() {
             populate_store(&self.store);
+            self.timers.load();
         }
     }
 }
@@ -602,6 +687,132 @@ fn run_service_action(_column_view: &gtk::ColumnView, service_name: &str, action
     });
 }
 
+/// Show the recent journal for a service in a monospace dialog, with manual
+/// refresh and an optional follow mode that re-reads it every two seconds.
+fn show_logs_dialog(column_view: &gtk::ColumnView, service_name: &str) {
+    let window = column_view
+        .root()
+        .and_then(|r| r.downcast::<gtk::Window>().ok());
+
+    let dialog = gtk::Window::builder()
+        .title(&format!("{} — Logs", service_name))
+        .default_width(800)
+        .default_height(500)
+        .modal(true)
+        .build();
+    if let Some(win) = &window {
+        dialog.set_transient_for(Some(win));
+    }
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
+
+    let toolbar = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    toolbar.add_css_class("toolbar");
+    toolbar.set_margin_start(6);
+    toolbar.set_margin_end(6);
+    toolbar.set_margin_top(6);
+    toolbar.set_margin_bottom(6);
+
+    let scope_dropdown = gtk::DropDown::new(
+        Some(gtk::StringList::new(&["System journal", "User journal"])),
+        gtk::Expression::NONE,
+    );
+    scope_dropdown.set_tooltip_text(Some("Journal to read from"));
+    toolbar.append(&scope_dropdown);
+
+    let spacer = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    spacer.set_hexpand(true);
+    toolbar.append(&spacer);
+
+    let follow_toggle = gtk::ToggleButton::with_label("Follow");
+    follow_toggle.set_tooltip_text(Some("Re-read the journal every 2 seconds"));
+    toolbar.append(&follow_toggle);
+
+    let refresh_button = gtk::Button::from_icon_name("view-refresh-symbolic");
+    refresh_button.set_tooltip_text(Some("Refresh logs"));
+    toolbar.append(&refresh_button);
+
+    vbox.append(&toolbar);
+
+    let text_view = gtk::TextView::new();
+    text_view.set_editable(false);
+    text_view.set_cursor_visible(false);
+    text_view.set_monospace(true);
+    text_view.set_wrap_mode(gtk::WrapMode::WordChar);
+    text_view.set_left_margin(8);
+    text_view.set_right_margin(8);
+
+    let scroll = gtk::ScrolledWindow::builder()
+        .vexpand(true)
+        .hexpand(true)
+        .child(&text_view)
+        .build();
+    vbox.append(&scroll);
+
+    dialog.set_child(Some(&vbox));
+
+    let name = service_name.to_string();
+    let load: Rc<dyn Fn()> = {
+        let text_view = text_view.clone();
+        let scope_dropdown = scope_dropdown.clone();
+        Rc::new(move || {
+            let name = name.clone();
+            let user = scope_dropdown.selected() == 1;
+            let text_view = text_view.clone();
+            glib::spawn_future_local(async move {
+                let result = gio::spawn_blocking(move || ServicesCollector::journal(&name, user, 200)).await;
+                let text = match result {
+                    Ok(Ok(out)) if out.trim().is_empty() => "No journal entries for this unit.".to_string(),
+                    Ok(Ok(out)) => out,
+                    Ok(Err(e)) => e,
+                    Err(_) => "Failed to read journal".to_string(),
+                };
+                let buffer = text_view.buffer();
+                buffer.set_text(&text);
+                buffer.place_cursor(&buffer.end_iter());
+                text_view.scroll_mark_onscreen(&buffer.get_insert());
+            });
+        })
+    };
+
+    {
+        let load = load.clone();
+        refresh_button.connect_clicked(move |_| load());
+    }
+    {
+        let load = load.clone();
+        scope_dropdown.connect_selected_notify(move |_| load());
+    }
+
+    let follow_source: Rc<RefCell<Option<glib::SourceId>>> = Rc::new(RefCell::new(None));
+    {
+        let load = load.clone();
+        let follow_source = follow_source.clone();
+        follow_toggle.connect_toggled(move |btn| {
+            if let Some(id) = follow_source.borrow_mut().take() {
+                id.remove();
+            }
+            if btn.is_active() {
+                let load = load.clone();
+                let id = glib::timeout_add_seconds_local(2, move || {
+                    load();
+                    glib::ControlFlow::Continue
+                });
+                *follow_source.borrow_mut() = Some(id);
+            }
+        });
+    }
+    dialog.connect_close_request(move |_| {
+        if let Some(id) = follow_source.borrow_mut().take() {
+            id.remove();
+        }
+        glib::Propagation::Proceed
+    });
+
+    load();
+    dialog.present();
+}
+
 fn capitalize(s: &str) -> String {
     let mut c = s.chars();
     match c.next() {
@@ -5,7 +5,11 @@ use gtk::gio;
 use gtk::subclass::prelude::ObjectSubclassIsExt;
 
 use crate::backend::startup::StartupCollector;
-use crate::model::startup_entry::{StartupEntry, StartupSource};
+use crate::config::Config;
+use crate::model::startup_entry::{StartupEntry, StartupImpact, StartupSource};
+use crate::ui::column_sort;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 // GObject wrapper for startup entry data in the model
 mod imp {
@@ -26,6 +30,8 @@ mod imp {
         pub launch_minimized: RefCell<bool>,
         pub wm_class: RefCell<String>,
         pub active_state: RefCell<String>,
+        pub impact: RefCell<StartupImpact>,
+        pub impact_reason: RefCell<String>,
     }
 
     #[glib::object_subclass]
@@ -59,6 +65,8 @@ impl StartupObject {
         *imp.launch_minimized.borrow_mut() = entry.launch_minimized;
         *imp.wm_class.borrow_mut() = entry.wm_class.clone();
         *imp.active_state.borrow_mut() = entry.active_state.clone();
+        *imp.impact.borrow_mut() = entry.impact;
+        *imp.impact_reason.borrow_mut() = entry.impact_reason.clone();
     }
 
     pub fn name(&self) -> String {
@@ -88,6 +96,12 @@ impl StartupObject {
     pub fn active_state(&self) -> String {
         self.imp().active_state.borrow().clone()
     }
+    pub fn impact(&self) -> StartupImpact {
+        *self.imp().impact.borrow()
+    }
+    pub fn impact_reason(&self) -> String {
+        self.imp().impact_reason.borrow().clone()
+    }
 
     pub fn to_startup_entry(&self) -> StartupEntry {
         let imp = self.imp();
@@ -106,6 +120,8 @@ impl StartupObject {
                 StartupSource::Autostart
             },
             active_state: imp.active_state.borrow().clone(),
+            impact: *imp.impact.borrow(),
+            impact_reason: imp.impact_reason.borrow().clone(),
         }
     }
 
@@ -128,7 +144,7 @@ pub struct StartupTab {
 }
 
 impl StartupTab {
-    pub fn new() -> Self {
+    pub fn new(config: Rc<RefCell<Config>>) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 0);
         widget.add_css_class("startup-view");
 
@@ -145,6 +161,10 @@ impl StartupTab {
         search_entry.add_css_class("search-bar");
         toolbar.append(&search_entry);
 
+        let add_button = gtk::Button::from_icon_name("list-add-symbolic");
+        add_button.set_tooltip_text(Some("Add startup app"));
+        toolbar.append(&add_button);
+
         let refresh_button = gtk::Button::from_icon_name("view-refresh-symbolic");
         refresh_button.set_tooltip_text(Some("Refresh startup entries"));
         toolbar.append(&refresh_button);
@@ -380,6 +400,45 @@ impl StartupTab {
         running_col.set_resizable(false);
         column_view.append_column(&running_col);
 
+        // Startup impact column (systemd units timed by systemd-analyze,
+        // autostart apps only flagged when known to be heavy)
+        let impact_factory = gtk::SignalListItemFactory::new();
+        impact_factory.connect_setup(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let label = gtk::Label::new(None);
+            label.set_halign(gtk::Align::Center);
+            item.set_child(Some(&label));
+        });
+        impact_factory.connect_bind(|_, item| {
+            let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+            let obj = item.item().and_downcast::<StartupObject>().unwrap();
+            let label = item.child().and_downcast::<gtk::Label>().unwrap();
+
+            label.remove_css_class("warning");
+            label.remove_css_class("error");
+            label.remove_css_class("dim-label");
+
+            let impact = obj.impact();
+            label.set_text(&impact.to_string());
+            label.set_tooltip_text(Some(&obj.impact_reason()));
+            match impact {
+                StartupImpact::High => label.add_css_class("error"),
+                StartupImpact::Medium => label.add_css_class("warning"),
+                StartupImpact::Low => {}
+                StartupImpact::NotMeasured => label.add_css_class("dim-label"),
+            }
+        });
+        let impact_col = gtk::ColumnViewColumn::new(Some("Impact"), Some(impact_factory));
+        impact_col.set_fixed_width(110);
+        impact_col.set_resizable(true);
+        let impact_sorter = gtk::CustomSorter::new(|a, b| {
+            let sa = a.downcast_ref::<StartupObject>().unwrap();
+            let sb = b.downcast_ref::<StartupObject>().unwrap();
+            sa.impact().cmp(&sb.impact()).into()
+        });
+        impact_col.set_sorter(Some(&impact_sorter));
+        column_view.append_column(&impact_col);
+
         // Type column
         let type_factory = gtk::SignalListItemFactory::new();
         type_factory.connect_setup(|_, item| {
@@ -436,6 +495,7 @@ impl StartupTab {
         if let Some(s) = cv_sorter {
             sort_model.set_sorter(Some(&s));
         }
+        column_sort::persist(&column_view, &config, "startup");
 
         // Scroll window
         let scroll = gtk::ScrolledWindow::builder()
@@ -450,6 +510,7 @@ impl StartupTab {
         menu.append(Some("Start"), Some("startup.start"));
         menu.append(Some("Stop"), Some("startup.stop"));
         menu.append(Some("Restart"), Some("startup.restart"));
+        menu.append(Some("Edit Unit File…"), Some("startup.edit"));
 
         let popover = gtk::PopoverMenu::from_model(Some(&menu));
         popover.set_parent(&column_view);
@@ -504,6 +565,21 @@ impl StartupTab {
         action_group.add_action(&make_action("stop", "stop", &selection));
         action_group.add_action(&make_action("restart", "restart", &selection));
 
+        let sel_for_edit = selection.clone();
+        let cv_for_edit = column_view.clone();
+        let edit_action = gio::SimpleAction::new("edit", None);
+        edit_action.connect_activate(move |_, _| {
+            if let Some(obj) = sel_for_edit
+                .selected_item()
+                .and_then(|i| i.downcast::<StartupObject>().ok())
+                .filter(|obj| obj.source() == "Systemd")
+            {
+                // Systemd startup entries are user units, named by file_path
+                crate::ui::unit_editor::show_unit_editor(&cv_for_edit, &obj.file_path(), true);
+            }
+        });
+        action_group.add_action(&edit_action);
+
         column_view.insert_action_group("startup", Some(&action_group));
 
         // Right-click gesture — only show menu for systemd services
@@ -550,27 +626,133 @@ impl StartupTab {
         // Refresh button: reload entries
         let store_ref = tab.store.clone();
         refresh_button.connect_clicked(move |_| {
-            let entries = StartupCollector::collect();
-            store_ref.remove_all();
-            for entry in &entries {
-                let obj = StartupObject::new();
-                obj.set_from_entry(entry);
-                store_ref.append(&obj);
-            }
-            log::info!("Refreshed startup entries: {} found", entries.len());
+            let count = reload_store(&store_ref);
+            log::info!("Refreshed startup entries: {} found", count);
+        });
+
+        // Add button: new autostart entry, then reload
+        let store_ref = tab.store.clone();
+        add_button.connect_clicked(move |button| {
+            show_add_dialog(button.upcast_ref(), &store_ref);
         });
 
         tab
     }
 
     pub fn load(&mut self) {
-        let entries = StartupCollector::collect();
-        self.store.remove_all();
-        for entry in &entries {
-            let obj = StartupObject::new();
-            obj.set_from_entry(entry);
-            self.store.append(&obj);
-        }
-        log::info!("Loaded startup entries: {} found", entries.len());
+        let count = reload_store(&self.store);
+        log::info!("Loaded startup entries: {} found", count);
+    }
+}
+
+/// Replace the store contents with freshly collected entries; returns the count.
+fn reload_store(store: &gio::ListStore) -> usize {
+    let entries = StartupCollector::collect();
+    store.remove_all();
+    for entry in &entries {
+        let obj = StartupObject::new();
+        obj.set_from_entry(entry);
+        store.append(&obj);
     }
+    entries.len()
+}
+
+/// Dialog for a new ~/.config/autostart entry. Errors (e.g. a command that
+/// isn't installed) are shown inline so the input can be corrected.
+fn show_add_dialog(parent: &gtk::Widget, store: &gio::ListStore) {
+    let window = parent.root().and_then(|r| r.downcast::<gtk::Window>().ok());
+
+    let dialog = gtk::Window::builder()
+        .title("Add Startup App")
+        .default_width(420)
+        .modal(true)
+        .build();
+    if let Some(win) = &window {
+        dialog.set_transient_for(Some(win));
+    }
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 12);
+    vbox.set_margin_top(12);
+    vbox.set_margin_start(12);
+    vbox.set_margin_end(12);
+    vbox.set_margin_bottom(12);
+
+    let grid = gtk::Grid::new();
+    grid.set_row_spacing(6);
+    grid.set_column_spacing(12);
+    let name_entry = gtk::Entry::new();
+    let exec_entry = gtk::Entry::new();
+    exec_entry.set_placeholder_text(Some("e.g. syncthing --no-browser"));
+    let comment_entry = gtk::Entry::new();
+    comment_entry.set_placeholder_text(Some("Optional"));
+    for (row, (label, entry)) in [("Name", &name_entry), ("Command", &exec_entry), ("Comment", &comment_entry)]
+        .into_iter()
+        .enumerate()
+    {
+        let label = gtk::Label::new(Some(label));
+        label.set_halign(gtk::Align::Start);
+        entry.set_hexpand(true);
+        grid.attach(&label, 0, row as i32, 1, 1);
+        grid.attach(entry, 1, row as i32, 1, 1);
+    }
+    vbox.append(&grid);
+
+    let minimized_check = gtk::CheckButton::with_label("Launch minimized");
+    vbox.append(&minimized_check);
+
+    let error_label = gtk::Label::new(None);
+    error_label.add_css_class("error");
+    error_label.set_halign(gtk::Align::Start);
+    error_label.set_wrap(true);
+    error_label.set_visible(false);
+    vbox.append(&error_label);
+
+    let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    button_box.set_halign(gtk::Align::End);
+    let cancel_btn = gtk::Button::with_label("Cancel");
+    let add_btn = gtk::Button::with_label("Add");
+    add_btn.add_css_class("suggested-action");
+    add_btn.set_sensitive(false);
+    button_box.append(&cancel_btn);
+    button_box.append(&add_btn);
+    vbox.append(&button_box);
+
+    // Name and command are required
+    for entry in [&name_entry, &exec_entry] {
+        let name_entry = name_entry.clone();
+        let exec_entry = exec_entry.clone();
+        let add_btn = add_btn.clone();
+        entry.connect_changed(move |_| {
+            add_btn.set_sensitive(
+                !name_entry.text().trim().is_empty() && !exec_entry.text().trim().is_empty(),
+            );
+        });
+    }
+
+    let dialog_for_cancel = dialog.clone();
+    cancel_btn.connect_clicked(move |_| dialog_for_cancel.close());
+
+    let dialog_for_add = dialog.clone();
+    let store = store.clone();
+    add_btn.connect_clicked(move |_| {
+        match StartupCollector::create_autostart(
+            &name_entry.text(),
+            &exec_entry.text(),
+            &comment_entry.text(),
+            minimized_check.is_active(),
+        ) {
+            Ok(_) => {
+                reload_store(&store);
+                dialog_for_add.close();
+            }
+            Err(e) => {
+                log::error!("Failed to create startup entry: {}", e);
+                error_label.set_text(&e);
+                error_label.set_visible(true);
+            }
+        }
+    });
+
+    dialog.set_child(Some(&vbox));
+    dialog.present();
 }
@@ -0,0 +1,191 @@
+use gtk4 as gtk;
+use gtk::prelude::*;
+use gtk::glib;
+use gtk::gio;
+use gtk::subclass::prelude::ObjectSubclassIsExt;
+
+use crate::backend::services::ServicesCollector;
+use crate::config::Config;
+use crate::ui::column_sort;
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::model::timer_entry::TimerEntry;
+
+// ---------------------------------------------------------------------------
+// TimerObject - GObject wrapper for a systemd timer
+// ---------------------------------------------------------------------------
+
+mod imp {
+    use super::*;
+    use gtk::glib;
+    use gtk::subclass::prelude::*;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Default)]
+    pub struct TimerObject {
+        pub entry: RefCell<TimerEntry>,
+        // Position in systemctl's output, which is ordered by next elapse
+        pub order: Cell<u32>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for TimerObject {
+        const NAME: &'static str = "TimerObject";
+        type Type = super::TimerObject;
+        type ParentType = glib::Object;
+    }
+
+    impl ObjectImpl for TimerObject {}
+}
+
+glib::wrapper! {
+    pub struct TimerObject(ObjectSubclass<imp::TimerObject>);
+}
+
+impl TimerObject {
+    pub fn new() -> Self {
+        glib::Object::builder().build()
+    }
+
+    pub fn set_from_entry(&self, entry: &TimerEntry, order: u32) {
+        *self.imp().entry.borrow_mut() = entry.clone();
+        self.imp().order.set(order);
+    }
+
+    pub fn entry(&self) -> TimerEntry {
+        self.imp().entry.borrow().clone()
+    }
+
+    pub fn order(&self) -> u32 {
+        self.imp().order.get()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TimersView - systemd timers list shown as a page of the Services tab
+// ---------------------------------------------------------------------------
+
+pub struct TimersView {
+    pub widget: gtk::ScrolledWindow,
+    store: gio::ListStore,
+}
+
+impl TimersView {
+    /// `search_entry` is the Services tab's search field, shared by both pages.
+    pub fn new(search_entry: &gtk::SearchEntry, config: &Rc<RefCell<Config>>) -> Self {
+        let store = gio::ListStore::new::<TimerObject>();
+
+        let search_weak = search_entry.downgrade();
+        let filter = gtk::CustomFilter::new(move |obj| {
+            let Some(search_entry) = search_weak.upgrade() else {
+                return true;
+            };
+            let text = search_entry.text().to_string().to_lowercase();
+            if text.is_empty() {
+                return true;
+            }
+            let entry = obj.downcast_ref::<TimerObject>().unwrap().entry();
+            entry.unit.to_lowercase().contains(&text) || entry.activates.to_lowercase().contains(&text)
+        });
+        let filter_model = gtk::FilterListModel::new(Some(store.clone()), Some(filter.clone()));
+        {
+            let filter_ref = filter.clone();
+            search_entry.connect_search_changed(move |_| {
+                filter_ref.changed(gtk::FilterChange::Different);
+            });
+        }
+
+        let sort_model = gtk::SortListModel::new(Some(filter_model), None::<gtk::Sorter>);
+        let selection = gtk::SingleSelection::new(Some(sort_model.clone()));
+        selection.set_autoselect(false);
+
+        let column_view = gtk::ColumnView::new(Some(selection));
+        column_view.set_show_column_separators(true);
+        column_view.set_show_row_separators(false);
+
+        // Times sort by schedule order rather than by their text
+        let by_order = |a: &glib::Object, b: &glib::Object| {
+            let ta = a.downcast_ref::<TimerObject>().unwrap();
+            let tb = b.downcast_ref::<TimerObject>().unwrap();
+            ta.order().cmp(&tb.order()).into()
+        };
+        let columns: [(&str, fn(&TimerEntry) -> String, bool, i32); 7] = [
+            ("Timer", |e| e.unit.clone(), false, -1),
+            ("Activates", |e| e.activates.clone(), false, -1),
+            ("Next", |e| e.next.clone(), true, 210),
+            ("Left", |e| e.left.clone(), true, 100),
+            ("Last", |e| e.last.clone(), false, 210),
+            ("Passed", |e| e.passed.clone(), false, 100),
+            ("Scope", |e| if e.user { "User".into() } else { "System".into() }, false, 70),
+        ];
+        for (title, text_of, schedule_sorted, width) in columns {
+            let factory = gtk::SignalListItemFactory::new();
+            factory.connect_setup(|_, item| {
+                let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+                let label = gtk::Label::new(None);
+                label.set_halign(gtk::Align::Start);
+                label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+                item.set_child(Some(&label));
+            });
+            factory.connect_bind(move |_, item| {
+                let item = item.downcast_ref::<gtk::ListItem>().unwrap();
+                let obj = item.item().and_downcast::<TimerObject>().unwrap();
+                let label = item.child().and_downcast::<gtk::Label>().unwrap();
+                let text = text_of(&obj.entry());
+                label.set_text(if text.is_empty() { "—" } else { &text });
+            });
+            let column = gtk::ColumnViewColumn::new(Some(title), Some(factory));
+            column.set_resizable(true);
+            if width > 0 {
+                column.set_fixed_width(width);
+            } else {
+                column.set_expand(true);
+            }
+            let sorter = if schedule_sorted {
+                gtk::CustomSorter::new(by_order)
+            } else {
+                gtk::CustomSorter::new(move |a, b| {
+                    let ta = text_of(&a.downcast_ref::<TimerObject>().unwrap().entry()).to_lowercase();
+                    let tb = text_of(&b.downcast_ref::<TimerObject>().unwrap().entry()).to_lowercase();
+                    ta.cmp(&tb).into()
+                })
+            };
+            column.set_sorter(Some(&sorter));
+            column_view.append_column(&column);
+        }
+        if let Some(sorter) = column_view.sorter() {
+            sort_model.set_sorter(Some(&sorter));
+        }
+        column_sort::persist(&column_view, config, "timers");
+
+        let widget = gtk::ScrolledWindow::builder()
+            .vexpand(true)
+            .hexpand(true)
+            .child(&column_view)
+            .build();
+
+        Self { widget, store }
+    }
+
+    /// Load (or reload) the timer list from systemd in the background, then
+    /// fill the list with the result.
+    pub fn load(&self) {
+        let store = self.store.clone();
+        glib::spawn_future_local(async move {
+            let Ok(timers) = gio::spawn_blocking(ServicesCollector::collect_timers).await else {
+                log::error!("Failed to list systemd timers");
+                return;
+            };
+            let objects: Vec<TimerObject> = timers
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    let obj = TimerObject::new();
+                    obj.set_from_entry(entry, i as u32);
+                    obj
+                })
+                .collect();
+            store.splice(0, store.n_items(), &objects);
+        });
+    }
+}
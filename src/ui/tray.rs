@@ -0,0 +1,416 @@
+use gtk4 as gtk;
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::prelude::*;
+use gtk::glib::variant::ObjectPath;
+
+use crate::model::SystemSnapshot;
+use crate::util;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// GTK4 has no StatusIcon, so the tray icon is exported over D-Bus as a
+// StatusNotifierItem (KDE, GNOME AppIndicator extension, waybar, ...) with a
+// com.canonical.dbusmenu object for its context menu.
+const ITEM_PATH: &str = "/StatusNotifierItem";
+const ITEM_IFACE: &str = "org.kde.StatusNotifierItem";
+const MENU_PATH: &str = "/StatusNotifierItem/Menu";
+const MENU_IFACE: &str = "com.canonical.dbusmenu";
+const WATCHER_NAME: &str = "org.kde.StatusNotifierWatcher";
+const WATCHER_PATH: &str = "/StatusNotifierWatcher";
+
+const ICON_NAME: &str = "utilities-system-monitor";
+
+const INTROSPECTION_XML: &str = r#"
+<node>
+  <interface name="org.kde.StatusNotifierItem">
+    <property name="Category" type="s" access="read"/>
+    <property name="Id" type="s" access="read"/>
+    <property name="Title" type="s" access="read"/>
+    <property name="Status" type="s" access="read"/>
+    <property name="WindowId" type="i" access="read"/>
+    <property name="IconName" type="s" access="read"/>
+    <property name="IconThemePath" type="s" access="read"/>
+    <property name="ToolTip" type="(sa(iiay)ss)" access="read"/>
+    <property name="ItemIsMenu" type="b" access="read"/>
+    <property name="Menu" type="o" access="read"/>
+    <property name="XAyatanaLabel" type="s" access="read"/>
+    <property name="XAyatanaLabelGuide" type="s" access="read"/>
+    <method name="Activate">
+      <arg name="x" type="i" direction="in"/>
+      <arg name="y" type="i" direction="in"/>
+    </method>
+    <method name="SecondaryActivate">
+      <arg name="x" type="i" direction="in"/>
+      <arg name="y" type="i" direction="in"/>
+    </method>
+    <method name="ContextMenu">
+      <arg name="x" type="i" direction="in"/>
+      <arg name="y" type="i" direction="in"/>
+    </method>
+    <method name="Scroll">
+      <arg name="delta" type="i" direction="in"/>
+      <arg name="orientation" type="s" direction="in"/>
+    </method>
+    <signal name="NewTitle"/>
+    <signal name="NewIcon"/>
+    <signal name="NewToolTip"/>
+    <signal name="NewStatus">
+      <arg name="status" type="s"/>
+    </signal>
+    <signal name="XAyatanaNewLabel">
+      <arg name="label" type="s"/>
+      <arg name="guide" type="s"/>
+    </signal>
+  </interface>
+  <interface name="com.canonical.dbusmenu">
+    <property name="Version" type="u" access="read"/>
+    <property name="TextDirection" type="s" access="read"/>
+    <property name="Status" type="s" access="read"/>
+    <property name="IconThemePath" type="as" access="read"/>
+    <method name="GetLayout">
+      <arg name="parentId" type="i" direction="in"/>
+      <arg name="recursionDepth" type="i" direction="in"/>
+      <arg name="propertyNames" type="as" direction="in"/>
+      <arg name="revision" type="u" direction="out"/>
+      <arg name="layout" type="(ia{sv}av)" direction="out"/>
+    </method>
+    <method name="GetGroupProperties">
+      <arg name="ids" type="ai" direction="in"/>
+      <arg name="propertyNames" type="as" direction="in"/>
+      <arg name="properties" type="a(ia{sv})" direction="out"/>
+    </method>
+    <method name="GetProperty">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="name" type="s" direction="in"/>
+      <arg name="value" type="v" direction="out"/>
+    </method>
+    <method name="Event">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="eventId" type="s" direction="in"/>
+      <arg name="data" type="v" direction="in"/>
+      <arg name="timestamp" type="u" direction="in"/>
+    </method>
+    <method name="EventGroup">
+      <arg name="events" type="a(isvu)" direction="in"/>
+      <arg name="idErrors" type="ai" direction="out"/>
+    </method>
+    <method name="AboutToShow">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="needUpdate" type="b" direction="out"/>
+    </method>
+    <method name="AboutToShowGroup">
+      <arg name="ids" type="ai" direction="in"/>
+      <arg name="updatesNeeded" type="ai" direction="out"/>
+      <arg name="idErrors" type="ai" direction="out"/>
+    </method>
+    <signal name="ItemsPropertiesUpdated">
+      <arg name="updatedProps" type="a(ia{sv})"/>
+      <arg name="removedProps" type="a(ias)"/>
+    </signal>
+    <signal name="LayoutUpdated">
+      <arg name="revision" type="u"/>
+      <arg name="parent" type="i"/>
+    </signal>
+    <signal name="ItemActivationRequested">
+      <arg name="id" type="i"/>
+      <arg name="timestamp" type="u"/>
+    </signal>
+  </interface>
+</node>
+"#;
+
+// dbusmenu item ids (0 is the root)
+const MENU_ROOT: i32 = 0;
+const MENU_TOP_PROCESS: i32 = 1;
+const MENU_SEPARATOR: i32 = 2;
+const MENU_SHOW: i32 = 3;
+const MENU_QUIT: i32 = 4;
+const MENU_ITEMS: [i32; 4] = [MENU_TOP_PROCESS, MENU_SEPARATOR, MENU_SHOW, MENU_QUIT];
+
+/// Text currently shown by the tray icon
+#[derive(Default)]
+struct TrayState {
+    label: String,
+    tooltip: String,
+    top_process: String,
+}
+
+pub struct TrayIcon {
+    connection: gio::DBusConnection,
+    state: Rc<RefCell<TrayState>>,
+    available: Rc<Cell<bool>>,
+}
+
+impl TrayIcon {
+    /// Export the tray icon on `connection` and register it with the
+    /// StatusNotifierWatcher. `on_availability` is called whenever a tray host
+    /// appears or disappears, so the caller can bring the window back if there
+    /// is nowhere to restore it from.
+    pub fn new(
+        connection: &gio::DBusConnection,
+        on_show: impl Fn() + 'static,
+        on_quit: impl Fn() + 'static,
+        on_availability: impl Fn(bool) + 'static,
+    ) -> Result<Self, String> {
+        let node = gio::DBusNodeInfo::for_xml(INTROSPECTION_XML).map_err(|e| e.to_string())?;
+        let item_iface = node.lookup_interface(ITEM_IFACE)
+            .ok_or_else(|| format!("Missing {} introspection data", ITEM_IFACE))?;
+        let menu_iface = node.lookup_interface(MENU_IFACE)
+            .ok_or_else(|| format!("Missing {} introspection data", MENU_IFACE))?;
+
+        let state = Rc::new(RefCell::new(TrayState {
+            label: String::new(),
+            tooltip: String::new(),
+            top_process: "Top: —".to_string(),
+        }));
+        let on_show: Rc<dyn Fn()> = Rc::new(on_show);
+        let on_quit: Rc<dyn Fn()> = Rc::new(on_quit);
+
+        // StatusNotifierItem object
+        let state_for_item = state.clone();
+        let show_for_item = on_show.clone();
+        connection
+            .register_object(ITEM_PATH, &item_iface)
+            .method_call(move |_, _, _, _, method, _, invocation| {
+                if method == "Activate" {
+                    show_for_item();
+                }
+                invocation.return_value(None);
+            })
+            .property(move |_, _, _, _, property| {
+                item_property(&state_for_item.borrow(), property)
+            })
+            .build()
+            .map_err(|e| format!("Failed to export tray icon: {}", e))?;
+
+        // dbusmenu object
+        let state_for_menu = state.clone();
+        connection
+            .register_object(MENU_PATH, &menu_iface)
+            .method_call(move |_, _, _, _, method, params, invocation| {
+                let state = state_for_menu.borrow();
+                let reply = match method {
+                    "GetLayout" => {
+                        let (parent, _, _) = params.get::<(i32, i32, Vec<String>)>().unwrap_or_default();
+                        Some(glib::Variant::tuple_from_iter([0u32.to_variant(), menu_layout(&state, parent)]))
+                    }
+                    "GetGroupProperties" => {
+                        let (ids, _) = params.get::<(Vec<i32>, Vec<String>)>().unwrap_or_default();
+                        let ids = if ids.is_empty() { MENU_ITEMS.to_vec() } else { ids };
+                        let props: Vec<(i32, HashMap<String, glib::Variant>)> = ids
+                            .into_iter()
+                            .map(|id| (id, menu_item_properties(&state, id)))
+                            .collect();
+                        Some((props,).to_variant())
+                    }
+                    "GetProperty" => {
+                        let (id, name) = params.get::<(i32, String)>().unwrap_or_default();
+                        let value = menu_item_properties(&state, id)
+                            .remove(&name)
+                            .unwrap_or_else(|| "".to_variant());
+                        Some((value,).to_variant())
+                    }
+                    "Event" => {
+                        drop(state);
+                        if let Some((id, event, _, _)) = params.get::<(i32, String, glib::Variant, u32)>() {
+                            handle_menu_event(id, &event, &on_show, &on_quit);
+                        }
+                        None
+                    }
+                    "EventGroup" => {
+                        drop(state);
+                        let (events,) = params
+                            .get::<(Vec<(i32, String, glib::Variant, u32)>,)>()
+                            .unwrap_or_default();
+                        for (id, event, _, _) in events {
+                            handle_menu_event(id, &event, &on_show, &on_quit);
+                        }
+                        Some((Vec::<i32>::new(),).to_variant())
+                    }
+                    "AboutToShow" => Some((false,).to_variant()),
+                    "AboutToShowGroup" => Some((Vec::<i32>::new(), Vec::<i32>::new()).to_variant()),
+                    _ => None,
+                };
+                invocation.return_value(reply.as_ref());
+            })
+            .property(|_, _, _, _, property| match property {
+                "Version" => 3u32.to_variant(),
+                "TextDirection" => "ltr".to_variant(),
+                "Status" => "normal".to_variant(),
+                _ => Vec::<String>::new().to_variant(),
+            })
+            .build()
+            .map_err(|e| format!("Failed to export tray menu: {}", e))?;
+
+        // (Re-)register whenever a tray host shows up, e.g. after the panel restarts
+        let available = Rc::new(Cell::new(false));
+        let available_for_watch = available.clone();
+        let on_availability: Rc<dyn Fn(bool)> = Rc::new(on_availability);
+        let on_vanished = on_availability.clone();
+        let available_for_vanish = available.clone();
+        gio::bus_watch_name_on_connection(
+            connection,
+            WATCHER_NAME,
+            gio::BusNameWatcherFlags::NONE,
+            move |conn, _, _| {
+                let service = conn.unique_name().map(|n| n.to_string()).unwrap_or_default();
+                let available = available_for_watch.clone();
+                let on_availability = on_availability.clone();
+                conn.call(
+                    Some(WATCHER_NAME),
+                    WATCHER_PATH,
+                    WATCHER_NAME,
+                    "RegisterStatusNotifierItem",
+                    Some(&(service,).to_variant()),
+                    None,
+                    gio::DBusCallFlags::NONE,
+                    -1,
+                    None::<&gio::Cancellable>,
+                    move |result| match result {
+                        Ok(_) => {
+                            log::info!("Registered tray icon with {}", WATCHER_NAME);
+                            available.set(true);
+                            on_availability(true);
+                        }
+                        Err(e) => {
+                            log::warn!("Failed to register tray icon: {}", e);
+                            available.set(false);
+                            on_availability(false);
+                        }
+                    },
+                );
+            },
+            move |_, _| {
+                log::info!("No StatusNotifierWatcher on the session bus, tray icon unavailable");
+                available_for_vanish.set(false);
+                on_vanished(false);
+            },
+        );
+
+        Ok(Self {
+            connection: connection.clone(),
+            state,
+            available,
+        })
+    }
+
+    /// Whether a tray host currently shows the icon
+    pub fn is_available(&self) -> bool {
+        self.available.get()
+    }
+
+    pub fn update(&self, snapshot: &SystemSnapshot) {
+        let cpu = snapshot.cpu.total_percent;
+        let mem_pct = if snapshot.memory.total > 0 {
+            (snapshot.memory.used as f64 / snapshot.memory.total as f64) * 100.0
+        } else { 0.0 };
+
+        let top_process = snapshot.app_groups
+            .iter()
+            .max_by(|a, b| a.total_cpu.partial_cmp(&b.total_cpu).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|g| format!("Top: {} ({})", g.display_name(), util::format_percent(g.total_cpu)))
+            .unwrap_or_else(|| "Top: —".to_string());
+
+        let label = format!("CPU {:.0}%", cpu);
+        let tooltip = format!(
+            "CPU: {}\nMemory: {}\n{}",
+            util::format_percent(cpu),
+            util::format_percent(mem_pct),
+            top_process,
+        );
+
+        let mut state = self.state.borrow_mut();
+        if state.label != label {
+            state.label = label.clone();
+            self.emit(ITEM_PATH, ITEM_IFACE, "XAyatanaNewLabel", Some((label, "CPU 100%").to_variant()));
+        }
+        if state.tooltip != tooltip {
+            state.tooltip = tooltip;
+            self.emit(ITEM_PATH, ITEM_IFACE, "NewToolTip", None);
+        }
+        if state.top_process != top_process {
+            state.top_process = top_process;
+            let updated = vec![(MENU_TOP_PROCESS, menu_item_properties(&state, MENU_TOP_PROCESS))];
+            let removed: Vec<(i32, Vec<String>)> = Vec::new();
+            self.emit(MENU_PATH, MENU_IFACE, "ItemsPropertiesUpdated", Some((updated, removed).to_variant()));
+        }
+    }
+
+    fn emit(&self, path: &str, iface: &str, signal: &str, params: Option<glib::Variant>) {
+        if let Err(e) = self.connection.emit_signal(None, path, iface, signal, params.as_ref()) {
+            log::debug!("Failed to emit {}.{}: {}", iface, signal, e);
+        }
+    }
+}
+
+fn item_property(state: &TrayState, property: &str) -> glib::Variant {
+    match property {
+        "Category" => "SystemServices".to_variant(),
+        "Id" => "task-manager-linux".to_variant(),
+        "Title" => "Task Manager".to_variant(),
+        "Status" => "Active".to_variant(),
+        "WindowId" => 0i32.to_variant(),
+        "IconName" => ICON_NAME.to_variant(),
+        "ToolTip" => (
+            ICON_NAME,
+            Vec::<(i32, i32, Vec<u8>)>::new(),
+            "Task Manager",
+            state.tooltip.as_str(),
+        ).to_variant(),
+        "ItemIsMenu" => false.to_variant(),
+        "Menu" => ObjectPath::try_from(MENU_PATH).unwrap().to_variant(),
+        "XAyatanaLabel" => state.label.to_variant(),
+        "XAyatanaLabelGuide" => "CPU 100%".to_variant(),
+        _ => "".to_variant(),
+    }
+}
+
+fn menu_item_properties(state: &TrayState, id: i32) -> HashMap<String, glib::Variant> {
+    let mut props = HashMap::new();
+    match id {
+        MENU_ROOT => {
+            props.insert("children-display".to_string(), "submenu".to_variant());
+        }
+        MENU_TOP_PROCESS => {
+            props.insert("label".to_string(), state.top_process.to_variant());
+            props.insert("enabled".to_string(), false.to_variant());
+        }
+        MENU_SEPARATOR => {
+            props.insert("type".to_string(), "separator".to_variant());
+        }
+        MENU_SHOW => {
+            props.insert("label".to_string(), "Show".to_variant());
+        }
+        MENU_QUIT => {
+            props.insert("label".to_string(), "Quit".to_variant());
+        }
+        _ => {}
+    }
+    props
+}
+
+/// Layout of `parent` as a dbusmenu (ia{sv}av) tuple; the menu is one level deep
+fn menu_layout(state: &TrayState, parent: i32) -> glib::Variant {
+    let children: Vec<glib::Variant> = if parent == MENU_ROOT {
+        MENU_ITEMS
+            .iter()
+            .map(|&id| (id, menu_item_properties(state, id), Vec::<glib::Variant>::new()).to_variant())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    (parent, menu_item_properties(state, parent), children).to_variant()
+}
+
+fn handle_menu_event(id: i32, event: &str, on_show: &Rc<dyn Fn()>, on_quit: &Rc<dyn Fn()>) {
+    if event != "clicked" {
+        return;
+    }
+    match id {
+        MENU_SHOW => on_show(),
+        MENU_QUIT => on_quit(),
+        _ => {}
+    }
+}
@@ -0,0 +1,183 @@
+use gtk4 as gtk;
+use gtk::prelude::*;
+use gtk::{gio, glib};
+
+use crate::backend::unit_file::{EditMode, UnitFile};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Edit a systemd unit in place of `systemctl edit [--full]`: the whole unit,
+/// or a drop-in override for units whose file can't be changed.
+pub fn show_unit_editor(widget: &impl IsA<gtk::Widget>, unit: &str, user: bool) {
+    let window = widget.root().and_downcast::<gtk::Window>();
+    let file = match UnitFile::find(unit, user) {
+        Ok(file) => Rc::new(file),
+        Err(e) => {
+            show_error(window.as_ref(), &e);
+            return;
+        }
+    };
+
+    let dialog = gtk::Window::builder()
+        .title(&format!("Edit {}", file.unit))
+        .default_width(760)
+        .default_height(560)
+        .modal(true)
+        .build();
+    dialog.set_transient_for(window.as_ref());
+
+    let vbox = gtk::Box::new(gtk::Orientation::Vertical, 6);
+    vbox.set_margin_top(12);
+    vbox.set_margin_start(12);
+    vbox.set_margin_end(12);
+    vbox.set_margin_bottom(12);
+
+    let mode_box = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    mode_box.add_css_class("linked");
+    mode_box.set_halign(gtk::Align::Start);
+    let full_btn = gtk::ToggleButton::with_label("Full Unit");
+    full_btn.set_tooltip_text(Some("Edit the whole unit file (systemctl edit --full)"));
+    let override_btn = gtk::ToggleButton::with_label("Override");
+    override_btn.set_tooltip_text(Some("Add settings in a drop-in file (systemctl edit)"));
+    override_btn.set_group(Some(&full_btn));
+    mode_box.append(&full_btn);
+    mode_box.append(&override_btn);
+    vbox.append(&mode_box);
+
+    let path_label = gtk::Label::new(None);
+    path_label.set_halign(gtk::Align::Start);
+    path_label.set_wrap(true);
+    path_label.set_selectable(true);
+    path_label.add_css_class("dim-label");
+    vbox.append(&path_label);
+
+    let text_view = gtk::TextView::new();
+    text_view.set_monospace(true);
+    text_view.set_left_margin(6);
+    text_view.set_top_margin(6);
+    let scroll = gtk::ScrolledWindow::builder()
+        .child(&text_view)
+        .vexpand(true)
+        .build();
+    vbox.append(&scroll);
+
+    let button_box = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+    button_box.set_halign(gtk::Align::End);
+    let cancel_btn = gtk::Button::with_label("Cancel");
+    let save_btn = gtk::Button::with_label(if user { "Save" } else { "Save…" });
+    save_btn.add_css_class("suggested-action");
+    if !user {
+        save_btn.set_tooltip_text(Some("Asks for your password"));
+    }
+    button_box.append(&cancel_btn);
+    button_box.append(&save_btn);
+    vbox.append(&button_box);
+
+    let mode = Rc::new(Cell::new(EditMode::Full));
+    // A buffer per mode, loaded the first time the mode is shown, so that
+    // switching modes keeps unsaved edits in the other one
+    let buffers = Rc::new([
+        (EditMode::Full, gtk::TextBuffer::new(None), Cell::new(false)),
+        (EditMode::Override, gtk::TextBuffer::new(None), Cell::new(false)),
+    ]);
+    let load: Rc<dyn Fn(EditMode)> = {
+        let file = file.clone();
+        let mode = mode.clone();
+        let buffers = buffers.clone();
+        let text_view = text_view.clone();
+        let path_label = path_label.clone();
+        let save_btn = save_btn.clone();
+        let dialog = dialog.clone();
+        Rc::new(move |new_mode| {
+            mode.set(new_mode);
+            let target = file.target_path(new_mode);
+            let note = match new_mode {
+                EditMode::Full if file.is_local() => format!("Saved to {}", target.display()),
+                EditMode::Full => format!(
+                    "Loaded from {}. Saving writes a copy to {}, which takes precedence.",
+                    file.fragment_path.display(),
+                    target.display()
+                ),
+                EditMode::Override => format!("Saved to {}", target.display()),
+            };
+            path_label.set_text(&note);
+            let Some((_, buffer, loaded)) = buffers.iter().find(|(buffer_mode, _, _)| *buffer_mode == new_mode) else {
+                return;
+            };
+            text_view.set_buffer(Some(buffer));
+            if !loaded.get() {
+                match file.load(new_mode) {
+                    Ok(contents) => {
+                        buffer.set_text(&contents);
+                        loaded.set(true);
+                    }
+                    Err(e) => show_error(Some(&dialog), &e),
+                }
+            }
+            save_btn.set_sensitive(loaded.get());
+        })
+    };
+
+    // Units outside the config directories (vendor files in /usr) start as an override
+    let initial = if file.is_local() { EditMode::Full } else { EditMode::Override };
+    if initial == EditMode::Full {
+        full_btn.set_active(true);
+    } else {
+        override_btn.set_active(true);
+    }
+    load(initial);
+    for (btn, btn_mode) in [(&full_btn, EditMode::Full), (&override_btn, EditMode::Override)] {
+        let load = load.clone();
+        btn.connect_toggled(move |btn| {
+            if btn.is_active() {
+                load(btn_mode);
+            }
+        });
+    }
+
+    let dialog_for_cancel = dialog.clone();
+    cancel_btn.connect_clicked(move |_| dialog_for_cancel.close());
+
+    let dialog_for_save = dialog.clone();
+    save_btn.connect_clicked(move |btn| {
+        let buffer = text_view.buffer();
+        let contents = buffer.text(&buffer.start_iter(), &buffer.end_iter(), false).to_string();
+        let file = UnitFile::clone(&file);
+        let mode = mode.get();
+        let dialog = dialog_for_save.clone();
+        let btn = btn.clone();
+        btn.set_sensitive(false);
+        glib::spawn_future_local(async move {
+            let result = gio::spawn_blocking(move || file.save(mode, &contents))
+                .await
+                .unwrap_or_else(|_| Err("Failed to save unit file".to_string()));
+            btn.set_sensitive(true);
+            match result {
+                Ok(path) => {
+                    log::info!("Saved {} and reloaded systemd", path.display());
+                    dialog.close();
+                }
+                // Keep the dialog open so the edit isn't lost
+                Err(e) => {
+                    log::error!("{}", e);
+                    show_error(Some(&dialog), &e);
+                }
+            }
+        });
+    });
+
+    dialog.set_child(Some(&vbox));
+    dialog.present();
+}
+
+fn show_error(parent: Option<&gtk::Window>, message: &str) {
+    let error = gtk::MessageDialog::new(
+        parent,
+        gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+        gtk::MessageType::Error,
+        gtk::ButtonsType::Ok,
+        message,
+    );
+    error.connect_response(|d, _| d.close());
+    error.present();
+}
@@ -5,8 +5,12 @@ use gtk::gio;
 use gtk::subclass::prelude::ObjectSubclassIsExt;
 
 use crate::backend::users::{self, UserInfo};
+use crate::config::Config;
 use crate::model::SystemSnapshot;
+use crate::ui::column_sort;
 use crate::util;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 // GObject wrapper for user data in the model
 mod imp {
@@ -80,13 +84,45 @@ pub struct UsersTab {
 }
 
 impl UsersTab {
-    pub fn new() -> Self {
+    pub fn new(config: Rc<RefCell<Config>>) -> Self {
         let widget = gtk::Box::new(gtk::Orientation::Vertical, 0);
         widget.add_css_class("users-view");
 
+        let toolbar = gtk::Box::new(gtk::Orientation::Horizontal, 6);
+        toolbar.add_css_class("toolbar");
+        toolbar.set_margin_start(6);
+        toolbar.set_margin_end(6);
+        toolbar.set_margin_top(6);
+        toolbar.set_margin_bottom(6);
+
+        let search_entry = gtk::SearchEntry::new();
+        search_entry.set_placeholder_text(Some("Search users..."));
+        search_entry.set_hexpand(true);
+        search_entry.add_css_class("search-bar");
+        toolbar.append(&search_entry);
+        widget.append(&toolbar);
+
         // List store for user objects
         let store = gio::ListStore::new::<UserObject>();
 
+        // Filter model (username contains the search text)
+        let search_entry_weak = search_entry.downgrade();
+        let filter = gtk::CustomFilter::new(move |obj| {
+            let Some(search_entry) = search_entry_weak.upgrade() else {
+                return true;
+            };
+            let text = search_entry.text().to_string().to_lowercase();
+            if text.is_empty() {
+                return true;
+            }
+            let user = obj.downcast_ref::<UserObject>().unwrap();
+            user.username().to_lowercase().contains(&text)
+        });
+        let filter_model = gtk::FilterListModel::new(Some(store.clone()), Some(filter.clone()));
+        search_entry.connect_search_changed(move |_| {
+            filter.changed(gtk::FilterChange::Different);
+        });
+
         // Sort model (default: sort by CPU descending)
         let sorter = gtk::CustomSorter::new(move |a, b| {
             let ua = a.downcast_ref::<UserObject>().unwrap();
@@ -96,7 +132,7 @@ impl UsersTab {
                 .unwrap_or(std::cmp::Ordering::Equal)
                 .into()
         });
-        let sort_model = gtk::SortListModel::new(Some(store.clone()), Some(sorter.clone()));
+        let sort_model = gtk::SortListModel::new(Some(filter_model), Some(sorter.clone()));
 
         // Selection model
         let selection = gtk::SingleSelection::new(Some(sort_model.clone()));
@@ -246,6 +282,7 @@ impl UsersTab {
         if let Some(s) = cv_sorter {
             sort_model.set_sorter(Some(&s));
         }
+        column_sort::persist(&column_view, &config, "users");
 
         // Scroll window
         let scroll = gtk::ScrolledWindow::builder()
@@ -255,8 +292,9 @@ impl UsersTab {
             .build();
         widget.append(&scroll);
 
-        // Context menu - Log Off User
+        // Context menu
         let menu = gio::Menu::new();
+        menu.append(Some("Show Processes"), Some("user.processes"));
         menu.append(Some("Log Off User"), Some("user.logoff"));
 
         let popover = gtk::PopoverMenu::from_model(Some(&menu));
@@ -280,6 +318,25 @@ impl UsersTab {
         });
         action_group.add_action(&logoff_action);
 
+        // Jump to the Processes tab filtered to the user (handled by the window)
+        let sel_clone = selection.clone();
+        let cv_ref = column_view.clone();
+        let processes_action = gio::SimpleAction::new("processes", None);
+        processes_action.connect_activate(move |_, _| {
+            if let Some(obj) = sel_clone
+                .selected_item()
+                .and_then(|i| i.downcast::<UserObject>().ok())
+            {
+                let _ = cv_ref.activate_action("win.show-user-processes", Some(&obj.username().to_variant()));
+            }
+        });
+        action_group.add_action(&processes_action);
+
+        // Double-click does the same
+        column_view.connect_activate(|cv, _| {
+            let _ = cv.activate_action("user.processes", None);
+        });
+
         column_view.insert_action_group("user", Some(&action_group));
 
         // Right-click gesture
@@ -294,6 +351,18 @@ impl UsersTab {
         });
         column_view.add_controller(gesture);
 
+        // Keyboard shortcut: Ctrl+F to focus search
+        let key_controller = gtk::EventControllerKey::new();
+        let search_entry_clone = search_entry.clone();
+        key_controller.connect_key_pressed(move |_, key, _, modifier| {
+            if key == gtk::gdk::Key::f && modifier == gtk::gdk::ModifierType::CONTROL_MASK {
+                search_entry_clone.grab_focus();
+                return glib::Propagation::Stop;
+            }
+            glib::Propagation::Proceed
+        });
+        widget.add_controller(key_controller);
+
         Self { widget, store }
     }
 
@@ -1,3 +1,5 @@
+use crate::config::TemperatureUnit;
+
 pub fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = 1024 * KB;
@@ -55,6 +57,15 @@ pub fn format_duration(secs: u64) -> String {
     }
 }
 
+/// Like `format_duration`, but counts seconds for the first minute.
+pub fn format_elapsed(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else {
+        format_duration(secs)
+    }
+}
+
 pub fn format_frequency(mhz: f64) -> String {
     if mhz >= 1000.0 {
         format!("{:.2} GHz", mhz / 1000.0)
@@ -63,9 +74,38 @@ pub fn format_frequency(mhz: f64) -> String {
     }
 }
 
-pub fn format_temperature(celsius: f64) -> String {
+/// Readable name for a /proc/<pid>/stat state letter (unknown letters pass through).
+pub fn process_state_label(state: &str) -> &str {
+    match state {
+        "R" => "Running",
+        "S" => "Sleeping",
+        "D" => "Disk Sleep",
+        "Z" => "Zombie",
+        "T" => "Stopped",
+        "t" => "Traced",
+        "I" => "Idle",
+        "X" | "x" => "Dead",
+        "K" => "Wakekill",
+        "W" => "Waking",
+        "P" => "Parked",
+        other => other,
+    }
+}
+
+/// Convert a Celsius reading to `unit`, with the unit's suffix.
+pub fn convert_temperature(celsius: f64, unit: TemperatureUnit) -> (f64, &'static str) {
+    match unit {
+        TemperatureUnit::Celsius => (celsius, "°C"),
+        TemperatureUnit::Fahrenheit => (celsius * 9.0 / 5.0 + 32.0, "°F"),
+        TemperatureUnit::Kelvin => (celsius + 273.15, " K"),
+    }
+}
+
+/// Format a Celsius reading in `unit`; 0 means no sensor.
+pub fn format_temperature(celsius: f64, unit: TemperatureUnit) -> String {
     if celsius > 0.0 {
-        format!("{:.0}°C", celsius)
+        let (value, suffix) = convert_temperature(celsius, unit);
+        format!("{:.0}{}", value, suffix)
     } else {
         "N/A".to_string()
     }
@@ -7,16 +7,49 @@ use adw::prelude::*;
 use crate::backend::Collector;
 use crate::backend::de_restart;
 use crate::backend::shortcut_setup;
-use crate::config::Config;
+use crate::config::{BackgroundRefresh, Config};
 use crate::model::SystemSnapshot;
+use crate::ui::alerts::{ProcessAlerts, ServiceAlerts};
+use crate::ui::bulk_signal;
+use crate::ui::run_task;
+use crate::ui::dbus_service::DbusService;
+use crate::ui::graph_widget::GraphSettings;
 use crate::ui::performance_tab::PerformanceTab;
+use crate::ui::preferences;
 use crate::ui::process_tab::ProcessTab;
 use crate::ui::startup_tab::StartupTab;
 use crate::ui::services_tab::ServicesTab;
+use crate::ui::tray::TrayIcon;
 use crate::ui::users_tab::UsersTab;
+use crate::ui::connections_tab::ConnectionsTab;
 use crate::util;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Collector interval floor while the app is in the background with `BackgroundRefresh::Slow`.
+const BACKGROUND_SLOW_INTERVAL_MS: u64 = 5000;
+
+/// How often failed service monitoring polls systemd.
+const SERVICE_CHECK_SECS: u32 = 10;
+
+/// Sidebar pages in row order: (stack child name, title).
+pub const TABS: &[(&str, &str)] = &[
+    ("processes", "Processes"),
+    ("performance", "Performance"),
+    ("startup", "Startup"),
+    ("services", "Services"),
+    ("users", "Users"),
+    ("connections", "Connections"),
+];
+
+/// Update speeds offered in the menu and preferences: (label, milliseconds).
+pub const REFRESH_INTERVALS: &[(&str, u64)] = &[
+    ("0.5 seconds", 500),
+    ("1 second", 1000),
+    ("2 seconds", 2000),
+    ("5 seconds", 5000),
+];
 
 pub struct MainWindow {
     pub window: adw::ApplicationWindow,
@@ -24,19 +57,34 @@ pub struct MainWindow {
 
 impl MainWindow {
     pub fn new(app: &adw::Application) -> adw::ApplicationWindow {
-        let config = Config::load();
+        let config = Rc::new(RefCell::new(Config::load()));
 
         let window = adw::ApplicationWindow::builder()
             .application(app)
             .title("Task Manager")
-            .default_width(config.window_width)
-            .default_height(config.window_height)
+            .default_width(config.borrow().window_width)
+            .default_height(config.borrow().window_height)
             .build();
 
         // Start backend collector
-        let (collector, rx) = Collector::new();
+        let refresh_interval_ms = config.borrow().refresh_interval_ms;
+        let (collector, rx) = Collector::new(refresh_interval_ms);
+        let collector_interval = collector.interval_handle();
+        let collector_paused = collector.pause_handle();
+        let priority_rules = collector.priority_rules_handle();
+        *priority_rules.lock().unwrap() = config.borrow().priority_rules.clone();
+        let measure_pss = collector.pss_handle();
+        measure_pss.store(config.borrow().measure_pss, Ordering::Relaxed);
         collector.start();
 
+        // Slow down or pause collection while none of the app's windows has
+        // focus. Not focused until presented, which covers starting minimized
+        // to the tray; the UI timer below tracks changes.
+        let app_focused = Rc::new(Cell::new(false));
+        let graph_settings = GraphSettings::from_config(&config.borrow());
+        apply_refresh_mode(false, &config.borrow(), &collector_interval, &collector_paused, &graph_settings);
+        let latest_snapshot: Rc<RefCell<Option<SystemSnapshot>>> = Rc::new(RefCell::new(None));
+
         // Main layout: sidebar + content
         let sidebar_list = gtk::ListBox::new();
         sidebar_list.set_selection_mode(gtk::SelectionMode::Single);
@@ -59,16 +107,27 @@ impl MainWindow {
             .title("Services")
             .build();
         services_row.add_prefix(&gtk::Image::from_icon_name("system-run-symbolic"));
+        // Failed unit count while service monitoring is on
+        let services_badge = gtk::Label::new(None);
+        services_badge.add_css_class("error");
+        services_badge.set_tooltip_text(Some("Failed services"));
+        services_badge.set_visible(false);
+        services_row.add_suffix(&services_badge);
         let users_row = adw::ActionRow::builder()
             .title("Users")
             .build();
         users_row.add_prefix(&gtk::Image::from_icon_name("system-users-symbolic"));
+        let connections_row = adw::ActionRow::builder()
+            .title("Connections")
+            .build();
+        connections_row.add_prefix(&gtk::Image::from_icon_name("network-workgroup-symbolic"));
 
         sidebar_list.append(&processes_row);
         sidebar_list.append(&performance_row);
         sidebar_list.append(&startup_row);
         sidebar_list.append(&services_row);
         sidebar_list.append(&users_row);
+        sidebar_list.append(&connections_row);
 
         let sidebar_scroll = gtk::ScrolledWindow::builder()
             .hscrollbar_policy(gtk::PolicyType::Never)
@@ -80,46 +139,45 @@ impl MainWindow {
         stack.set_transition_type(gtk::StackTransitionType::Crossfade);
 
         // Process tab
-        let process_tab = ProcessTab::new();
+        let process_tab = ProcessTab::new(config.clone(), priority_rules, measure_pss, latest_snapshot.clone());
         stack.add_named(&process_tab.widget, Some("processes"));
 
         // Performance tab
-        let performance_tab = PerformanceTab::new();
+        let performance_tab = PerformanceTab::new(config.clone(), &graph_settings);
         stack.add_named(&performance_tab.widget, Some("performance"));
 
         // Startup tab
-        let mut startup_tab = StartupTab::new();
+        let mut startup_tab = StartupTab::new(config.clone());
         startup_tab.load();
         stack.add_named(&startup_tab.widget, Some("startup"));
 
         // Services tab
-        let mut services_tab = ServicesTab::new();
+        let mut services_tab = ServicesTab::new(config.clone());
         services_tab.load();
         stack.add_named(&services_tab.widget, Some("services"));
 
         // Users tab
-        let users_tab = UsersTab::new();
+        let users_tab = UsersTab::new(config.clone());
         stack.add_named(&users_tab.widget, Some("users"));
 
+        // Connections tab
+        let mut connections_tab = ConnectionsTab::new(config.clone());
+        stack.add_named(&connections_tab.widget, Some("connections"));
+
         // Sidebar selection handler
         let stack_ref = stack.clone();
         sidebar_list.connect_row_selected(move |_, row| {
-            if let Some(row) = row {
-                let idx = row.index();
-                match idx {
-                    0 => stack_ref.set_visible_child_name("processes"),
-                    1 => stack_ref.set_visible_child_name("performance"),
-                    2 => stack_ref.set_visible_child_name("startup"),
-                    3 => stack_ref.set_visible_child_name("services"),
-                    4 => stack_ref.set_visible_child_name("users"),
-                    _ => {}
-                }
+            if let Some((name, _)) = row.and_then(|r| TABS.get(r.index() as usize)) {
+                stack_ref.set_visible_child_name(name);
             }
         });
 
-        // Select first row
-        if let Some(first_row) = sidebar_list.row_at_index(0) {
-            sidebar_list.select_row(Some(&first_row));
+        // Select the configured default tab
+        let default_index = TABS.iter()
+            .position(|(name, _)| *name == config.borrow().default_tab)
+            .unwrap_or(0);
+        if let Some(row) = sidebar_list.row_at_index(default_index as i32) {
+            sidebar_list.select_row(Some(&row));
         }
 
         // Status bar
@@ -127,10 +185,12 @@ impl MainWindow {
         status_bar.add_css_class("status-bar");
         let status_processes = gtk::Label::new(Some("Processes: 0"));
         let status_cpu = gtk::Label::new(Some("CPU: 0%"));
+        let status_load = gtk::Label::new(Some("Load: 0.00 0.00 0.00"));
         let status_memory = gtk::Label::new(Some("Memory: 0%"));
         let status_gpu = gtk::Label::new(Some(""));
         status_bar.append(&status_processes);
         status_bar.append(&status_cpu);
+        status_bar.append(&status_load);
         status_bar.append(&status_memory);
         status_bar.append(&status_gpu);
 
@@ -156,6 +216,10 @@ impl MainWindow {
             for (i, cmd) in de.commands.iter().enumerate() {
                 menu.append(Some(&cmd.label), Some(&format!("win.de-restart-{}", i)));
             }
+            // An item without an action is shown greyed out
+            if let Some(note) = &de.note {
+                menu.append(Some(note), None);
+            }
 
             let menu_button = gtk::MenuButton::builder()
                 .icon_name("system-reboot-symbolic")
@@ -191,8 +255,23 @@ impl MainWindow {
             } else {
                 "Install Ctrl+Shift+Esc Shortcut"
             };
+            primary_menu.append(Some("Preferences"), Some("win.preferences"));
+            primary_menu.append(Some("Run New Task…"), Some("win.run-task"));
+            primary_menu.append(Some("Export…"), Some("win.export"));
+            primary_menu.append(Some("Signal Processes by Name…"), Some("win.signal-by-name"));
             primary_menu.append(Some(shortcut_label), Some("win.setup-shortcut"));
 
+            let speed_menu = gtk::gio::Menu::new();
+            for (label, ms) in REFRESH_INTERVALS {
+                let item = gtk::gio::MenuItem::new(Some(label), None);
+                item.set_action_and_target_value(Some("win.refresh-interval"), Some(&ms.to_variant()));
+                speed_menu.append_item(&item);
+            }
+            primary_menu.append_submenu(Some("Update Speed"), &speed_menu);
+            if config.borrow().show_tray_icon {
+                primary_menu.append(Some("Start Minimized to Tray"), Some("win.start-minimized"));
+            }
+
             let hamburger = gtk::MenuButton::builder()
                 .icon_name("open-menu-symbolic")
                 .menu_model(&primary_menu)
@@ -206,6 +285,84 @@ impl MainWindow {
                 setup_shortcut_with_feedback(&window_ref);
             });
             window.add_action(&shortcut_action);
+
+            let export_action = gtk::gio::SimpleAction::new("export", None);
+            let window_ref = window.clone();
+            let snapshot_ref = latest_snapshot.clone();
+            export_action.connect_activate(move |_, _| {
+                match snapshot_ref.borrow().as_ref() {
+                    Some(snapshot) => show_export_dialog(&window_ref, snapshot.clone()),
+                    None => show_message(&window_ref, gtk::MessageType::Error, "No data collected yet. Try again in a moment."),
+                }
+            });
+            window.add_action(&export_action);
+
+            let run_task_action = gtk::gio::SimpleAction::new("run-task", None);
+            let window_ref = window.clone();
+            run_task_action.connect_activate(move |_, _| {
+                run_task::show_run_dialog(&window_ref);
+            });
+            window.add_action(&run_task_action);
+
+            let signal_by_name_action = gtk::gio::SimpleAction::new("signal-by-name", None);
+            let window_ref = window.clone();
+            let snapshot_ref = latest_snapshot.clone();
+            signal_by_name_action.connect_activate(move |_, _| {
+                match snapshot_ref.borrow().as_ref() {
+                    Some(snapshot) => bulk_signal::show_bulk_signal_dialog(&window_ref, snapshot.processes.clone()),
+                    None => show_message(&window_ref, gtk::MessageType::Error, "No data collected yet. Try again in a moment."),
+                }
+            });
+            window.add_action(&signal_by_name_action);
+
+            let interval_action = gtk::gio::SimpleAction::new_stateful(
+                "refresh-interval",
+                Some(glib::VariantTy::UINT64),
+                &refresh_interval_ms.to_variant(),
+            );
+            let config_ref = config.clone();
+            let focused = app_focused.clone();
+            let interval = collector_interval.clone();
+            let paused = collector_paused.clone();
+            let graph_settings_ref = graph_settings.clone();
+            interval_action.connect_activate(move |action, param| {
+                let Some(ms) = param.and_then(|p| p.get::<u64>()) else {
+                    return;
+                };
+                action.set_state(&ms.to_variant());
+                let mut cfg = config_ref.borrow_mut();
+                cfg.refresh_interval_ms = ms;
+                cfg.save();
+                apply_refresh_mode(focused.get(), &cfg, &interval, &paused, &graph_settings_ref);
+            });
+            window.add_action(&interval_action);
+
+            // Preferences saved a new background behavior
+            let refresh_mode_action = gtk::gio::SimpleAction::new("apply-refresh-mode", None);
+            let config_ref = config.clone();
+            let focused = app_focused.clone();
+            let interval = collector_interval.clone();
+            let paused = collector_paused.clone();
+            let graph_settings_ref = graph_settings.clone();
+            refresh_mode_action.connect_activate(move |_, _| {
+                apply_refresh_mode(focused.get(), &config_ref.borrow(), &interval, &paused, &graph_settings_ref);
+            });
+            window.add_action(&refresh_mode_action);
+
+            let start_minimized_action = gtk::gio::SimpleAction::new_stateful(
+                "start-minimized",
+                None,
+                &config.borrow().start_minimized.to_variant(),
+            );
+            let config_ref = config.clone();
+            start_minimized_action.connect_activate(move |action, _| {
+                let enabled = !action.state().and_then(|s| s.get::<bool>()).unwrap_or(false);
+                action.set_state(&enabled.to_variant());
+                let mut cfg = config_ref.borrow_mut();
+                cfg.start_minimized = enabled;
+                cfg.save();
+            });
+            window.add_action(&start_minimized_action);
         }
 
         let main_box = gtk::Box::new(gtk::Orientation::Vertical, 0);
@@ -218,11 +375,165 @@ impl MainWindow {
         stack.set_vexpand(true);
         stack.set_hexpand(true);
 
+        // Tray icon (StatusNotifierItem over the application's session bus connection)
+        let start_hidden = config.borrow().start_minimized;
+        let tray = if config.borrow().show_tray_icon {
+            app.dbus_connection().and_then(|connection| {
+                let window_for_show = window.downgrade();
+                let window_for_tray = window.downgrade();
+                let app_for_quit = app.downgrade();
+                let result = TrayIcon::new(
+                    &connection,
+                    move || {
+                        if let Some(win) = window_for_show.upgrade() {
+                            win.present();
+                        }
+                    },
+                    move || {
+                        if let Some(app) = app_for_quit.upgrade() {
+                            app.quit();
+                        }
+                    },
+                    move |available| {
+                        // Nowhere to restore a hidden window from, so bring it back
+                        if let Some(win) = window_for_tray.upgrade() {
+                            if !available && !win.is_visible() {
+                                win.present();
+                            }
+                        }
+                    },
+                );
+                result.map_err(|e| log::warn!("{}", e)).ok()
+            })
+        } else {
+            None
+        };
+        let tray = Rc::new(tray);
+
+        // Runaway process notifications (thresholds live in the config)
+        let mut alerts = app.dbus_connection().map(|connection| ProcessAlerts::new(&connection, config.clone()));
+
+        // Failed service notifications: poll systemd while enabled in Preferences
+        if let Some(connection) = app.dbus_connection() {
+            let mut service_alerts = ServiceAlerts::new(&connection);
+            let config_ref = config.clone();
+            let window_weak = window.downgrade();
+            glib::spawn_future_local(async move {
+                while window_weak.upgrade().is_some() {
+                    if config_ref.borrow().service_alerts_enabled {
+                        // Keep the previous states through a failed check, so
+                        // units that were already failed aren't announced again
+                        let result = gtk::gio::spawn_blocking(crate::backend::services::ServicesCollector::try_collect)
+                            .await
+                            .unwrap_or_else(|_| Err("Service check panicked".to_string()));
+                        match result {
+                            Ok(entries) => {
+                                let failed = service_alerts.check(&entries);
+                                services_badge.set_text(&failed.to_string());
+                                services_badge.set_visible(failed > 0);
+                            }
+                            Err(e) => log::warn!("Skipping service alert check: {}", e),
+                        }
+                    } else {
+                        service_alerts.reset();
+                        services_badge.set_visible(false);
+                    }
+                    glib::timeout_future_seconds(SERVICE_CHECK_SECS).await;
+                }
+            });
+        }
+
+        // org.task_manager.Linux: Show/Quit and top-process queries from other tools
+        let dbus_service = app.dbus_connection().and_then(|connection| {
+            let window_for_show = window.downgrade();
+            let app_for_quit = app.downgrade();
+            DbusService::new(
+                &connection,
+                move || {
+                    if let Some(win) = window_for_show.upgrade() {
+                        win.present();
+                    }
+                },
+                move || {
+                    if let Some(app) = app_for_quit.upgrade() {
+                        app.quit();
+                    }
+                },
+            )
+            .map_err(|e| log::warn!("{}", e))
+            .ok()
+        });
+
         // Poll for updates from the collector
         let process_tab = Rc::new(RefCell::new(process_tab));
+
+        let preferences_action = gtk::gio::SimpleAction::new("preferences", None);
+        let window_ref = window.clone();
+        let config_ref = config.clone();
+        let process_tab_ref = process_tab.clone();
+        let graph_settings_ref = graph_settings.clone();
+        preferences_action.connect_activate(move |_, _| {
+            preferences::show_preferences(&window_ref, config_ref.clone(), process_tab_ref.clone(), graph_settings_ref.clone());
+        });
+        window.add_action(&preferences_action);
+
+        // "Show Processes" on the Disk panel: switch to the process list,
+        // filtered and sorted by disk activity
+        let disk_activity_action = gtk::gio::SimpleAction::new("show-disk-activity", None);
+        let process_tab_ref = process_tab.clone();
+        let sidebar_ref = sidebar_list.clone();
+        disk_activity_action.connect_activate(move |_, _| {
+            let processes_index = TABS.iter().position(|(name, _)| *name == "processes").unwrap_or(0);
+            if let Some(row) = sidebar_ref.row_at_index(processes_index as i32) {
+                sidebar_ref.select_row(Some(&row));
+            }
+            process_tab_ref.borrow().show_disk_activity();
+        });
+        window.add_action(&disk_activity_action);
+
+        // "Show Processes" on a user in the Users tab
+        let user_processes_action =
+            gtk::gio::SimpleAction::new("show-user-processes", Some(glib::VariantTy::STRING));
+        let process_tab_ref = process_tab.clone();
+        let sidebar_ref = sidebar_list.clone();
+        user_processes_action.connect_activate(move |_, param| {
+            let Some(username) = param.and_then(|p| p.get::<String>()) else {
+                return;
+            };
+            let processes_index = TABS.iter().position(|(name, _)| *name == "processes").unwrap_or(0);
+            if let Some(row) = sidebar_ref.row_at_index(processes_index as i32) {
+                sidebar_ref.select_row(Some(&row));
+            }
+            process_tab_ref.borrow().show_user_processes(&username);
+        });
+        window.add_action(&user_processes_action);
         let performance_tab = Rc::new(RefCell::new(performance_tab));
+
+        // Status bar segments jump to the tab (and Performance panel) they summarize
+        let status_targets = [
+            (&status_processes, "processes", None),
+            (&status_cpu, "performance", Some("cpu")),
+            (&status_load, "performance", Some("cpu")),
+            (&status_memory, "performance", Some("memory")),
+            (&status_gpu, "performance", Some("gpu")),
+        ];
+        for (label, tab, panel) in status_targets {
+            label.set_cursor_from_name(Some("pointer"));
+            let click = gtk::GestureClick::new();
+            let sidebar_ref = sidebar_list.clone();
+            let performance_tab_ref = performance_tab.clone();
+            click.connect_released(move |_, _, _, _| {
+                let index = TABS.iter().position(|(name, _)| *name == tab).unwrap_or(0);
+                if let Some(row) = sidebar_ref.row_at_index(index as i32) {
+                    sidebar_ref.select_row(Some(&row));
+                }
+                if let Some(panel) = panel {
+                    performance_tab_ref.borrow().show_panel(panel);
+                }
+            });
+            label.add_controller(click);
+        }
         let users_tab = Rc::new(RefCell::new(users_tab));
-        let latest_snapshot: Rc<RefCell<Option<SystemSnapshot>>> = Rc::new(RefCell::new(None));
 
         let process_tab_clone = process_tab.clone();
         let performance_tab_clone = performance_tab.clone();
@@ -230,51 +541,212 @@ impl MainWindow {
         let snapshot_clone = latest_snapshot.clone();
         let status_processes_clone = status_processes.clone();
         let status_cpu_clone = status_cpu.clone();
+        let status_load_clone = status_load.clone();
         let status_memory_clone = status_memory.clone();
         let status_gpu_clone = status_gpu.clone();
+        let tray_clone = tray.clone();
+        let config_for_focus = config.clone();
+        let graph_settings_for_focus = graph_settings.clone();
 
         glib::timeout_add_local(std::time::Duration::from_millis(100), move || {
+            let has_focus = app_has_focus();
+            if has_focus != app_focused.get() {
+                app_focused.set(has_focus);
+                apply_refresh_mode(has_focus, &config_for_focus.borrow(), &collector_interval, &collector_paused, &graph_settings_for_focus);
+            }
+
             // Drain channel, keep latest
+            let mut fresh = false;
             while let Ok(snapshot) = rx.try_recv() {
                 *snapshot_clone.borrow_mut() = Some(snapshot);
+                fresh = true;
+            }
+
+            // Graphs take one sample per update, so only new snapshots update
+            // the tabs; a pending view switch is applied to the process list
+            if !fresh {
+                if process_tab_clone.borrow().needs_update() {
+                    if let Some(snapshot) = snapshot_clone.borrow().as_ref() {
+                        process_tab_clone.borrow_mut().update(snapshot);
+                    }
+                }
+                return glib::ControlFlow::Continue;
             }
 
             if let Some(snapshot) = snapshot_clone.borrow().as_ref() {
                 process_tab_clone.borrow_mut().update(snapshot);
                 performance_tab_clone.borrow_mut().update(snapshot);
                 users_tab_clone.borrow_mut().update(snapshot);
+                connections_tab.update(snapshot);
 
                 // Update status bar
                 status_processes_clone.set_text(&format!("Processes: {}", snapshot.process_count));
                 status_cpu_clone.set_text(&format!("CPU: {}", util::format_percent(snapshot.cpu.total_percent)));
+                let load = &snapshot.load;
+                status_load_clone.set_text(&format!("Load: {:.2} {:.2} {:.2}", load.one, load.five, load.fifteen));
+                status_load_clone.set_tooltip_text(Some(&format!(
+                    "1, 5 and 15 minute load averages\n{} runnable of {} tasks",
+                    load.runnable, load.total
+                )));
                 let mem_pct = if snapshot.memory.total > 0 {
                     (snapshot.memory.used as f64 / snapshot.memory.total as f64) * 100.0
                 } else { 0.0 };
                 status_memory_clone.set_text(&format!("Memory: {}", util::format_percent(mem_pct)));
-                if let Some(gpu) = snapshot.gpu.first() {
-                    if gpu.available {
-                        status_gpu_clone.set_text(&format!("GPU: {}", util::format_percent(gpu.utilization_percent)));
-                    }
+                if let Some(gpu) = snapshot.primary_gpu_index().map(|i| &snapshot.gpu[i]) {
+                    status_gpu_clone.set_text(&format!("GPU: {}", util::format_percent(gpu.utilization_percent)));
+                }
+
+                if let Some(tray) = tray_clone.as_ref() {
+                    tray.update(snapshot);
+                }
+
+                if let Some(alerts) = alerts.as_mut() {
+                    alerts.check(snapshot);
+                }
+
+                if let Some(service) = dbus_service.as_ref() {
+                    service.update(snapshot);
                 }
             }
 
             glib::ControlFlow::Continue
         });
 
-        // Save window size on close
+        // Save window size on close; keep running in the tray if there is one
         let config_clone = config.clone();
+        let tray_for_close = tray.clone();
         window.connect_close_request(move |win| {
-            let mut cfg = config_clone.clone();
+            let mut cfg = config_clone.borrow_mut();
             cfg.window_width = win.width();
             cfg.window_height = win.height();
             cfg.save();
+            if tray_for_close.as_ref().as_ref().is_some_and(|t| t.is_available()) {
+                win.set_visible(false);
+                return glib::Propagation::Stop;
+            }
             glib::Propagation::Proceed
         });
 
+        // When starting minimized the window stays hidden unless the tray turns
+        // out to be unavailable (handled by the tray's availability callback)
+        if !(start_hidden && tray.is_some()) {
+            window.present();
+        }
+
         window
     }
 }
 
+/// Export formats offered in the file chooser: (choice id, label, extension).
+const EXPORT_FORMATS: &[(&str, &str, &str)] = &[
+    ("processes-csv", "Processes (CSV)", "csv"),
+    ("app-groups-csv", "App groups (CSV)", "csv"),
+    ("performance-csv", "Performance (CSV)", "csv"),
+    ("disk-csv", "Disk devices (CSV)", "csv"),
+    ("network-csv", "Network interfaces (CSV)", "csv"),
+    ("processes-json", "Processes (JSON)", "json"),
+    ("snapshot-json", "Full snapshot (JSON)", "json"),
+];
+
+/// Whether the main window or one of the app's dialogs has focus.
+fn app_has_focus() -> bool {
+    gtk::Window::toplevels()
+        .iter::<gtk::Window>()
+        .flatten()
+        .any(|w| w.is_active())
+}
+
+/// Set the collector's cadence for the app's focus state and the
+/// configured background behavior, and space graph samples to match.
+fn apply_refresh_mode(
+    active: bool,
+    config: &Config,
+    interval: &AtomicU64,
+    paused: &AtomicBool,
+    graph_settings: &GraphSettings,
+) {
+    let mode = if active { BackgroundRefresh::Normal } else { config.background_refresh };
+    let ms = match mode {
+        BackgroundRefresh::Slow => config.refresh_interval_ms.max(BACKGROUND_SLOW_INTERVAL_MS),
+        _ => config.refresh_interval_ms,
+    };
+    interval.store(ms, Ordering::Relaxed);
+    graph_settings.set_sample_interval_ms(ms);
+    paused.store(mode == BackgroundRefresh::Paused, Ordering::Relaxed);
+}
+
+fn show_export_dialog(window: &adw::ApplicationWindow, snapshot: SystemSnapshot) {
+    let dialog = gtk::FileChooserDialog::new(
+        Some("Export"),
+        Some(window),
+        gtk::FileChooserAction::Save,
+        &[("Cancel", gtk::ResponseType::Cancel), ("Export", gtk::ResponseType::Accept)],
+    );
+    dialog.set_modal(true);
+    dialog.set_current_name("task-manager-export.csv");
+
+    let options: Vec<(&str, &str)> = EXPORT_FORMATS.iter().map(|(id, label, _)| (*id, *label)).collect();
+    dialog.add_choice("format", "Export", &options);
+    dialog.set_choice("format", "processes-csv");
+
+    let win = window.clone();
+    dialog.connect_response(move |d, response| {
+        if response != gtk::ResponseType::Accept {
+            d.close();
+            return;
+        }
+        let Some(mut path) = d.file().and_then(|f| f.path()) else {
+            d.close();
+            return;
+        };
+        let format = d.choice("format").map(|c| c.to_string()).unwrap_or_default();
+        d.close();
+
+        // Add the format's extension if the user didn't type one
+        if let Some((_, _, ext)) = EXPORT_FORMATS.iter().find(|(id, _, _)| *id == format) {
+            if path.extension().is_none() {
+                path.set_extension(ext);
+            }
+        }
+
+        use crate::backend::export;
+        let result = match format.as_str() {
+            "processes-csv" => export::export_processes_csv(&path, &snapshot.processes),
+            "app-groups-csv" => export::export_app_groups_csv(&path, &snapshot.app_groups),
+            "performance-csv" => export::export_performance_csv(&path, &snapshot, false),
+            "disk-csv" => export::export_disk_csv(&path, &snapshot.disk),
+            "network-csv" => export::export_network_csv(&path, &snapshot.network),
+            "processes-json" => export::export_processes_json(&path, &snapshot.processes, true),
+            "snapshot-json" => export::export_snapshot_json(&path, &snapshot, true),
+            other => Err(format!("Unknown export format: {}", other)),
+        };
+
+        match result {
+            Ok(()) => {
+                log::info!("Exported {} to {}", format, path.display());
+                show_message(&win, gtk::MessageType::Info, &format!("Exported to {}", path.display()));
+            }
+            Err(e) => {
+                log::error!("Export to {} failed: {}", path.display(), e);
+                show_message(&win, gtk::MessageType::Error, &format!("Export failed:\n\n{}", e));
+            }
+        }
+    });
+    dialog.present();
+}
+
+fn show_message(window: &adw::ApplicationWindow, kind: gtk::MessageType, message: &str) {
+    let dialog = gtk::MessageDialog::new(
+        Some(window),
+        gtk::DialogFlags::MODAL | gtk::DialogFlags::DESTROY_WITH_PARENT,
+        kind,
+        gtk::ButtonsType::Ok,
+        message,
+    );
+    dialog.connect_response(|d, _| d.close());
+    dialog.present();
+}
+
 fn setup_shortcut_with_feedback(window: &adw::ApplicationWindow) {
     match shortcut_setup::install() {
         Ok(msg) => {